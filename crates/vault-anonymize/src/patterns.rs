@@ -356,6 +356,11 @@ impl PatternSet {
         }
     }
 
+    /// Returns the patterns in the set, in insertion order.
+    pub fn patterns(&self) -> impl Iterator<Item = &Pattern> {
+        self.patterns.iter().map(|compiled| &compiled.pattern)
+    }
+
     /// Returns the number of patterns.
     #[must_use]
     pub fn len(&self) -> usize {
@@ -3,9 +3,17 @@
 //! Benchmarks PII detection and anonymization pipeline latency
 //! without modifying any existing anonymization logic.
 
+use super::timing::IterationTimer;
+use super::DataPattern;
+use crate::stats::drop_non_finite;
 use crate::{BenchmarkResult, StandardMetrics};
 use async_trait::async_trait;
-use std::time::Instant;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use std::time::{Duration, Instant};
+use vault_anonymize::patterns::BUILTIN_PATTERNS;
+use vault_anonymize::{AnonymizerConfig, DetectorConfig};
 
 /// Benchmark type for anonymization operations.
 #[derive(Debug, Clone, Copy)]
@@ -16,6 +24,8 @@ pub enum AnonymizationType {
     Detection,
     /// JSON anonymization.
     Json,
+    /// Shared anonymizer versus a fresh anonymizer per record.
+    Reuse,
 }
 
 /// Anonymization benchmark measuring PII detection and anonymization throughput.
@@ -24,6 +34,13 @@ pub struct AnonymizationBenchmark {
     id: String,
     benchmark_type: AnonymizationType,
     iterations: usize,
+    warmup: Option<usize>,
+    max_total_time: Option<Duration>,
+    trim_outliers: bool,
+    detector_config: DetectorConfig,
+    anonymizer_config: AnonymizerConfig,
+    seed: u64,
+    data_pattern: Option<DataPattern>,
 }
 
 impl AnonymizationBenchmark {
@@ -35,6 +52,13 @@ impl AnonymizationBenchmark {
             id: id.into(),
             benchmark_type: AnonymizationType::Full,
             iterations: 10,
+            warmup: None,
+            max_total_time: None,
+            trim_outliers: false,
+            detector_config: DetectorConfig::default(),
+            anonymizer_config: AnonymizerConfig::default(),
+            seed: super::DEFAULT_SEED,
+            data_pattern: None,
         }
     }
 
@@ -46,6 +70,13 @@ impl AnonymizationBenchmark {
             id: id.into(),
             benchmark_type: AnonymizationType::Detection,
             iterations: 10,
+            warmup: None,
+            max_total_time: None,
+            trim_outliers: false,
+            detector_config: DetectorConfig::default(),
+            anonymizer_config: AnonymizerConfig::default(),
+            seed: super::DEFAULT_SEED,
+            data_pattern: None,
         }
     }
 
@@ -57,6 +88,36 @@ impl AnonymizationBenchmark {
             id: id.into(),
             benchmark_type: AnonymizationType::Json,
             iterations: 10,
+            warmup: None,
+            max_total_time: None,
+            trim_outliers: false,
+            detector_config: DetectorConfig::default(),
+            anonymizer_config: AnonymizerConfig::default(),
+            seed: super::DEFAULT_SEED,
+            data_pattern: None,
+        }
+    }
+
+    /// Creates an anonymizer reuse benchmark.
+    ///
+    /// Each iteration anonymizes every record once with a single shared
+    /// `Anonymizer` and once constructing a fresh `Anonymizer` per record.
+    /// Latency percentiles describe the shared pass; `reuse_speedup` is the
+    /// ratio of fresh to shared time.
+    #[must_use]
+    pub fn reuse(record_count: usize, id: impl Into<String>) -> Self {
+        Self {
+            record_count,
+            id: id.into(),
+            benchmark_type: AnonymizationType::Reuse,
+            iterations: 10,
+            warmup: None,
+            max_total_time: None,
+            trim_outliers: false,
+            detector_config: DetectorConfig::default(),
+            anonymizer_config: AnonymizerConfig::default(),
+            seed: super::DEFAULT_SEED,
+            data_pattern: None,
         }
     }
 
@@ -67,49 +128,190 @@ impl AnonymizationBenchmark {
         self
     }
 
+    /// Sets the number of untimed warmup iterations run before timing starts.
+    ///
+    /// Defaults to 10% of the iteration count.
+    #[must_use]
+    pub fn with_warmup(mut self, warmup: usize) -> Self {
+        self.warmup = Some(warmup);
+        self
+    }
+
+    /// Caps the cumulative time spent in the iteration loop.
+    ///
+    /// Once the budget is reached the loop stops early and the `iterations`
+    /// metric reports the number of iterations actually completed.
+    #[must_use]
+    pub fn with_max_total_time(mut self, max_total_time: Duration) -> Self {
+        self.max_total_time = Some(max_total_time);
+        self
+    }
+
+    /// Reports the mean with the top and bottom 5% of samples dropped; see
+    /// [`StandardMetrics::from_samples_with`].
+    #[must_use]
+    pub fn with_trim_outliers(mut self, trim_outliers: bool) -> Self {
+        self.trim_outliers = trim_outliers;
+        self
+    }
+
+    /// Sets the PII detector configuration.
+    ///
+    /// Applies to detection-only runs and replaces the detector inside the
+    /// anonymizer configuration, so every mode detects with the same rules.
+    #[must_use]
+    pub fn with_detector_config(mut self, config: DetectorConfig) -> Self {
+        self.anonymizer_config.detector = config.clone();
+        self.detector_config = config;
+        self
+    }
+
+    /// Sets the anonymizer configuration, including its detector.
+    #[must_use]
+    pub fn with_anonymizer_config(mut self, config: AnonymizerConfig) -> Self {
+        self.detector_config = config.detector.clone();
+        self.anonymizer_config = config;
+        self
+    }
+
+    /// Sets the seed for test-record generation.
+    ///
+    /// The seed decides which PII fields each record carries and where they
+    /// appear; the same seed always produces the same corpus. Defaults to
+    /// [`DEFAULT_SEED`](super::DEFAULT_SEED).
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Sets the filler text written between the PII fields of text records;
+    /// `None` keeps the default lorem ipsum sentence.
+    ///
+    /// The pattern is rendered as printable text and drawn from the seed.
+    /// JSON records carry no filler and ignore it.
+    #[must_use]
+    pub fn with_data_pattern(mut self, pattern: impl Into<Option<DataPattern>>) -> Self {
+        self.data_pattern = pattern.into();
+        self
+    }
+
+    /// Returns the number of built-in patterns the detector config keeps
+    /// active, applying the same confidence, risk, and type filters as the
+    /// detector.
+    fn pattern_count(&self) -> usize {
+        let config = &self.detector_config;
+        BUILTIN_PATTERNS
+            .patterns()
+            .filter(|p| p.confidence >= config.min_confidence)
+            .filter(|p| p.risk_level as u8 >= config.min_risk_level as u8)
+            .filter(|p| {
+                config.include_types.is_empty() || config.include_types.contains(&p.pii_type)
+            })
+            .filter(|p| !config.exclude_types.contains(&p.pii_type))
+            .count()
+    }
+
+    /// Returns the warmup iteration count, defaulting to 10% of iterations.
+    fn warmup_iterations(&self) -> usize {
+        self.warmup.unwrap_or_else(|| super::default_warmup(self.iterations))
+    }
+
+    /// Returns a loop driver honoring the warmup and time budget settings.
+    fn timer(&self) -> IterationTimer {
+        IterationTimer::new(self.iterations, self.warmup_iterations())
+            .with_max_total_time(self.max_total_time)
+    }
+
     /// Generates test records with PII data.
+    ///
+    /// Each PII field is kept with a 75% chance and the survivors are
+    /// shuffled between runs of filler text, so density and placement vary
+    /// from record to record. Field values are still derived from the index.
     fn generate_test_records(&self) -> Vec<String> {
+        let mut rng = StdRng::seed_from_u64(self.seed);
         (0..self.record_count)
             .map(|i| {
-                format!(
-                    "Record {}: Contact john.doe{}@example.com or call 555-{:04}-{:04}. \
-                     SSN: {:03}-{:02}-{:04}. Address: {} Main St, City, ST {}",
-                    i,
-                    i,
-                    i % 10000,
-                    (i + 1234) % 10000,
-                    (i % 900) + 100,
-                    (i % 90) + 10,
-                    (i % 9000) + 1000,
-                    (i % 900) + 100,
-                    (i % 90000) + 10000
-                )
+                let street = (i % 900) + 100;
+                let mut fields = vec![
+                    format!("Contact john.doe{}@example.com", i),
+                    format!("Call {}", test_phone(i)),
+                    format!("SSN: {}", test_ssn(i)),
+                    format!("Address: {} Main St, City, ST {}", street, (i % 90000) + 10000),
+                ];
+                fields.retain(|_| rng.gen_bool(0.75));
+                fields.shuffle(&mut rng);
+
+                let mut record = format!("Record {}:", i);
+                for field in fields {
+                    for _ in 0..rng.gen_range(0..4) {
+                        record.push(' ');
+                        match self.data_pattern {
+                            Some(pattern) => {
+                                record.push_str(&pattern.generate_text(FILLER.len(), rng.gen()))
+                            }
+                            None => record.push_str(FILLER),
+                        }
+                    }
+                    record.push(' ');
+                    record.push_str(&field);
+                    record.push('.');
+                }
+                record
             })
             .collect()
     }
 
     /// Generates test JSON records with PII data.
+    ///
+    /// Optional PII fields are each kept with a 75% chance, driven by the
+    /// same seed as [`generate_test_records`](Self::generate_test_records).
     fn generate_test_json_records(&self) -> Vec<serde_json::Value> {
+        let mut rng = StdRng::seed_from_u64(self.seed);
         (0..self.record_count)
             .map(|i| {
+                let mut user = serde_json::json!({
+                    "name": format!("John Doe {}", i),
+                    "email": format!("john.doe{}@example.com", i),
+                });
+                let mut metadata = serde_json::json!({
+                    "created_at": "2024-01-01T00:00:00Z",
+                });
+
+                if rng.gen_bool(0.75) {
+                    user["phone"] = test_phone(i).into();
+                }
+                if rng.gen_bool(0.75) {
+                    user["ssn"] = test_ssn(i).into();
+                }
+                if rng.gen_bool(0.75) {
+                    let ip = format!("192.168.{}.{}", i % 256, (i + 1) % 256);
+                    metadata["ip_address"] = ip.into();
+                }
+
                 serde_json::json!({
                     "id": i,
-                    "user": {
-                        "name": format!("John Doe {}", i),
-                        "email": format!("john.doe{}@example.com", i),
-                        "phone": format!("555-{:04}-{:04}", i % 10000, (i + 1234) % 10000),
-                        "ssn": format!("{:03}-{:02}-{:04}", (i % 900) + 100, (i % 90) + 10, (i % 9000) + 1000)
-                    },
-                    "metadata": {
-                        "created_at": "2024-01-01T00:00:00Z",
-                        "ip_address": format!("192.168.{}.{}", i % 256, (i + 1) % 256)
-                    }
+                    "user": user,
+                    "metadata": metadata,
                 })
             })
             .collect()
     }
 }
 
+/// Default filler sentence between the PII fields of a text record.
+const FILLER: &str = "Lorem ipsum dolor sit amet.";
+
+/// Returns the test phone number for record `i`.
+fn test_phone(i: usize) -> String {
+    format!("555-{:04}-{:04}", i % 10000, (i + 1234) % 10000)
+}
+
+/// Returns the test SSN for record `i`.
+fn test_ssn(i: usize) -> String {
+    format!("{:03}-{:02}-{:04}", (i % 900) + 100, (i % 90) + 10, (i % 9000) + 1000)
+}
+
 #[async_trait]
 impl super::BenchTarget for AnonymizationBenchmark {
     fn id(&self) -> &str {
@@ -121,6 +323,7 @@ impl super::BenchTarget for AnonymizationBenchmark {
             AnonymizationType::Full => "Full Anonymization Pipeline",
             AnonymizationType::Detection => "PII Detection",
             AnonymizationType::Json => "JSON Anonymization",
+            AnonymizationType::Reuse => "Anonymizer Reuse",
         }
     }
 
@@ -129,25 +332,33 @@ impl super::BenchTarget for AnonymizationBenchmark {
             AnonymizationType::Full => "Measures full PII detection and anonymization pipeline latency",
             AnonymizationType::Detection => "Measures PII detection throughput",
             AnonymizationType::Json => "Measures JSON document anonymization throughput",
+            AnonymizationType::Reuse => "Compares a shared anonymizer against one constructed per record",
         }
     }
 
     async fn run(&self) -> BenchmarkResult {
-        use vault_anonymize::{Anonymizer, AnonymizerConfig, PiiDetector, DetectorConfig};
+        use vault_anonymize::{Anonymizer, PiiDetector};
 
-        let mut times = Vec::with_capacity(self.iterations);
+        let mut timer = self.timer();
         let mut total_pii_found = 0;
         let mut total_anonymized = 0;
         let mut total_bytes: usize = 0;
+        let mut fresh_times = Vec::new();
+        let warmup = self.warmup_iterations();
 
         match self.benchmark_type {
             AnonymizationType::Full => {
                 let records = self.generate_test_records();
                 total_bytes = records.iter().map(|r| r.len()).sum();
 
-                let anonymizer = Anonymizer::new(AnonymizerConfig::default());
+                let anonymizer = Anonymizer::new(self.anonymizer_config.clone());
 
-                for _ in 0..self.iterations {
+                while timer.next_iteration().is_some() {
+                    // Counters only cover timed iterations
+                    if timer.is_first_timed() {
+                        total_pii_found = 0;
+                        total_anonymized = 0;
+                    }
                     let start = Instant::now();
 
                     for record in &records {
@@ -156,16 +367,21 @@ impl super::BenchTarget for AnonymizationBenchmark {
                         total_anonymized += result.stats.total_anonymized;
                     }
 
-                    times.push(start.elapsed().as_secs_f64() * 1000.0);
+                    timer.record(start);
                 }
             }
             AnonymizationType::Detection => {
                 let records = self.generate_test_records();
                 total_bytes = records.iter().map(|r| r.len()).sum();
 
-                let detector = PiiDetector::with_config(DetectorConfig::default());
+                let detector = PiiDetector::with_config(self.detector_config.clone());
 
-                for _ in 0..self.iterations {
+                while timer.next_iteration().is_some() {
+                    // Counters only cover timed iterations
+                    if timer.is_first_timed() {
+                        total_pii_found = 0;
+                        total_anonymized = 0;
+                    }
                     let start = Instant::now();
 
                     for record in &records {
@@ -173,7 +389,7 @@ impl super::BenchTarget for AnonymizationBenchmark {
                         total_pii_found += detections.len();
                     }
 
-                    times.push(start.elapsed().as_secs_f64() * 1000.0);
+                    timer.record(start);
                 }
             }
             AnonymizationType::Json => {
@@ -183,9 +399,14 @@ impl super::BenchTarget for AnonymizationBenchmark {
                     .map(|r| serde_json::to_string(r).unwrap_or_default().len())
                     .sum();
 
-                let anonymizer = Anonymizer::new(AnonymizerConfig::default());
+                let anonymizer = Anonymizer::new(self.anonymizer_config.clone());
 
-                for _ in 0..self.iterations {
+                while timer.next_iteration().is_some() {
+                    // Counters only cover timed iterations
+                    if timer.is_first_timed() {
+                        total_pii_found = 0;
+                        total_anonymized = 0;
+                    }
                     let start = Instant::now();
 
                     for record in &records {
@@ -194,41 +415,84 @@ impl super::BenchTarget for AnonymizationBenchmark {
                         total_anonymized += output.stats.total_anonymized;
                     }
 
-                    times.push(start.elapsed().as_secs_f64() * 1000.0);
+                    timer.record(start);
+                }
+            }
+            AnonymizationType::Reuse => {
+                let records = self.generate_test_records();
+                total_bytes = records.iter().map(|r| r.len()).sum();
+
+                let anonymizer = Anonymizer::new(self.anonymizer_config.clone());
+
+                while timer.next_iteration().is_some() {
+                    // Counters only cover timed iterations
+                    if timer.is_first_timed() {
+                        total_pii_found = 0;
+                        total_anonymized = 0;
+                    }
+                    // Shared instance across all records
+                    let start = Instant::now();
+                    for record in &records {
+                        let result = anonymizer.anonymize(record).expect("Anonymization failed");
+                        total_pii_found += result.stats.total_pii_found;
+                        total_anonymized += result.stats.total_anonymized;
+                    }
+                    let shared_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+                    // Fresh instance per record
+                    let start = Instant::now();
+                    for record in &records {
+                        let fresh = Anonymizer::new(self.anonymizer_config.clone());
+                        let _ = fresh.anonymize(record).expect("Anonymization failed");
+                    }
+                    let fresh_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+                    if !timer.is_warmup() {
+                        fresh_times.push(fresh_ms);
+                    }
+                    timer.record_ms(shared_ms);
                 }
             }
         }
+        let mut times = timer.into_samples();
 
         // Calculate statistics
-        let avg_ms = times.iter().sum::<f64>() / self.iterations as f64;
+        let dropped_samples = drop_non_finite(&mut times) + drop_non_finite(&mut fresh_times);
+        let iterations = times.len();
+        let metrics = StandardMetrics::from_samples_with(
+            &times,
+            total_bytes as u64,
+            iterations as u64,
+            self.trim_outliers,
+        );
+        let avg_ms = metrics.duration_ms.unwrap_or_default();
         let records_per_second = (self.record_count as f64 / avg_ms) * 1000.0;
-        let throughput_bps = (total_bytes as f64 / avg_ms) * 1000.0;
-
-        // Sort for percentiles
-        times.sort_by(|a, b| a.partial_cmp(b).unwrap());
 
-        let p50_idx = self.iterations / 2;
-        let p95_idx = (self.iterations as f64 * 0.95) as usize;
-        let p99_idx = (self.iterations as f64 * 0.99) as usize;
+        let avg_pii_per_record = total_pii_found as f64 / (self.record_count * iterations) as f64;
 
-        let avg_pii_per_record = total_pii_found as f64 / (self.record_count * self.iterations) as f64;
-
-        let metrics = StandardMetrics::new()
-            .with_duration_ms(avg_ms)
-            .with_data_size(total_bytes as u64)
-            .with_iterations(self.iterations as u64)
+        let mut metrics = metrics
             .with_ops_per_second(records_per_second)
-            .with_bytes_per_second(throughput_bps)
-            .with_latencies(
-                times[p50_idx],
-                times[p95_idx.min(self.iterations - 1)],
-                times[p99_idx.min(self.iterations - 1)],
-            )
             .with_custom("record_count", self.record_count as u64)
+            .with_custom("pattern_count", self.pattern_count() as u64)
+            .with_custom("seed", self.seed)
             .with_custom("records_per_second", records_per_second)
             .with_custom("avg_pii_per_record", avg_pii_per_record)
             .with_custom("total_pii_found", total_pii_found as u64)
-            .with_custom("total_anonymized", total_anonymized as u64);
+            .with_custom("total_anonymized", total_anonymized as u64)
+            .with_custom("warmup_iterations", warmup as u64)
+            .with_custom("dropped_samples", dropped_samples as u64);
+
+        if let Some(pattern) = self.data_pattern {
+            metrics = metrics.with_custom("data_pattern", pattern.name());
+        }
+        if !fresh_times.is_empty() {
+            let fresh_avg_ms = fresh_times.iter().sum::<f64>() / fresh_times.len() as f64;
+            metrics = metrics
+                .with_custom("reuse_avg_ms", avg_ms)
+                .with_custom("fresh_avg_ms", fresh_avg_ms)
+                .with_custom("fresh_records_per_second", (self.record_count as f64 / fresh_avg_ms) * 1000.0)
+                .with_custom("reuse_speedup", fresh_avg_ms / avg_ms);
+        }
 
         BenchmarkResult::new(&self.id, metrics.to_json_value())
     }
@@ -250,6 +514,17 @@ mod tests {
         assert!(result.metrics["records_per_second"].as_f64().unwrap() > 0.0);
     }
 
+    #[tokio::test]
+    async fn test_reuse_benchmark() {
+        let benchmark = AnonymizationBenchmark::reuse(10, "test-reuse")
+            .with_iterations(2);
+
+        let result = benchmark.run().await;
+
+        assert!(result.metrics["fresh_avg_ms"].as_f64().unwrap() > 0.0);
+        assert!(result.metrics["reuse_speedup"].as_f64().unwrap() > 0.0);
+    }
+
     #[tokio::test]
     async fn test_pii_detection_benchmark() {
         let benchmark = AnonymizationBenchmark::pii_detection(10, "test-pii-detection")
@@ -260,4 +535,47 @@ mod tests {
         assert_eq!(result.target_id, "test-pii-detection");
         assert!(result.metrics["total_pii_found"].as_u64().unwrap() > 0);
     }
+
+    #[tokio::test]
+    async fn test_custom_detector_config() {
+        use vault_core::record::PIIType;
+
+        let default = AnonymizationBenchmark::pii_detection(10, "test-default-config")
+            .with_iterations(2);
+        let email_only = AnonymizationBenchmark::pii_detection(10, "test-email-only")
+            .with_iterations(2)
+            .with_detector_config(DetectorConfig {
+                include_types: vec![PIIType::Email],
+                ..DetectorConfig::default()
+            });
+
+        let default = default.run().await;
+        let email_only = email_only.run().await;
+
+        let patterns = |r: &BenchmarkResult| r.metrics["pattern_count"].as_u64().unwrap();
+        let found = |r: &BenchmarkResult| r.metrics["total_pii_found"].as_u64().unwrap();
+        assert!(patterns(&email_only) >= 1);
+        assert!(patterns(&email_only) < patterns(&default));
+        assert!(found(&email_only) < found(&default));
+    }
+
+    #[test]
+    fn test_seeded_records_are_reproducible() {
+        let a = AnonymizationBenchmark::new(50, "test-seed-a").with_seed(7);
+        let b = AnonymizationBenchmark::new(50, "test-seed-b").with_seed(7);
+        let other = AnonymizationBenchmark::new(50, "test-seed-other").with_seed(8);
+
+        assert_eq!(a.generate_test_records(), b.generate_test_records());
+        assert_eq!(a.generate_test_json_records(), b.generate_test_json_records());
+        assert_ne!(a.generate_test_records(), other.generate_test_records());
+
+        let patterned = |seed| {
+            AnonymizationBenchmark::new(50, "test-seed-pattern")
+                .with_seed(seed)
+                .with_data_pattern(DataPattern::Random)
+                .generate_test_records()
+        };
+        assert_eq!(patterned(7), patterned(7));
+        assert_ne!(patterned(7), a.generate_test_records());
+    }
 }
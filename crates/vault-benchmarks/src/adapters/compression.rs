@@ -0,0 +1,446 @@
+//! Compression benchmark adapter.
+//!
+//! Measures compress and decompress throughput for the codecs a storage layer
+//! might apply before writing, together with the ratio each achieves. Speed
+//! alone does not settle a codec choice, so every result carries both.
+
+use super::timing::IterationTimer;
+use super::DataPattern;
+use crate::stats::drop_non_finite;
+use crate::{BenchmarkResult, StandardMetrics};
+use async_trait::async_trait;
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
+use std::hint::black_box;
+use std::io;
+use std::time::{Duration, Instant};
+
+/// Compression codec to benchmark.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Codec {
+    /// No compression; a plain copy, as a baseline.
+    None,
+    /// DEFLATE with a gzip header (levels 0-9). Requires the `gzip` feature.
+    #[cfg(feature = "gzip")]
+    Gzip,
+    /// Zstandard (levels 1-22).
+    #[default]
+    Zstd,
+}
+
+impl Codec {
+    /// Returns the codec name recorded in the `codec` metric.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::None => "none",
+            #[cfg(feature = "gzip")]
+            Self::Gzip => "gzip",
+            Self::Zstd => "zstd",
+        }
+    }
+
+    /// Returns the codec's default compression level.
+    fn default_level(self) -> i32 {
+        match self {
+            Self::None => 0,
+            #[cfg(feature = "gzip")]
+            Self::Gzip => 6,
+            Self::Zstd => zstd::DEFAULT_COMPRESSION_LEVEL,
+        }
+    }
+
+    fn compress(self, data: &[u8], level: i32) -> io::Result<Vec<u8>> {
+        match self {
+            Self::None => Ok(data.to_vec()),
+            #[cfg(feature = "gzip")]
+            Self::Gzip => {
+                use std::io::Write;
+
+                let level = flate2::Compression::new(level.clamp(0, 9) as u32);
+                let mut encoder = flate2::write::GzEncoder::new(Vec::new(), level);
+                encoder.write_all(data)?;
+                encoder.finish()
+            }
+            Self::Zstd => zstd::bulk::compress(data, level),
+        }
+    }
+
+    fn decompress(self, data: &[u8], original_len: usize) -> io::Result<Vec<u8>> {
+        match self {
+            Self::None => Ok(data.to_vec()),
+            #[cfg(feature = "gzip")]
+            Self::Gzip => {
+                use std::io::Read;
+
+                let mut out = Vec::with_capacity(original_len);
+                flate2::read::GzDecoder::new(data).read_to_end(&mut out)?;
+                Ok(out)
+            }
+            Self::Zstd => zstd::bulk::decompress(data, original_len),
+        }
+    }
+}
+
+/// Kind of payload compressed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DataKind {
+    /// Prose-like text drawn from a small vocabulary.
+    #[default]
+    Text,
+    /// A JSON array of record objects.
+    Json,
+    /// Uniformly random bytes, which do not compress.
+    Random,
+}
+
+impl DataKind {
+    /// Returns the name recorded in the `data_kind` metric.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Text => "text",
+            Self::Json => "json",
+            Self::Random => "random",
+        }
+    }
+}
+
+/// Compression benchmark measuring throughput and achieved ratio.
+pub struct CompressionBenchmark {
+    data_size: usize,
+    id: String,
+    codec: Codec,
+    level: i32,
+    data_kind: DataKind,
+    data_pattern: Option<DataPattern>,
+    iterations: usize,
+    warmup: Option<usize>,
+    max_total_time: Option<Duration>,
+    trim_outliers: bool,
+    seed: u64,
+}
+
+impl CompressionBenchmark {
+    /// Creates a benchmark compressing `data_size` bytes of text with `codec`
+    /// at its default level.
+    #[must_use]
+    pub fn new(codec: Codec, data_size: usize, id: impl Into<String>) -> Self {
+        Self {
+            data_size,
+            id: id.into(),
+            codec,
+            level: codec.default_level(),
+            data_kind: DataKind::default(),
+            data_pattern: None,
+            iterations: 20,
+            warmup: None,
+            max_total_time: None,
+            trim_outliers: false,
+            seed: super::DEFAULT_SEED,
+        }
+    }
+
+    /// Sets the compression level. Gzip clamps it to 0-9; ignored for
+    /// [`Codec::None`].
+    #[must_use]
+    pub fn with_level(mut self, level: i32) -> Self {
+        self.level = level;
+        self
+    }
+
+    /// Sets the kind of payload compressed.
+    #[must_use]
+    pub fn with_data_kind(mut self, data_kind: DataKind) -> Self {
+        self.data_kind = data_kind;
+        self
+    }
+
+    /// Compresses a generic byte pattern instead of the configured data
+    /// kind; `None` restores the data kind.
+    #[must_use]
+    pub fn with_data_pattern(mut self, pattern: impl Into<Option<DataPattern>>) -> Self {
+        self.data_pattern = pattern.into();
+        self
+    }
+
+    /// Sets the number of iterations.
+    #[must_use]
+    pub fn with_iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Sets the number of untimed warmup iterations run before timing starts.
+    ///
+    /// Defaults to 10% of the iteration count.
+    #[must_use]
+    pub fn with_warmup(mut self, warmup: usize) -> Self {
+        self.warmup = Some(warmup);
+        self
+    }
+
+    /// Caps the cumulative time spent in the iteration loop.
+    ///
+    /// Once the budget is reached the loop stops early and the `iterations`
+    /// metric reports the number of iterations actually completed.
+    #[must_use]
+    pub fn with_max_total_time(mut self, max_total_time: Duration) -> Self {
+        self.max_total_time = Some(max_total_time);
+        self
+    }
+
+    /// Reports the mean with the top and bottom 5% of samples dropped; see
+    /// [`StandardMetrics::from_samples_with`].
+    #[must_use]
+    pub fn with_trim_outliers(mut self, trim_outliers: bool) -> Self {
+        self.trim_outliers = trim_outliers;
+        self
+    }
+
+    /// Sets the seed used to generate the payload. Defaults to
+    /// [`DEFAULT_SEED`](super::DEFAULT_SEED).
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Returns the warmup iteration count, defaulting to 10% of iterations.
+    fn warmup_iterations(&self) -> usize {
+        self.warmup.unwrap_or_else(|| super::default_warmup(self.iterations))
+    }
+
+    /// Returns a loop driver honoring the warmup and time budget settings.
+    fn timer(&self) -> IterationTimer {
+        IterationTimer::new(self.iterations, self.warmup_iterations())
+            .with_max_total_time(self.max_total_time)
+    }
+
+    /// Generates exactly `data_size` bytes of the configured pattern or kind.
+    fn generate_data(&self) -> Vec<u8> {
+        const WORDS: &[&str] = &[
+            "vault", "record", "dataset", "encrypted", "the", "of", "and", "storage", "model",
+            "training", "access", "policy", "a", "to", "content", "version", "lineage", "key",
+        ];
+
+        if let Some(pattern) = self.data_pattern {
+            return pattern.generate(self.data_size, self.seed);
+        }
+
+        let mut rng = StdRng::seed_from_u64(self.seed);
+        let mut data = Vec::with_capacity(self.data_size + 256);
+        match self.data_kind {
+            DataKind::Text => {
+                while data.len() < self.data_size {
+                    let word = WORDS[rng.gen_range(0..WORDS.len())];
+                    data.extend_from_slice(word.as_bytes());
+                    data.push(if rng.gen_ratio(1, 12) { b'\n' } else { b' ' });
+                }
+            }
+            DataKind::Json => {
+                data.push(b'[');
+                let mut i = 0u64;
+                while data.len() < self.data_size {
+                    let record = serde_json::json!({
+                        "id": i,
+                        "name": format!("user-{}", rng.gen_range(0..10_000)),
+                        "score": rng.gen_range(0.0..100.0),
+                        "tags": [WORDS[rng.gen_range(0..WORDS.len())]],
+                        "active": rng.gen_bool(0.5),
+                    });
+                    if i > 0 {
+                        data.push(b',');
+                    }
+                    data.extend_from_slice(record.to_string().as_bytes());
+                    i += 1;
+                }
+            }
+            DataKind::Random => {
+                data.resize(self.data_size, 0);
+                rng.fill_bytes(&mut data);
+            }
+        }
+        data.truncate(self.data_size);
+        data
+    }
+}
+
+#[async_trait]
+impl super::BenchTarget for CompressionBenchmark {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        match self.codec {
+            Codec::None => "Uncompressed Copy",
+            #[cfg(feature = "gzip")]
+            Codec::Gzip => "Gzip Compression",
+            Codec::Zstd => "Zstandard Compression",
+        }
+    }
+
+    fn description(&self) -> &str {
+        "Measures compress and decompress throughput and the achieved compression ratio"
+    }
+
+    async fn run(&self) -> BenchmarkResult {
+        let data = self.generate_data();
+
+        let mut timer = self.timer();
+        let mut compress_times = Vec::with_capacity(self.iterations);
+        let mut decompress_times = Vec::with_capacity(self.iterations);
+        let mut compressed_bytes = 0;
+        let warmup = self.warmup_iterations();
+
+        while let Some(i) = timer.next_iteration() {
+            let start = Instant::now();
+            let compressed = match self.codec.compress(black_box(&data), self.level) {
+                Ok(compressed) => compressed,
+                Err(e) => {
+                    return BenchmarkResult::failed(&self.id, format!("Compression failed: {e}"))
+                }
+            };
+            let compress_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+            let decompress_start = Instant::now();
+            let decompressed = match self.codec.decompress(black_box(&compressed), data.len()) {
+                Ok(decompressed) => decompressed,
+                Err(e) => {
+                    return BenchmarkResult::failed(&self.id, format!("Decompression failed: {e}"))
+                }
+            };
+            let decompress_ms = decompress_start.elapsed().as_secs_f64() * 1000.0;
+            timer.record(start);
+
+            // Compared outside the timed region so the check costs nothing
+            if i == 0 && decompressed != data {
+                return BenchmarkResult::failed(
+                    &self.id,
+                    "Round-trip verification failed: decompressed data differs from input",
+                );
+            }
+            if !timer.is_warmup() {
+                compress_times.push(compress_ms);
+                decompress_times.push(decompress_ms);
+            }
+            compressed_bytes = compressed.len();
+        }
+        let mut times = timer.into_samples();
+
+        // Calculate statistics; a sample is one compress plus one decompress
+        let dropped_samples = drop_non_finite(&mut times);
+        let iterations = times.len();
+        let mean = |samples: &[f64]| samples.iter().sum::<f64>() / samples.len() as f64;
+        let compress_ms = mean(&compress_times);
+        let decompress_ms = mean(&decompress_times);
+        let throughput = |ms: f64| (data.len() as f64 / ms) * 1000.0;
+
+        let metrics = StandardMetrics::from_samples_with(
+            &times,
+            data.len() as u64,
+            iterations as u64,
+            self.trim_outliers,
+        )
+        .with_custom("codec", self.codec.name())
+        .with_custom("level", i64::from(self.level));
+        let metrics = match self.data_pattern {
+            Some(pattern) => metrics.with_custom("data_pattern", pattern.name()),
+            None => metrics.with_custom("data_kind", self.data_kind.name()),
+        };
+        let metrics = metrics
+            .with_custom("seed", self.seed)
+            .with_custom("compressed_bytes", compressed_bytes as u64)
+            .with_custom("ratio", data.len() as f64 / compressed_bytes.max(1) as f64)
+            .with_custom("compress_ms", compress_ms)
+            .with_custom("decompress_ms", decompress_ms)
+            .with_custom("compress_throughput_bps", throughput(compress_ms))
+            .with_custom("decompress_throughput_bps", throughput(decompress_ms))
+            .with_custom("warmup_iterations", warmup as u64)
+            .with_custom("dropped_samples", dropped_samples as u64);
+
+        BenchmarkResult::new(&self.id, metrics.to_json_value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::BenchTarget;
+
+    #[tokio::test]
+    async fn test_compression_benchmark() {
+        let ratio = |r: &BenchmarkResult| r.metrics["ratio"].as_f64().unwrap();
+
+        let text = CompressionBenchmark::new(Codec::Zstd, 64 * 1024, "test-zstd")
+            .with_iterations(5)
+            .run()
+            .await;
+        assert!(!text.is_failed(), "{:?}", text.error);
+        assert_eq!(text.metrics["data_size_bytes"], 64 * 1024);
+        assert!(ratio(&text) > 2.0);
+
+        let json = CompressionBenchmark::new(Codec::Zstd, 64 * 1024, "test-json")
+            .with_data_kind(DataKind::Json)
+            .with_iterations(5)
+            .run()
+            .await;
+        assert!(!json.is_failed(), "{:?}", json.error);
+        assert_eq!(json.metrics["level"], zstd::DEFAULT_COMPRESSION_LEVEL);
+        assert!(ratio(&json) > 1.5);
+
+        let random = CompressionBenchmark::new(Codec::Zstd, 64 * 1024, "test-random")
+            .with_data_kind(DataKind::Random)
+            .with_iterations(5)
+            .run()
+            .await;
+        assert!(ratio(&random) < 1.01);
+
+        let zeros = CompressionBenchmark::new(Codec::Zstd, 64 * 1024, "test-zeros")
+            .with_data_kind(DataKind::Random)
+            .with_data_pattern(DataPattern::Zeros)
+            .with_iterations(5)
+            .run()
+            .await;
+        assert_eq!(zeros.metrics["data_pattern"], "zeros");
+        assert!(zeros.metrics.get("data_kind").is_none());
+        assert!(ratio(&zeros) > 100.0);
+    }
+
+    #[cfg(feature = "gzip")]
+    #[tokio::test]
+    async fn test_gzip_codec() {
+        let result = CompressionBenchmark::new(Codec::Gzip, 64 * 1024, "test-gzip")
+            .with_data_kind(DataKind::Json)
+            .with_iterations(5)
+            .run()
+            .await;
+
+        assert!(!result.is_failed(), "{:?}", result.error);
+        assert_eq!(result.metrics["codec"], "gzip");
+        assert_eq!(result.metrics["level"], 6);
+        assert!(result.metrics["ratio"].as_f64().unwrap() > 1.5);
+    }
+
+    #[test]
+    fn test_seeded_data_is_reproducible() {
+        for kind in [DataKind::Text, DataKind::Json, DataKind::Random] {
+            let build = |seed| {
+                CompressionBenchmark::new(Codec::Zstd, 4096, "test-seed")
+                    .with_data_kind(kind)
+                    .with_seed(seed)
+            };
+            assert_eq!(build(7).generate_data(), build(7).generate_data());
+            assert_ne!(build(7).generate_data(), build(8).generate_data());
+        }
+
+        let build = |seed| {
+            CompressionBenchmark::new(Codec::Zstd, 4096, "test-seed")
+                .with_data_pattern(DataPattern::Text)
+                .with_seed(seed)
+        };
+        assert_eq!(build(7).generate_data(), build(7).generate_data());
+        assert_eq!(build(7).generate_data(), DataPattern::Text.generate(4096, 7));
+    }
+}
@@ -0,0 +1,107 @@
+//! Shared state passed between benchmark targets within one run.
+//!
+//! A [`RunContext`] is created once per run and handed to every target via
+//! [`BenchTarget::run_with_context`](super::BenchTarget::run_with_context).
+//! Targets use it to publish fixtures (a populated store, a key) that later
+//! targets reuse instead of rebuilding them.
+//!
+//! # Sharing contract
+//!
+//! - Values are keyed by string; by convention the key is prefixed with the
+//!   publishing target's id (e.g. `"storage-write-1mb/store"`).
+//! - A value is only guaranteed to be present for targets that declare the
+//!   publisher in [`depends_on`](super::BenchTarget::depends_on), since only
+//!   then is the publisher ordered first.
+//! - Values are stored as `Arc<T>` and handed out as clones of that `Arc`;
+//!   consumers that need to mutate shared state must use interior mutability
+//!   in `T` themselves.
+//! - The context is `Send + Sync` and may be read from concurrent tasks. The
+//!   map lock is held only while inserting or looking up, never while a
+//!   target runs.
+
+use std::any::Any;
+use std::collections::HashMap;
+use std::sync::{Arc, RwLock};
+
+/// Type-erased key/value store shared by the targets of one run.
+#[derive(Default)]
+pub struct RunContext {
+    values: RwLock<HashMap<String, Arc<dyn Any + Send + Sync>>>,
+}
+
+impl RunContext {
+    /// Creates an empty context.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Stores a value under `key`, replacing any previous value.
+    pub fn insert<T: Any + Send + Sync>(&self, key: impl Into<String>, value: T) {
+        self.values
+            .write()
+            .unwrap_or_else(|e| e.into_inner())
+            .insert(key.into(), Arc::new(value));
+    }
+
+    /// Returns the value stored under `key`, if present and of type `T`.
+    #[must_use]
+    pub fn get<T: Any + Send + Sync>(&self, key: &str) -> Option<Arc<T>> {
+        let value = self
+            .values
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(key)
+            .cloned()?;
+        value.downcast::<T>().ok()
+    }
+
+    /// Returns true if a value is stored under `key`.
+    #[must_use]
+    pub fn contains(&self, key: &str) -> bool {
+        self.values
+            .read()
+            .unwrap_or_else(|e| e.into_inner())
+            .contains_key(key)
+    }
+
+    /// Returns the number of stored values.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.values.read().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Returns true if no values are stored.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl std::fmt::Debug for RunContext {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let values = self.values.read().unwrap_or_else(|e| e.into_inner());
+        f.debug_struct("RunContext")
+            .field("keys", &values.keys().collect::<Vec<_>>())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_context() {
+        let ctx = RunContext::new();
+        ctx.insert("writer/key", vec![7u8; 32]);
+
+        let key = ctx.get::<Vec<u8>>("writer/key").unwrap();
+        assert_eq!(key.len(), 32);
+
+        // Wrong type or missing key yields None
+        assert!(ctx.get::<String>("writer/key").is_none());
+        assert!(ctx.get::<Vec<u8>>("missing").is_none());
+        assert_eq!(ctx.len(), 1);
+    }
+}
@@ -1,17 +1,120 @@
 //! Encryption benchmark adapter.
 //!
-//! Benchmarks AES-256-GCM encryption and decryption throughput
+//! Benchmarks AES-256-GCM and ChaCha20-Poly1305 encryption and decryption throughput
 //! without modifying any existing crypto logic.
 
+use super::payload::{DataPattern, Payload};
+use super::timing::IterationTimer;
+use crate::cycles::CycleTally;
+use crate::stats::drop_non_finite;
 use crate::{BenchmarkResult, StandardMetrics};
 use async_trait::async_trait;
-use std::time::Instant;
+use std::hint::black_box;
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use vault_crypto::{
+    AesGcmCipher, ChaCha20Poly1305Cipher, CryptoError, CryptoResult, EncryptedData, SecureBytes,
+};
+
+/// Encryption operation to benchmark.
+#[derive(Debug, Clone, Copy)]
+pub enum EncryptionMode {
+    /// Encrypt then decrypt one buffer per iteration.
+    Roundtrip,
+    /// Decrypt one buffer, encrypted during setup, per iteration.
+    DecryptOnly,
+    /// Decrypt a batch of small objects sharing one key per iteration.
+    DecryptBatch {
+        /// Number of objects in the batch.
+        object_count: usize,
+    },
+    /// Decrypt a tampered buffer, encrypted during setup, expecting rejection.
+    TamperDetection,
+}
+
+/// AEAD cipher to benchmark.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum CipherKind {
+    /// AES-256-GCM.
+    #[default]
+    AesGcm,
+    /// ChaCha20-Poly1305.
+    ChaCha20,
+}
+
+impl CipherKind {
+    /// Returns the algorithm name recorded in the `algorithm` metric.
+    #[must_use]
+    pub fn algorithm(self) -> &'static str {
+        match self {
+            Self::AesGcm => "AES-256-GCM",
+            Self::ChaCha20 => "ChaCha20-Poly1305",
+        }
+    }
+}
+
+/// Dispatches to the `vault_crypto` cipher selected by a [`CipherKind`].
+enum Cipher {
+    AesGcm(AesGcmCipher),
+    ChaCha20(ChaCha20Poly1305Cipher),
+}
+
+impl Cipher {
+    fn new(kind: CipherKind) -> Self {
+        match kind {
+            CipherKind::AesGcm => Self::AesGcm(AesGcmCipher::new()),
+            CipherKind::ChaCha20 => Self::ChaCha20(ChaCha20Poly1305Cipher::new()),
+        }
+    }
+
+    fn generate_key(&self) -> SecureBytes {
+        match self {
+            Self::AesGcm(c) => c.generate_key(),
+            Self::ChaCha20(c) => c.generate_key(),
+        }
+    }
+
+    fn encrypt(
+        &self,
+        key: &SecureBytes,
+        plaintext: &[u8],
+        aad: Option<&[u8]>,
+    ) -> CryptoResult<EncryptedData> {
+        match self {
+            Self::AesGcm(c) => c.encrypt(key, plaintext, aad),
+            Self::ChaCha20(c) => c.encrypt(key, plaintext, aad),
+        }
+    }
+
+    fn decrypt(&self, key: &SecureBytes, data: &EncryptedData) -> CryptoResult<SecureBytes> {
+        match self {
+            Self::AesGcm(c) => c.decrypt(key, data),
+            Self::ChaCha20(c) => c.decrypt(key, data),
+        }
+    }
+}
+
+/// Pre-encrypted objects prepared during setup.
+struct BatchFixture {
+    key: SecureBytes,
+    objects: Vec<EncryptedData>,
+}
 
 /// Encryption benchmark measuring encrypt/decrypt throughput.
 pub struct EncryptionBenchmark {
     data_size: usize,
     id: String,
+    mode: EncryptionMode,
+    cipher: CipherKind,
+    data_pattern: DataPattern,
+    seed: u64,
     iterations: usize,
+    warmup: Option<usize>,
+    max_total_time: Option<Duration>,
+    trim_outliers: bool,
+    context: Option<Vec<(String, String)>>,
+    verify: bool,
+    fixture: Mutex<Option<BatchFixture>>,
 }
 
 impl EncryptionBenchmark {
@@ -21,7 +124,65 @@ impl EncryptionBenchmark {
         Self {
             data_size,
             id: id.into(),
+            mode: EncryptionMode::Roundtrip,
+            cipher: CipherKind::AesGcm,
+            data_pattern: DataPattern::Random,
+            seed: super::DEFAULT_SEED,
             iterations: 100,
+            warmup: None,
+            max_total_time: None,
+            trim_outliers: false,
+            context: None,
+            verify: true,
+            fixture: Mutex::new(None),
+        }
+    }
+
+    /// Creates an encrypt/decrypt benchmark using the given cipher.
+    #[must_use]
+    pub fn with_cipher(data_size: usize, id: impl Into<String>, kind: CipherKind) -> Self {
+        Self {
+            cipher: kind,
+            ..Self::new(data_size, id)
+        }
+    }
+
+    /// Creates a batch decryption benchmark.
+    ///
+    /// `object_count` objects of `object_size` bytes are encrypted under one
+    /// key during setup; each iteration decrypts the whole batch.
+    #[must_use]
+    pub fn decrypt_batch(object_size: usize, object_count: usize, id: impl Into<String>) -> Self {
+        Self {
+            mode: EncryptionMode::DecryptBatch {
+                object_count: object_count.max(1),
+            },
+            ..Self::new(object_size, id)
+        }
+    }
+
+    /// Creates a decryption-only benchmark.
+    ///
+    /// The payload is encrypted once during setup; each iteration only
+    /// decrypts it, so the latency percentiles reflect decrypt cost.
+    #[must_use]
+    pub fn decrypt_only(data_size: usize, id: impl Into<String>) -> Self {
+        Self {
+            mode: EncryptionMode::DecryptOnly,
+            ..Self::new(data_size, id)
+        }
+    }
+
+    /// Creates a tamper-detection benchmark.
+    ///
+    /// The payload is encrypted once during setup and one byte of each
+    /// ciphertext is flipped; each iteration times how long decryption takes
+    /// to reject it. `success_rate` is the fraction of attempts rejected.
+    #[must_use]
+    pub fn tamper_detection(data_size: usize, id: impl Into<String>) -> Self {
+        Self {
+            mode: EncryptionMode::TamperDetection,
+            ..Self::new(data_size, id)
         }
     }
 
@@ -31,6 +192,308 @@ impl EncryptionBenchmark {
         self.iterations = iterations;
         self
     }
+
+    /// Sets the number of untimed warmup iterations run before timing starts.
+    ///
+    /// Defaults to 10% of the iteration count.
+    #[must_use]
+    pub fn with_warmup(mut self, warmup: usize) -> Self {
+        self.warmup = Some(warmup);
+        self
+    }
+
+    /// Caps the cumulative time spent in the iteration loop.
+    ///
+    /// Once the budget is reached the loop stops early and the `iterations`
+    /// metric reports the number of iterations actually completed.
+    #[must_use]
+    pub fn with_max_total_time(mut self, max_total_time: Duration) -> Self {
+        self.max_total_time = Some(max_total_time);
+        self
+    }
+
+    /// Reports the mean with the top and bottom 5% of samples dropped; see
+    /// [`StandardMetrics::from_samples_with`].
+    #[must_use]
+    pub fn with_trim_outliers(mut self, trim_outliers: bool) -> Self {
+        self.trim_outliers = trim_outliers;
+        self
+    }
+
+    /// Uses the given fields as the AEAD encryption context.
+    ///
+    /// By default a small synthetic context is used; pass production-shaped
+    /// fields (tenant, object identifiers, ...) to benchmark realistic AAD.
+    #[must_use]
+    pub fn with_context(mut self, fields: Vec<(String, String)>) -> Self {
+        self.context = Some(fields);
+        self
+    }
+
+    /// Sets the plaintext contents.
+    ///
+    /// Defaults to [`DataPattern::Random`], so that compressible inputs
+    /// cannot flatter the AEAD numbers.
+    #[must_use]
+    pub fn with_data_pattern(mut self, pattern: DataPattern) -> Self {
+        self.data_pattern = pattern;
+        self
+    }
+
+    /// Sets the seed used to generate random and text plaintexts. Defaults
+    /// to [`DEFAULT_SEED`](super::DEFAULT_SEED).
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Enables or disables the round-trip correctness check.
+    ///
+    /// When enabled (the default) the first timed iteration checks that
+    /// every decrypted chunk matches its plaintext, and the run fails
+    /// otherwise. The `roundtrip_verified` metric reports whether the check
+    /// ran and passed.
+    #[must_use]
+    pub fn with_verification(mut self, verify: bool) -> Self {
+        self.verify = verify;
+        self
+    }
+
+    /// Returns the warmup iteration count, defaulting to 10% of iterations.
+    fn warmup_iterations(&self) -> usize {
+        self.warmup.unwrap_or_else(|| super::default_warmup(self.iterations))
+    }
+
+    /// Returns a loop driver honoring the warmup and time budget settings.
+    fn timer(&self) -> IterationTimer {
+        IterationTimer::new(self.iterations, self.warmup_iterations())
+            .with_max_total_time(self.max_total_time)
+    }
+
+    /// Encrypts `object_count` distinct objects under a single fresh key.
+    fn prepare_batch(&self, object_count: usize) -> CryptoResult<BatchFixture> {
+        let cipher = Cipher::new(self.cipher);
+        let key = cipher.generate_key();
+        let mut plaintext = self.data_pattern.generate(self.data_size, self.seed);
+
+        let objects = (0..object_count)
+            .map(|i| {
+                if let Some(first) = plaintext.first_mut() {
+                    *first = (i % 256) as u8;
+                }
+                cipher.encrypt(&key, &plaintext, None)
+            })
+            .collect::<CryptoResult<_>>()?;
+
+        Ok(BatchFixture { key, objects })
+    }
+
+    /// Encrypts the payload once under a fresh key, one object per tile.
+    fn prepare_payload(&self) -> CryptoResult<BatchFixture> {
+        let cipher = Cipher::new(self.cipher);
+        let key = cipher.generate_key();
+        let objects = Payload::new(self.data_size, self.data_pattern, self.seed)
+            .chunks()
+            .map(|chunk| cipher.encrypt(&key, chunk, None))
+            .collect::<CryptoResult<_>>()?;
+
+        Ok(BatchFixture { key, objects })
+    }
+
+    /// Encrypts the payload like [`prepare_payload`](Self::prepare_payload),
+    /// then flips one byte in the middle of each ciphertext.
+    fn prepare_tampered(&self) -> CryptoResult<BatchFixture> {
+        let mut fixture = self.prepare_payload()?;
+        for object in &mut fixture.objects {
+            let middle = object.ciphertext.len() / 2;
+            object.ciphertext[middle] ^= 0x01;
+        }
+        Ok(fixture)
+    }
+
+    /// Takes the fixture built during setup, or builds one if setup was skipped.
+    fn take_fixture(
+        &self,
+        prepare: impl FnOnce() -> CryptoResult<BatchFixture>,
+    ) -> Result<BatchFixture, String> {
+        let fixture = self.fixture.lock().unwrap().take();
+        match fixture {
+            Some(fixture) => Ok(fixture),
+            None => prepare().map_err(|e| format!("Encryption failed: {e}")),
+        }
+    }
+
+    /// Decrypts every object of a fixture, discarding the plaintext.
+    fn decrypt_all(cipher: &Cipher, fixture: &BatchFixture) -> Result<(), String> {
+        for object in &fixture.objects {
+            if let Err(e) = black_box(cipher.decrypt(&fixture.key, black_box(object))) {
+                return Err(format!("Decryption failed: {e}"));
+            }
+        }
+        Ok(())
+    }
+
+    /// Times decryption of the payload encrypted during setup.
+    fn run_decrypt_only(&self) -> BenchmarkResult {
+        let fixture = match self.take_fixture(|| self.prepare_payload()) {
+            Ok(fixture) => fixture,
+            Err(message) => return BenchmarkResult::failed(&self.id, message),
+        };
+        let cipher = Cipher::new(self.cipher);
+
+        let mut timer = self.timer();
+        let warmup = self.warmup_iterations();
+
+        while timer.next_iteration().is_some() {
+            let start = Instant::now();
+            if let Err(message) = Self::decrypt_all(&cipher, &fixture) {
+                return BenchmarkResult::failed(&self.id, message);
+            }
+            timer.record(start);
+        }
+        let mut times = timer.into_samples();
+
+        // Calculate statistics
+        let dropped_samples = drop_non_finite(&mut times);
+        let iterations = times.len();
+
+        let mut metrics = StandardMetrics::from_samples_with(
+            &times,
+            self.data_size as u64,
+            iterations as u64,
+            self.trim_outliers,
+        );
+        let avg_ms = metrics.duration_ms.unwrap_or_default();
+        let throughput_bps = metrics.bytes_per_second.unwrap_or_default();
+        metrics = metrics
+            .with_custom("operation", "decrypt")
+            .with_custom("decrypt_avg_ms", avg_ms)
+            .with_custom("decrypt_throughput_bps", throughput_bps)
+            .with_custom("algorithm", self.cipher.algorithm())
+            .with_custom("data_pattern", self.data_pattern.name())
+            .with_custom("warmup_iterations", warmup as u64)
+            .with_custom("dropped_samples", dropped_samples as u64);
+
+        if fixture.objects.len() > 1 {
+            metrics = metrics
+                .with_custom("tiled", true)
+                .with_custom("tile_count", fixture.objects.len() as u64);
+        }
+
+        BenchmarkResult::new(&self.id, metrics.to_json_value())
+    }
+
+    /// Times rejection of the tampered payload prepared during setup.
+    fn run_tamper_detection(&self) -> BenchmarkResult {
+        let fixture = match self.take_fixture(|| self.prepare_tampered()) {
+            Ok(fixture) => fixture,
+            Err(message) => return BenchmarkResult::failed(&self.id, message),
+        };
+        let cipher = Cipher::new(self.cipher);
+
+        let mut timer = self.timer();
+        let mut attempts = 0usize;
+        let mut rejected = 0usize;
+        let warmup = self.warmup_iterations();
+
+        while timer.next_iteration().is_some() {
+            let start = Instant::now();
+            for object in &fixture.objects {
+                let outcome = black_box(cipher.decrypt(&fixture.key, black_box(object)));
+                attempts += 1;
+                if matches!(
+                    outcome,
+                    Err(CryptoError::DecryptionFailed(_) | CryptoError::AuthenticationFailed)
+                ) {
+                    rejected += 1;
+                }
+            }
+            timer.record(start);
+        }
+        let mut times = timer.into_samples();
+
+        // Calculate statistics
+        let dropped_samples = drop_non_finite(&mut times);
+        let iterations = times.len();
+
+        let mut metrics = StandardMetrics::from_samples_with(
+            &times,
+            self.data_size as u64,
+            iterations as u64,
+            self.trim_outliers,
+        );
+        let avg_ms = metrics.duration_ms.unwrap_or_default();
+        let success_rate = rejected as f64 / attempts.max(1) as f64;
+        metrics = metrics
+            .with_success_rate(success_rate)
+            .with_custom("operation", "tamper_detection")
+            .with_custom("detection_avg_ms", avg_ms)
+            .with_custom("algorithm", self.cipher.algorithm())
+            .with_custom("data_pattern", self.data_pattern.name())
+            .with_custom("warmup_iterations", warmup as u64)
+            .with_custom("dropped_samples", dropped_samples as u64);
+
+        if fixture.objects.len() > 1 {
+            metrics = metrics
+                .with_custom("tiled", true)
+                .with_custom("tile_count", fixture.objects.len() as u64);
+        }
+
+        let mut result = BenchmarkResult::new(&self.id, metrics.to_json_value());
+        if rejected < attempts {
+            let accepted = attempts - rejected;
+            result.error = Some(format!(
+                "{accepted} of {attempts} tampered decryptions were not rejected"
+            ));
+        }
+        result
+    }
+
+    /// Times decryption of the prepared batch.
+    fn run_decrypt_batch(&self, object_count: usize) -> BenchmarkResult {
+        let fixture = match self.take_fixture(|| self.prepare_batch(object_count)) {
+            Ok(fixture) => fixture,
+            Err(message) => return BenchmarkResult::failed(&self.id, message),
+        };
+        let cipher = Cipher::new(self.cipher);
+
+        let mut timer = self.timer();
+        let warmup = self.warmup_iterations();
+
+        while timer.next_iteration().is_some() {
+            let start = Instant::now();
+            if let Err(message) = Self::decrypt_all(&cipher, &fixture) {
+                return BenchmarkResult::failed(&self.id, message);
+            }
+            timer.record(start);
+        }
+        let mut times = timer.into_samples();
+
+        // Calculate statistics (per batch)
+        let dropped_samples = drop_non_finite(&mut times);
+        let iterations = times.len();
+
+        let metrics = StandardMetrics::from_samples_with(
+            &times,
+            (object_count * self.data_size) as u64,
+            iterations as u64,
+            self.trim_outliers,
+        );
+        let avg_ms = metrics.duration_ms.unwrap_or_default();
+        let objects_per_second = (object_count as f64 / avg_ms) * 1000.0;
+        let metrics = metrics
+            .with_ops_per_second(objects_per_second)
+            .with_custom("objects_per_second", objects_per_second)
+            .with_custom("object_size", self.data_size as u64)
+            .with_custom("object_count", object_count as u64)
+            .with_custom("algorithm", self.cipher.algorithm())
+            .with_custom("data_pattern", self.data_pattern.name())
+            .with_custom("warmup_iterations", warmup as u64)
+            .with_custom("dropped_samples", dropped_samples as u64);
+
+        BenchmarkResult::new(&self.id, metrics.to_json_value())
+    }
 }
 
 #[async_trait]
@@ -40,75 +503,188 @@ impl super::BenchTarget for EncryptionBenchmark {
     }
 
     fn name(&self) -> &str {
-        "AES-256-GCM Encryption"
+        match (self.mode, self.cipher) {
+            (EncryptionMode::Roundtrip, CipherKind::ChaCha20) => "ChaCha20-Poly1305 Encryption",
+            (EncryptionMode::DecryptOnly, CipherKind::ChaCha20) => "ChaCha20-Poly1305 Decryption",
+            (EncryptionMode::DecryptBatch { .. }, CipherKind::ChaCha20) => {
+                "ChaCha20-Poly1305 Batch Decryption"
+            }
+            (EncryptionMode::TamperDetection, CipherKind::ChaCha20) => {
+                "ChaCha20-Poly1305 Tamper Detection"
+            }
+            (EncryptionMode::Roundtrip, CipherKind::AesGcm) => "AES-256-GCM Encryption",
+            (EncryptionMode::DecryptOnly, CipherKind::AesGcm) => "AES-256-GCM Decryption",
+            (EncryptionMode::DecryptBatch { .. }, CipherKind::AesGcm) => "AES-256-GCM Batch Decryption",
+            (EncryptionMode::TamperDetection, CipherKind::AesGcm) => "AES-256-GCM Tamper Detection",
+        }
     }
 
     fn description(&self) -> &str {
-        "Measures AES-256-GCM encryption and decryption throughput"
+        match self.mode {
+            EncryptionMode::Roundtrip => "Measures AEAD encryption and decryption throughput",
+            EncryptionMode::DecryptOnly => "Measures AEAD decryption latency in isolation",
+            EncryptionMode::DecryptBatch { .. } => {
+                "Measures decryption of many small objects sharing one key"
+            }
+            EncryptionMode::TamperDetection => {
+                "Measures how quickly AEAD decryption rejects tampered ciphertext"
+            }
+        }
+    }
+
+    async fn setup(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        let fixture = match self.mode {
+            EncryptionMode::Roundtrip => None,
+            EncryptionMode::DecryptOnly => Some(self.prepare_payload()?),
+            EncryptionMode::TamperDetection => Some(self.prepare_tampered()?),
+            EncryptionMode::DecryptBatch { object_count } => {
+                Some(self.prepare_batch(object_count)?)
+            }
+        };
+        *self.fixture.lock().unwrap() = fixture;
+        Ok(())
+    }
+
+    async fn teardown(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        self.fixture.lock().unwrap().take();
+        Ok(())
     }
 
     async fn run(&self) -> BenchmarkResult {
-        use vault_crypto::{AesGcmCipher, EncryptionContext};
+        use vault_crypto::EncryptionContext;
 
-        // Generate test data
-        let data: Vec<u8> = (0..self.data_size).map(|i| (i % 256) as u8).collect();
-        let cipher = AesGcmCipher::new();
+        match self.mode {
+            EncryptionMode::Roundtrip => {}
+            EncryptionMode::DecryptOnly => return self.run_decrypt_only(),
+            EncryptionMode::DecryptBatch { object_count } => {
+                return self.run_decrypt_batch(object_count)
+            }
+            EncryptionMode::TamperDetection => return self.run_tamper_detection(),
+        }
+
+        // Generate test data, tiled if it exceeds the allocation limit
+        let payload = Payload::new(self.data_size, self.data_pattern, self.seed);
+        let cipher = Cipher::new(self.cipher);
         let key = cipher.generate_key();
 
         // Create encryption context for AAD
-        let context = EncryptionContext::new()
-            .with("benchmark", "true")
-            .with("data_size", self.data_size.to_string());
+        let context = match &self.context {
+            Some(fields) => fields
+                .iter()
+                .fold(EncryptionContext::new(), |ctx, (k, v)| ctx.with(k.as_str(), v.as_str())),
+            None => EncryptionContext::new()
+                .with("benchmark", "true")
+                .with("data_size", self.data_size.to_string()),
+        };
         let aad = context.to_aad();
 
         // Benchmark encryption
-        let mut encrypt_times = Vec::with_capacity(self.iterations);
+        let mut timer = self.timer();
         let mut decrypt_times = Vec::with_capacity(self.iterations);
+        let mut cycles = CycleTally::new();
+        let mut roundtrip_verified = false;
 
-        for _ in 0..self.iterations {
-            // Encrypt
-            let start = Instant::now();
-            let encrypted = cipher.encrypt(&key, &data, Some(&aad)).expect("Encryption failed");
-            encrypt_times.push(start.elapsed().as_secs_f64() * 1000.0);
+        let warmup = self.warmup_iterations();
+        while timer.next_iteration().is_some() {
+            if timer.is_first_timed() {
+                cycles.reset();
+            }
+            let verify = self.verify && timer.is_first_timed();
+            let mut encrypt_ms = 0.0;
+            let mut decrypt_ms = 0.0;
 
-            // Decrypt
-            let start = Instant::now();
-            let _decrypted = cipher.decrypt(&key, &encrypted).expect("Decryption failed");
-            decrypt_times.push(start.elapsed().as_secs_f64() * 1000.0);
-        }
+            for chunk in payload.chunks() {
+                // Encrypt
+                let start = Instant::now();
+                let cycle_start = cycles.start();
+                // Inputs and outputs go through black_box so the compiler can't
+                // specialize on the constant payload or elide unused results
+                let encrypted = cipher.encrypt(&key, black_box(chunk), Some(&aad));
+                let encrypted = match black_box(encrypted) {
+                    Ok(encrypted) => encrypted,
+                    Err(e) => {
+                        return BenchmarkResult::failed(&self.id, format!("Encryption failed: {e}"))
+                    }
+                };
+                cycles.record(cycle_start, chunk.len());
+                encrypt_ms += start.elapsed().as_secs_f64() * 1000.0;
 
-        // Calculate statistics
-        let avg_encrypt_ms = encrypt_times.iter().sum::<f64>() / self.iterations as f64;
-        let avg_decrypt_ms = decrypt_times.iter().sum::<f64>() / self.iterations as f64;
-        let total_ms = avg_encrypt_ms + avg_decrypt_ms;
+                // Decrypt
+                let start = Instant::now();
+                let decrypted = match black_box(cipher.decrypt(&key, black_box(&encrypted))) {
+                    Ok(decrypted) => decrypted,
+                    Err(e) => {
+                        let message = format!("Decryption failed: {e}");
+                        return BenchmarkResult::failed(&self.id, message);
+                    }
+                };
+                decrypt_ms += start.elapsed().as_secs_f64() * 1000.0;
 
-        // Calculate throughput (bytes per second)
-        let encrypt_throughput = (self.data_size as f64 / avg_encrypt_ms) * 1000.0;
-        let decrypt_throughput = (self.data_size as f64 / avg_decrypt_ms) * 1000.0;
+                // Compared outside the timed region so the check costs nothing
+                if verify && decrypted.as_slice() != chunk {
+                    return BenchmarkResult::failed(
+                        &self.id,
+                        "Round-trip verification failed: decrypted data differs from plaintext",
+                    );
+                }
+            }
+            roundtrip_verified |= verify;
 
-        // Sort for percentiles
-        encrypt_times.sort_by(|a, b| a.partial_cmp(b).unwrap());
-        decrypt_times.sort_by(|a, b| a.partial_cmp(b).unwrap());
+            if !timer.is_warmup() {
+                decrypt_times.push(decrypt_ms);
+            }
+            timer.record_ms(encrypt_ms);
+        }
+        let mut encrypt_times = timer.into_samples();
 
-        let p50_idx = self.iterations / 2;
-        let p95_idx = (self.iterations as f64 * 0.95) as usize;
-        let p99_idx = (self.iterations as f64 * 0.99) as usize;
+        // Calculate statistics
+        let dropped_samples = drop_non_finite(&mut encrypt_times) + drop_non_finite(&mut decrypt_times);
+        let iterations = encrypt_times.len();
+        let data_size = self.data_size as u64;
+        let encrypt = StandardMetrics::from_samples_with(
+            &encrypt_times,
+            data_size,
+            iterations as u64,
+            self.trim_outliers,
+        );
+        let decrypt = StandardMetrics::from_samples_with(
+            &decrypt_times,
+            data_size,
+            decrypt_times.len() as u64,
+            self.trim_outliers,
+        );
+        let avg_encrypt_ms = encrypt.duration_ms.unwrap_or_default();
+        let avg_decrypt_ms = decrypt.duration_ms.unwrap_or_default();
+        let total_ms = avg_encrypt_ms + avg_decrypt_ms;
+        let encrypt_throughput = encrypt.bytes_per_second.unwrap_or_default();
+        let decrypt_throughput = decrypt.bytes_per_second.unwrap_or_default();
 
-        let metrics = StandardMetrics::new()
+        // Latencies describe encryption; duration and ops cover the round trip
+        let mut metrics = encrypt
             .with_duration_ms(total_ms)
-            .with_data_size(self.data_size as u64)
-            .with_iterations(self.iterations as u64)
-            .with_bytes_per_second(encrypt_throughput)
-            .with_latencies(
-                encrypt_times[p50_idx],
-                encrypt_times[p95_idx.min(self.iterations - 1)],
-                encrypt_times[p99_idx.min(self.iterations - 1)],
-            )
+            .with_ops_per_second(1000.0 / total_ms)
             .with_custom("encrypt_avg_ms", avg_encrypt_ms)
             .with_custom("decrypt_avg_ms", avg_decrypt_ms)
             .with_custom("encrypt_throughput_bps", encrypt_throughput)
             .with_custom("decrypt_throughput_bps", decrypt_throughput)
-            .with_custom("algorithm", "AES-256-GCM");
+            .with_custom("algorithm", self.cipher.algorithm())
+            .with_custom("data_pattern", self.data_pattern.name())
+            .with_custom("aad_field_count", context.fields.len() as u64)
+            .with_custom("aad_bytes", aad.len() as u64)
+            .with_custom("roundtrip_verified", roundtrip_verified)
+            .with_custom("warmup_iterations", warmup as u64)
+            .with_custom("dropped_samples", dropped_samples as u64);
+
+        // Cycles are sampled around encryption only, matching bytes_per_second
+        if let Some(cycles_per_byte) = cycles.cycles_per_byte() {
+            metrics = metrics.with_custom("cycles_per_byte", cycles_per_byte);
+        }
+
+        if payload.is_tiled() {
+            metrics = metrics
+                .with_custom("tiled", true)
+                .with_custom("tile_count", payload.tile_count() as u64);
+        }
 
         BenchmarkResult::new(&self.id, metrics.to_json_value())
     }
@@ -130,4 +706,112 @@ mod tests {
         assert!(result.metrics["duration_ms"].as_f64().unwrap() > 0.0);
         assert!(result.metrics["encrypt_throughput_bps"].as_f64().unwrap() > 0.0);
     }
+
+    #[tokio::test]
+    async fn test_roundtrip_verification() {
+        let verified = EncryptionBenchmark::new(1024, "test-verified")
+            .with_iterations(2)
+            .run()
+            .await;
+        let unverified = EncryptionBenchmark::new(1024, "test-unverified")
+            .with_iterations(2)
+            .with_verification(false)
+            .run()
+            .await;
+
+        assert_eq!(verified.metrics["roundtrip_verified"], true);
+        assert_eq!(unverified.metrics["roundtrip_verified"], false);
+        assert!(!verified.is_failed());
+
+        // Warmup iterations are never checked, so a warmup-only run is unverified
+        let warmup_only = EncryptionBenchmark::new(1024, "test-warmup-only")
+            .with_iterations(0)
+            .with_warmup(3)
+            .run()
+            .await;
+        assert_eq!(warmup_only.metrics["roundtrip_verified"], false);
+    }
+
+    #[tokio::test]
+    async fn test_chacha20_benchmark() {
+        let benchmark = EncryptionBenchmark::with_cipher(1024, "test-chacha20", CipherKind::ChaCha20)
+            .with_iterations(10);
+
+        let result = benchmark.run().await;
+
+        assert_eq!(result.metrics["algorithm"], "ChaCha20-Poly1305");
+        assert!(result.metrics["encrypt_throughput_bps"].as_f64().unwrap() > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_decrypt_only_benchmark() {
+        let benchmark = EncryptionBenchmark::decrypt_only(1024, "test-decrypt-only")
+            .with_iterations(10);
+
+        benchmark.setup().await.unwrap();
+        let result = benchmark.run().await;
+        benchmark.teardown().await.unwrap();
+
+        assert_eq!(result.metrics["operation"], "decrypt");
+        assert!(result.metrics["latency_p50_ms"].as_f64().unwrap() > 0.0);
+        assert!(result.metrics["decrypt_throughput_bps"].as_f64().unwrap() > 0.0);
+        assert!(result.metrics.get("encrypt_avg_ms").is_none());
+    }
+
+    #[tokio::test]
+    async fn test_decrypt_batch_benchmark() {
+        let benchmark = EncryptionBenchmark::decrypt_batch(256, 50, "test-decrypt-batch")
+            .with_iterations(5);
+
+        benchmark.setup().await.unwrap();
+        let result = benchmark.run().await;
+        benchmark.teardown().await.unwrap();
+
+        assert_eq!(result.metrics["object_count"].as_u64().unwrap(), 50);
+        assert_eq!(result.metrics["object_size"].as_u64().unwrap(), 256);
+        assert!(result.metrics["objects_per_second"].as_f64().unwrap() > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_tamper_detection_benchmark() {
+        let benchmark = EncryptionBenchmark::tamper_detection(1024, "test-tamper")
+            .with_iterations(10);
+
+        benchmark.setup().await.unwrap();
+        let result = benchmark.run().await;
+        benchmark.teardown().await.unwrap();
+
+        assert!(!result.is_failed());
+        assert_eq!(result.metrics["success_rate"], 1.0);
+        assert_eq!(result.metrics["operation"], "tamper_detection");
+        assert!(result.metrics["detection_avg_ms"].as_f64().unwrap() > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_max_total_time_stops_early() {
+        let benchmark = EncryptionBenchmark::new(1024, "test-time-budget")
+            .with_iterations(50)
+            .with_max_total_time(Duration::ZERO);
+
+        let result = benchmark.run().await;
+
+        assert_eq!(result.metrics["iterations"].as_u64().unwrap(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_custom_context() {
+        let benchmark = EncryptionBenchmark::new(1024, "test-context")
+            .with_iterations(2)
+            .with_context(vec![
+                ("tenant_id".to_string(), "tenant-0042".to_string()),
+                ("object_id".to_string(), "obj-7f3a".to_string()),
+                ("region".to_string(), "eu-west-1".to_string()),
+            ]);
+
+        let result = benchmark.run().await;
+
+        assert_eq!(result.metrics["aad_field_count"].as_u64().unwrap(), 3);
+        // Each field is encoded as key NUL value NUL
+        assert_eq!(result.metrics["aad_bytes"].as_u64().unwrap(), 58);
+    }
 }
@@ -1,60 +1,114 @@
 //! Hashing benchmark adapter.
 //!
-//! Benchmarks BLAKE3, SHA-256, and checksum verification throughput
-//! without modifying any existing crypto logic.
+//! Benchmarks BLAKE3, SHA-256, SHA-512, and checksum verification throughput
+//! without modifying any existing crypto logic. A streaming variant feeds
+//! BLAKE3 incrementally to expose per-update overhead, and a comparison
+//! variant times all three algorithms over the same buffer in one run.
 
+use super::pacing::{arrival, Pacer};
+use super::timing::IterationTimer;
+use super::payload::{DataPattern, Payload};
+use crate::cycles::CycleTally;
+use crate::stats::drop_non_finite;
 use crate::{BenchmarkResult, StandardMetrics};
 use async_trait::async_trait;
-use std::time::Instant;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
 
 /// Hash algorithm to benchmark.
 #[derive(Debug, Clone, Copy)]
 pub enum HashType {
     Blake3,
     Sha256,
+    Sha512,
     Checksum,
+    /// Incremental BLAKE3, fed `chunk_size` bytes per update.
+    Streaming { chunk_size: usize },
+    /// BLAKE3, SHA-256, and SHA-512 back to back over the same buffer.
+    Comparison,
 }
 
+/// Algorithms timed by the comparison variant, with their metric key prefix
+/// and display name.
+const COMPARED_ALGORITHMS: [(&str, &str, vault_crypto::HashAlgorithm); 3] = [
+    ("blake3", "BLAKE3", vault_crypto::HashAlgorithm::Blake3),
+    ("sha256", "SHA-256", vault_crypto::HashAlgorithm::Sha256),
+    ("sha512", "SHA-512", vault_crypto::HashAlgorithm::Sha512),
+];
+
 /// Hashing benchmark measuring hash computation throughput.
 pub struct HashingBenchmark {
     data_size: usize,
     id: String,
     hash_type: HashType,
+    data_pattern: DataPattern,
+    seed: u64,
     iterations: usize,
+    warmup: Option<usize>,
+    max_total_time: Option<Duration>,
+    trim_outliers: bool,
+    arrival_rate: Option<f64>,
 }
 
 impl HashingBenchmark {
-    /// Creates a BLAKE3 benchmark.
-    #[must_use]
-    pub fn blake3(data_size: usize, id: impl Into<String>) -> Self {
+    fn with_hash_type(data_size: usize, id: impl Into<String>, hash_type: HashType) -> Self {
         Self {
             data_size,
             id: id.into(),
-            hash_type: HashType::Blake3,
+            hash_type,
+            data_pattern: DataPattern::Sequential,
+            seed: super::DEFAULT_SEED,
             iterations: 1000,
+            warmup: None,
+            max_total_time: None,
+            trim_outliers: false,
+            arrival_rate: None,
         }
     }
 
+    /// Creates a BLAKE3 benchmark.
+    #[must_use]
+    pub fn blake3(data_size: usize, id: impl Into<String>) -> Self {
+        Self::with_hash_type(data_size, id, HashType::Blake3)
+    }
+
     /// Creates a SHA-256 benchmark.
     #[must_use]
     pub fn sha256(data_size: usize, id: impl Into<String>) -> Self {
-        Self {
-            data_size,
-            id: id.into(),
-            hash_type: HashType::Sha256,
-            iterations: 1000,
-        }
+        Self::with_hash_type(data_size, id, HashType::Sha256)
+    }
+
+    /// Creates a SHA-512 benchmark.
+    #[must_use]
+    pub fn sha512(data_size: usize, id: impl Into<String>) -> Self {
+        Self::with_hash_type(data_size, id, HashType::Sha512)
     }
 
     /// Creates a checksum verification benchmark.
     #[must_use]
     pub fn checksum(data_size: usize, id: impl Into<String>) -> Self {
-        Self {
-            data_size,
-            id: id.into(),
-            hash_type: HashType::Checksum,
-            iterations: 1000,
-        }
+        Self::with_hash_type(data_size, id, HashType::Checksum)
+    }
+
+    /// Creates a streaming BLAKE3 benchmark.
+    ///
+    /// Each iteration feeds the buffer to an incremental hasher in
+    /// `chunk_size` pieces, as ingestion does. A `chunk_size` of zero is
+    /// treated as one byte.
+    #[must_use]
+    pub fn streaming(data_size: usize, chunk_size: usize, id: impl Into<String>) -> Self {
+        Self::with_hash_type(data_size, id, HashType::Streaming { chunk_size: chunk_size.max(1) })
+    }
+
+    /// Creates a benchmark comparing BLAKE3, SHA-256, and SHA-512.
+    ///
+    /// Each iteration hashes the buffer once with every algorithm. The result
+    /// reports `<algorithm>_bps` and `<algorithm>_relative` (throughput as a
+    /// fraction of the fastest) for each, plus `fastest_algorithm`. Arrival
+    /// rate pacing does not apply to this variant.
+    #[must_use]
+    pub fn comparison(data_size: usize, id: impl Into<String>) -> Self {
+        Self::with_hash_type(data_size, id, HashType::Comparison)
     }
 
     /// Sets the number of iterations.
@@ -63,6 +117,141 @@ impl HashingBenchmark {
         self.iterations = iterations;
         self
     }
+
+    /// Sets the number of untimed warmup iterations run before timing starts.
+    ///
+    /// Defaults to 10% of the iteration count.
+    #[must_use]
+    pub fn with_warmup(mut self, warmup: usize) -> Self {
+        self.warmup = Some(warmup);
+        self
+    }
+
+    /// Caps the cumulative time spent in the iteration loop.
+    ///
+    /// Once the budget is reached the loop stops early and the `iterations`
+    /// metric reports the number of iterations actually completed.
+    #[must_use]
+    pub fn with_max_total_time(mut self, max_total_time: Duration) -> Self {
+        self.max_total_time = Some(max_total_time);
+        self
+    }
+
+    /// Reports the mean with the top and bottom 5% of samples dropped; see
+    /// [`StandardMetrics::from_samples_with`].
+    #[must_use]
+    pub fn with_trim_outliers(mut self, trim_outliers: bool) -> Self {
+        self.trim_outliers = trim_outliers;
+        self
+    }
+
+    /// Switches to open-loop mode with a fixed arrival rate.
+    ///
+    /// Operations are issued every `1 / ops_per_sec` seconds whether or not
+    /// the previous one has finished, and latencies are measured from each
+    /// operation's scheduled arrival, so they include queue wait. The result
+    /// reports `arrival_rate`, `backlog`, and `kept_up`.
+    ///
+    /// A rate that is not finite and positive is ignored, and the loop
+    /// stays closed.
+    #[must_use]
+    pub fn with_arrival_rate(mut self, ops_per_sec: f64) -> Self {
+        self.arrival_rate = Some(ops_per_sec);
+        self
+    }
+
+    /// Sets the contents of the hashed buffer. Defaults to
+    /// [`DataPattern::Sequential`].
+    #[must_use]
+    pub fn with_data_pattern(mut self, pattern: DataPattern) -> Self {
+        self.data_pattern = pattern;
+        self
+    }
+
+    /// Sets the seed used to generate random and text buffers. Defaults to
+    /// [`DEFAULT_SEED`](super::DEFAULT_SEED).
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Returns the warmup iteration count, defaulting to 10% of iterations.
+    fn warmup_iterations(&self) -> usize {
+        self.warmup.unwrap_or_else(|| super::default_warmup(self.iterations))
+    }
+
+    /// Returns a loop driver honoring the warmup and time budget settings.
+    fn timer(&self) -> IterationTimer {
+        IterationTimer::new(self.iterations, self.warmup_iterations())
+            .with_max_total_time(self.max_total_time)
+    }
+
+    /// Times every algorithm in [`COMPARED_ALGORITHMS`] over the same payload.
+    ///
+    /// The standard metrics describe a whole iteration, so throughput counts
+    /// one pass per algorithm.
+    fn run_comparison(&self, payload: &Payload) -> BenchmarkResult {
+        use vault_crypto::hash;
+
+        let mut timer = self.timer();
+        let mut algorithm_ms = [0.0f64; COMPARED_ALGORITHMS.len()];
+        let warmup = self.warmup_iterations();
+
+        while timer.next_iteration().is_some() {
+            let start = Instant::now();
+            let mut elapsed = [0.0f64; COMPARED_ALGORITHMS.len()];
+            for (slot, (_, _, algorithm)) in elapsed.iter_mut().zip(COMPARED_ALGORITHMS) {
+                let algorithm_start = Instant::now();
+                for chunk in payload.chunks() {
+                    black_box(hash(algorithm, black_box(chunk)));
+                }
+                *slot = algorithm_start.elapsed().as_secs_f64() * 1000.0;
+            }
+            if !timer.is_warmup() {
+                for (total, ms) in algorithm_ms.iter_mut().zip(elapsed) {
+                    *total += ms;
+                }
+            }
+            timer.record(start);
+        }
+
+        let mut times = timer.into_samples();
+        let dropped_samples = drop_non_finite(&mut times);
+        let iterations = times.len();
+        let hashed_bytes = (self.data_size * iterations) as f64;
+        let throughputs = algorithm_ms.map(|ms| (hashed_bytes / ms) * 1000.0);
+        let fastest = (0..throughputs.len())
+            .max_by(|&a, &b| throughputs[a].total_cmp(&throughputs[b]))
+            .unwrap_or(0);
+
+        let pass_bytes = (self.data_size * COMPARED_ALGORITHMS.len()) as u64;
+        let mut metrics = StandardMetrics::from_samples_with(
+            &times,
+            pass_bytes,
+            iterations as u64,
+            self.trim_outliers,
+        )
+        .with_data_size(self.data_size as u64)
+        .with_custom("algorithm", "comparison")
+        .with_custom("data_pattern", self.data_pattern.name())
+        .with_custom("fastest_algorithm", COMPARED_ALGORITHMS[fastest].1)
+        .with_custom("warmup_iterations", warmup as u64)
+        .with_custom("dropped_samples", dropped_samples as u64);
+        for ((key, _, _), bps) in COMPARED_ALGORITHMS.iter().zip(throughputs) {
+            metrics = metrics
+                .with_custom(format!("{key}_bps"), bps)
+                .with_custom(format!("{key}_relative"), bps / throughputs[fastest]);
+        }
+
+        if payload.is_tiled() {
+            metrics = metrics
+                .with_custom("tiled", true)
+                .with_custom("tile_count", payload.tile_count() as u64);
+        }
+
+        BenchmarkResult::new(&self.id, metrics.to_json_value())
+    }
 }
 
 #[async_trait]
@@ -75,7 +264,10 @@ impl super::BenchTarget for HashingBenchmark {
         match self.hash_type {
             HashType::Blake3 => "BLAKE3 Hashing",
             HashType::Sha256 => "SHA-256 Hashing",
+            HashType::Sha512 => "SHA-512 Hashing",
             HashType::Checksum => "Checksum Verification",
+            HashType::Streaming { .. } => "Streaming BLAKE3 Hashing",
+            HashType::Comparison => "Hash Algorithm Comparison",
         }
     }
 
@@ -83,76 +275,156 @@ impl super::BenchTarget for HashingBenchmark {
         match self.hash_type {
             HashType::Blake3 => "Measures BLAKE3 hashing throughput",
             HashType::Sha256 => "Measures SHA-256 hashing throughput",
+            HashType::Sha512 => "Measures SHA-512 hashing throughput",
             HashType::Checksum => "Measures checksum computation and verification",
+            HashType::Streaming { .. } => "Measures incremental BLAKE3 hashing over fixed-size chunks",
+            HashType::Comparison => "Compares BLAKE3, SHA-256, and SHA-512 throughput on one buffer",
         }
     }
 
     async fn run(&self) -> BenchmarkResult {
-        use vault_crypto::{blake3, sha256, Checksum, HashAlgorithm};
+        use vault_crypto::{blake3, hash, sha256, Checksum, HashAlgorithm};
 
-        // Generate test data
-        let data: Vec<u8> = (0..self.data_size).map(|i| (i % 256) as u8).collect();
+        // Generate test data, tiled if it exceeds the allocation limit
+        let payload = Payload::new(self.data_size, self.data_pattern, self.seed);
 
-        let mut times = Vec::with_capacity(self.iterations);
+        let mut timer = self.timer();
+        let mut cycles = CycleTally::new();
+        let mut pacer = self.arrival_rate.and_then(Pacer::new);
+        let warmup = self.warmup_iterations();
 
         match self.hash_type {
             HashType::Blake3 => {
-                for _ in 0..self.iterations {
-                    let start = Instant::now();
-                    let _hash = blake3(&data);
-                    times.push(start.elapsed().as_secs_f64() * 1000.0);
+                while timer.next_iteration().is_some() {
+                    if timer.is_first_timed() {
+                        cycles.reset();
+                    }
+                    let start = arrival(&mut pacer).await;
+                    for chunk in payload.chunks() {
+                        let cycle_start = cycles.start();
+                        // black_box keeps the optimizer from proving the digest
+                        // unused and skipping the hash it is meant to measure
+                        black_box(blake3(black_box(chunk)));
+                        cycles.record(cycle_start, chunk.len());
+                    }
+                    timer.record(start);
                 }
             }
             HashType::Sha256 => {
-                for _ in 0..self.iterations {
-                    let start = Instant::now();
-                    let _hash = sha256(&data);
-                    times.push(start.elapsed().as_secs_f64() * 1000.0);
+                while timer.next_iteration().is_some() {
+                    if timer.is_first_timed() {
+                        cycles.reset();
+                    }
+                    let start = arrival(&mut pacer).await;
+                    for chunk in payload.chunks() {
+                        let cycle_start = cycles.start();
+                        black_box(sha256(black_box(chunk)));
+                        cycles.record(cycle_start, chunk.len());
+                    }
+                    timer.record(start);
+                }
+            }
+            HashType::Sha512 => {
+                while timer.next_iteration().is_some() {
+                    if timer.is_first_timed() {
+                        cycles.reset();
+                    }
+                    let start = arrival(&mut pacer).await;
+                    for chunk in payload.chunks() {
+                        let cycle_start = cycles.start();
+                        black_box(hash(HashAlgorithm::Sha512, black_box(chunk)));
+                        cycles.record(cycle_start, chunk.len());
+                    }
+                    timer.record(start);
                 }
             }
             HashType::Checksum => {
-                // Pre-compute checksum for verification
-                let checksum = Checksum::compute(HashAlgorithm::Blake3, &data);
+                // Pre-compute checksums for verification
+                let checksums: Vec<(&[u8], Checksum)> = payload
+                    .chunks()
+                    .map(|chunk| (chunk, Checksum::compute(HashAlgorithm::Blake3, chunk)))
+                    .collect();
 
-                for _ in 0..self.iterations {
-                    let start = Instant::now();
-                    let _valid = checksum.verify(&data);
-                    times.push(start.elapsed().as_secs_f64() * 1000.0);
+                while timer.next_iteration().is_some() {
+                    if timer.is_first_timed() {
+                        cycles.reset();
+                    }
+                    let start = arrival(&mut pacer).await;
+                    for (chunk, checksum) in &checksums {
+                        let cycle_start = cycles.start();
+                        black_box(checksum.verify(black_box(chunk)));
+                        cycles.record(cycle_start, chunk.len());
+                    }
+                    timer.record(start);
+                }
+            }
+            HashType::Comparison => return self.run_comparison(&payload),
+            HashType::Streaming { chunk_size } => {
+                while timer.next_iteration().is_some() {
+                    if timer.is_first_timed() {
+                        cycles.reset();
+                    }
+                    let start = arrival(&mut pacer).await;
+                    // One hasher spans all tiles, so the stream covers the full data size
+                    let cycle_start = cycles.start();
+                    let mut hasher = ::blake3::Hasher::new();
+                    for tile in payload.chunks() {
+                        for piece in tile.chunks(chunk_size) {
+                            hasher.update(black_box(piece));
+                        }
+                    }
+                    black_box(hasher.finalize());
+                    cycles.record(cycle_start, self.data_size);
+                    timer.record(start);
                 }
             }
         }
+        let mut times = timer.into_samples();
 
         // Calculate statistics
-        let avg_ms = times.iter().sum::<f64>() / self.iterations as f64;
-        let throughput_bps = (self.data_size as f64 / avg_ms) * 1000.0;
-        let ops_per_second = 1000.0 / avg_ms;
-
-        // Sort for percentiles
-        times.sort_by(|a, b| a.partial_cmp(b).unwrap());
-
-        let p50_idx = self.iterations / 2;
-        let p95_idx = (self.iterations as f64 * 0.95) as usize;
-        let p99_idx = (self.iterations as f64 * 0.99) as usize;
+        let dropped_samples = drop_non_finite(&mut times);
+        let iterations = times.len();
 
         let algorithm = match self.hash_type {
             HashType::Blake3 => "BLAKE3",
             HashType::Sha256 => "SHA-256",
+            HashType::Sha512 => "SHA-512",
             HashType::Checksum => "BLAKE3-Checksum",
+            HashType::Streaming { .. } => "BLAKE3-Streaming",
+            HashType::Comparison => "comparison",
         };
 
-        let metrics = StandardMetrics::new()
-            .with_duration_ms(avg_ms)
-            .with_data_size(self.data_size as u64)
-            .with_iterations(self.iterations as u64)
-            .with_bytes_per_second(throughput_bps)
-            .with_ops_per_second(ops_per_second)
-            .with_latencies(
-                times[p50_idx],
-                times[p95_idx.min(self.iterations - 1)],
-                times[p99_idx.min(self.iterations - 1)],
-            )
+        let mut metrics = StandardMetrics::from_samples_with(
+            &times,
+            self.data_size as u64,
+            iterations as u64,
+            self.trim_outliers,
+        );
+        let throughput_bps = metrics.bytes_per_second.unwrap_or_default();
+        metrics = metrics
             .with_custom("algorithm", algorithm)
-            .with_custom("throughput_bps", throughput_bps);
+            .with_custom("data_pattern", self.data_pattern.name())
+            .with_custom("throughput_bps", throughput_bps)
+            .with_custom("warmup_iterations", warmup as u64)
+            .with_custom("dropped_samples", dropped_samples as u64);
+
+        if let Some(cycles_per_byte) = cycles.cycles_per_byte() {
+            metrics = metrics.with_custom("cycles_per_byte", cycles_per_byte);
+        }
+
+        if let HashType::Streaming { chunk_size } = self.hash_type {
+            metrics = metrics.with_custom("chunk_size", chunk_size as u64);
+        }
+
+        if let Some(pacer) = &pacer {
+            metrics = pacer.annotate(metrics);
+        }
+
+        if payload.is_tiled() {
+            metrics = metrics
+                .with_custom("tiled", true)
+                .with_custom("tile_count", payload.tile_count() as u64);
+        }
 
         BenchmarkResult::new(&self.id, metrics.to_json_value())
     }
@@ -185,6 +457,45 @@ mod tests {
         assert!(result.metrics["algorithm"].as_str().unwrap() == "SHA-256");
     }
 
+    #[tokio::test]
+    async fn test_sha512_benchmark() {
+        let benchmark = HashingBenchmark::sha512(1024, "test-sha512")
+            .with_iterations(10);
+
+        let result = benchmark.run().await;
+
+        assert_eq!(result.target_id, "test-sha512");
+        assert_eq!(result.metrics["algorithm"], "SHA-512");
+    }
+
+    #[tokio::test]
+    async fn test_streaming_benchmark() {
+        let benchmark = HashingBenchmark::streaming(4096, 1000, "test-streaming")
+            .with_iterations(10);
+
+        let result = benchmark.run().await;
+
+        assert_eq!(result.metrics["algorithm"], "BLAKE3-Streaming");
+        assert_eq!(result.metrics["chunk_size"], 1000);
+        assert!(result.metrics["bytes_per_second"].as_f64().unwrap() > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_comparison_benchmark() {
+        let benchmark = HashingBenchmark::comparison(4096, "test-comparison")
+            .with_iterations(5);
+
+        let result = benchmark.run().await;
+
+        let fastest = result.metrics["fastest_algorithm"].as_str().unwrap();
+        assert!(["BLAKE3", "SHA-256", "SHA-512"].contains(&fastest));
+        for key in ["blake3", "sha256", "sha512"] {
+            assert!(result.metrics[format!("{key}_bps")].as_f64().unwrap() > 0.0);
+            let relative = result.metrics[format!("{key}_relative")].as_f64().unwrap();
+            assert!(relative > 0.0 && relative <= 1.0);
+        }
+    }
+
     #[tokio::test]
     async fn test_checksum_benchmark() {
         let benchmark = HashingBenchmark::checksum(1024, "test-checksum")
@@ -195,4 +506,19 @@ mod tests {
         assert_eq!(result.target_id, "test-checksum");
         assert!(result.metrics["ops_per_second"].as_f64().unwrap() > 0.0);
     }
+
+    #[tokio::test]
+    async fn test_warmup_excluded_from_samples() {
+        let defaulted = HashingBenchmark::blake3(1024, "test-warmup").with_iterations(20);
+        let result = defaulted.run().await;
+        assert_eq!(result.metrics["iterations"].as_u64().unwrap(), 20);
+        assert_eq!(result.metrics["warmup_iterations"].as_u64().unwrap(), 2);
+
+        let explicit = HashingBenchmark::blake3(1024, "test-warmup")
+            .with_iterations(20)
+            .with_warmup(5);
+        let result = explicit.run().await;
+        assert_eq!(result.metrics["iterations"].as_u64().unwrap(), 20);
+        assert_eq!(result.metrics["warmup_iterations"].as_u64().unwrap(), 5);
+    }
 }
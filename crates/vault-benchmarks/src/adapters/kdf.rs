@@ -0,0 +1,222 @@
+//! Key derivation benchmark adapter.
+//!
+//! Password-based key derivation is slow on purpose, so the number that
+//! matters is how long a single derivation takes (it sits directly on the
+//! login path), not throughput. Results report latency percentiles over many
+//! derivations and record the work factor that produced them.
+
+use super::timing::IterationTimer;
+use crate::stats::drop_non_finite;
+use crate::{BenchmarkResult, StandardMetrics};
+use async_trait::async_trait;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Key derivation function to benchmark.
+///
+/// Only the functions `vault_crypto` exposes are listed.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Kdf {
+    /// Argon2id with the parameters used by `vault_crypto::derive_key_argon2`.
+    #[default]
+    Argon2id,
+}
+
+impl Kdf {
+    /// Returns the name recorded in the `kdf` metric.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Argon2id => "argon2id",
+        }
+    }
+}
+
+/// Salt length in bytes, matching `vault_crypto::random_salt`.
+const SALT_LEN: usize = 32;
+
+/// Key derivation benchmark measuring single-call derivation latency.
+pub struct KeyDerivationBenchmark {
+    kdf: Kdf,
+    output_len: usize,
+    id: String,
+    iterations: usize,
+    warmup: Option<usize>,
+    max_total_time: Option<Duration>,
+    trim_outliers: bool,
+    seed: u64,
+}
+
+impl KeyDerivationBenchmark {
+    /// Creates a benchmark deriving `output_len`-byte keys with `kdf`.
+    #[must_use]
+    pub fn new(kdf: Kdf, output_len: usize, id: impl Into<String>) -> Self {
+        Self {
+            kdf,
+            output_len,
+            id: id.into(),
+            iterations: 20,
+            warmup: None,
+            max_total_time: None,
+            trim_outliers: false,
+            seed: super::DEFAULT_SEED,
+        }
+    }
+
+    /// Sets the number of iterations.
+    #[must_use]
+    pub fn with_iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Sets the number of untimed warmup iterations run before timing starts.
+    ///
+    /// Defaults to 10% of the iteration count.
+    #[must_use]
+    pub fn with_warmup(mut self, warmup: usize) -> Self {
+        self.warmup = Some(warmup);
+        self
+    }
+
+    /// Caps the cumulative time spent in the iteration loop.
+    ///
+    /// Once the budget is reached the loop stops early and the `iterations`
+    /// metric reports the number of iterations actually completed.
+    #[must_use]
+    pub fn with_max_total_time(mut self, max_total_time: Duration) -> Self {
+        self.max_total_time = Some(max_total_time);
+        self
+    }
+
+    /// Reports the mean with the top and bottom 5% of samples dropped; see
+    /// [`StandardMetrics::from_samples_with`].
+    #[must_use]
+    pub fn with_trim_outliers(mut self, trim_outliers: bool) -> Self {
+        self.trim_outliers = trim_outliers;
+        self
+    }
+
+    /// Sets the seed the salt is drawn from. Defaults to
+    /// [`DEFAULT_SEED`](super::DEFAULT_SEED).
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Returns a salt the size `vault_crypto::random_salt` produces, drawn
+    /// from the seed.
+    fn salt(&self) -> Vec<u8> {
+        let mut salt = vec![0u8; SALT_LEN];
+        StdRng::seed_from_u64(self.seed).fill_bytes(&mut salt);
+        salt
+    }
+
+    /// Returns the warmup iteration count, defaulting to 10% of iterations.
+    fn warmup_iterations(&self) -> usize {
+        self.warmup.unwrap_or_else(|| super::default_warmup(self.iterations))
+    }
+
+    /// Returns a loop driver honoring the warmup and time budget settings.
+    fn timer(&self) -> IterationTimer {
+        IterationTimer::new(self.iterations, self.warmup_iterations())
+            .with_max_total_time(self.max_total_time)
+    }
+}
+
+#[async_trait]
+impl super::BenchTarget for KeyDerivationBenchmark {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        match self.kdf {
+            Kdf::Argon2id => "Argon2id Key Derivation",
+        }
+    }
+
+    fn description(&self) -> &str {
+        "Measures single-call password key derivation latency at the configured work factor"
+    }
+
+    async fn run(&self) -> BenchmarkResult {
+        use vault_crypto::{
+            derive_key_argon2, ARGON2_ITERATIONS, ARGON2_MEMORY_KIB, ARGON2_PARALLELISM,
+        };
+
+        let password = b"correct horse battery staple";
+        let salt = self.salt();
+
+        let warmup = self.warmup_iterations();
+        let mut timer = self.timer();
+
+        while timer.next_iteration().is_some() {
+            let start = Instant::now();
+            let derived = match self.kdf {
+                Kdf::Argon2id => derive_key_argon2(black_box(password), &salt, self.output_len),
+            };
+            if let Err(e) = black_box(derived) {
+                return BenchmarkResult::failed(&self.id, format!("Key derivation failed: {e}"));
+            }
+            timer.record(start);
+        }
+        let mut times = timer.into_samples();
+
+        // Calculate statistics; each sample is one derivation
+        let dropped_samples = drop_non_finite(&mut times);
+        let iterations = times.len();
+
+        let metrics = StandardMetrics::from_samples_with(
+            &times,
+            self.output_len as u64,
+            iterations as u64,
+            self.trim_outliers,
+        )
+        .with_custom("kdf", self.kdf.name())
+        .with_custom("memory_kib", u64::from(ARGON2_MEMORY_KIB))
+        .with_custom("time_cost", u64::from(ARGON2_ITERATIONS))
+        .with_custom("parallelism", u64::from(ARGON2_PARALLELISM))
+        .with_custom("salt_bytes", salt.len() as u64)
+        .with_custom("seed", self.seed)
+        .with_custom("warmup_iterations", warmup as u64)
+        .with_custom("dropped_samples", dropped_samples as u64);
+
+        BenchmarkResult::new(&self.id, metrics.to_json_value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::BenchTarget;
+
+    #[tokio::test]
+    async fn test_key_derivation_benchmark() {
+        let benchmark = KeyDerivationBenchmark::new(Kdf::Argon2id, 32, "test-kdf")
+            .with_iterations(2)
+            .with_warmup(0);
+
+        let result = benchmark.run().await;
+
+        assert!(!result.is_failed(), "{:?}", result.error);
+        assert_eq!(result.metrics["iterations"].as_u64().unwrap(), 2);
+        assert_eq!(result.metrics["kdf"], "argon2id");
+        assert_eq!(result.metrics["memory_kib"], 65536);
+        assert_eq!(result.metrics["time_cost"], 3);
+        assert_eq!(result.metrics["parallelism"], 4);
+        assert!(result.metrics["latency_p50_ms"].as_f64().unwrap() > 0.0);
+    }
+
+    #[test]
+    fn test_seeded_salt_is_reproducible() {
+        let build =
+            |seed| KeyDerivationBenchmark::new(Kdf::Argon2id, 32, "test-seed").with_seed(seed);
+        assert_eq!(build(7).salt(), build(7).salt());
+        assert_ne!(build(7).salt(), build(8).salt());
+        assert_eq!(build(7).salt().len(), vault_crypto::random_salt().len());
+    }
+}
@@ -0,0 +1,207 @@
+//! Key generation benchmark adapter.
+//!
+//! Benchmarks AES-256-GCM data key generation in isolation, separating the
+//! per-object key setup cost of envelope encryption from bulk cipher cost.
+//!
+//! Keys are drawn the way `AesGcmCipher::generate_key` draws them, but from a
+//! [`StdRng`] seeded with the target's seed instead of the thread-local
+//! generator. Both are ChaCha12, so the cost is the same and the generated
+//! keys are reproducible from run to run.
+
+use super::timing::IterationTimer;
+use crate::stats::drop_non_finite;
+use crate::{BenchmarkResult, StandardMetrics};
+use async_trait::async_trait;
+use rand::rngs::StdRng;
+use rand::{RngCore, SeedableRng};
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+/// Key generation benchmark measuring keys generated per second.
+pub struct KeyGenBenchmark {
+    id: String,
+    iterations: usize,
+    warmup: Option<usize>,
+    max_total_time: Option<Duration>,
+    trim_outliers: bool,
+    seed: u64,
+}
+
+impl KeyGenBenchmark {
+    /// Creates a new AES-256 key generation benchmark.
+    #[must_use]
+    pub fn new(id: impl Into<String>) -> Self {
+        Self {
+            id: id.into(),
+            iterations: 1000,
+            warmup: None,
+            max_total_time: None,
+            trim_outliers: false,
+            seed: super::DEFAULT_SEED,
+        }
+    }
+
+    /// Sets the number of iterations.
+    #[must_use]
+    pub fn with_iterations(mut self, iterations: usize) -> Self {
+        self.iterations = iterations;
+        self
+    }
+
+    /// Sets the number of untimed warmup iterations run before timing starts.
+    ///
+    /// Defaults to 10% of the iteration count.
+    #[must_use]
+    pub fn with_warmup(mut self, warmup: usize) -> Self {
+        self.warmup = Some(warmup);
+        self
+    }
+
+    /// Caps the cumulative time spent in the iteration loop.
+    ///
+    /// Once the budget is reached the loop stops early and the `iterations`
+    /// metric reports the number of iterations actually completed.
+    #[must_use]
+    pub fn with_max_total_time(mut self, max_total_time: Duration) -> Self {
+        self.max_total_time = Some(max_total_time);
+        self
+    }
+
+    /// Reports the mean with the top and bottom 5% of samples dropped; see
+    /// [`StandardMetrics::from_samples_with`].
+    #[must_use]
+    pub fn with_trim_outliers(mut self, trim_outliers: bool) -> Self {
+        self.trim_outliers = trim_outliers;
+        self
+    }
+
+    /// Sets the seed of the generator keys are drawn from. Defaults to
+    /// [`DEFAULT_SEED`](super::DEFAULT_SEED).
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Returns the generator keys are drawn from, seeded with `seed`.
+    fn key_rng(&self) -> StdRng {
+        StdRng::seed_from_u64(self.seed)
+    }
+
+    /// Returns the warmup iteration count, defaulting to 10% of iterations.
+    fn warmup_iterations(&self) -> usize {
+        self.warmup.unwrap_or_else(|| super::default_warmup(self.iterations))
+    }
+
+    /// Returns a loop driver honoring the warmup and time budget settings.
+    fn timer(&self) -> IterationTimer {
+        IterationTimer::new(self.iterations, self.warmup_iterations())
+            .with_max_total_time(self.max_total_time)
+    }
+}
+
+#[async_trait]
+impl super::BenchTarget for KeyGenBenchmark {
+    fn id(&self) -> &str {
+        &self.id
+    }
+
+    fn name(&self) -> &str {
+        "AES-256 Key Generation"
+    }
+
+    fn description(&self) -> &str {
+        "Measures AES-256-GCM data key generation throughput"
+    }
+
+    async fn run(&self) -> BenchmarkResult {
+        use vault_crypto::{KeyAlgorithm, SecureBytes};
+
+        let key_len = KeyAlgorithm::Aes256Gcm.key_size();
+        let mut rng = self.key_rng();
+        let mut key_size = 0;
+
+        let warmup = self.warmup_iterations();
+        let mut timer = self.timer();
+
+        while timer.next_iteration().is_some() {
+            let start = Instant::now();
+            // Keep the key observable so generation can't be optimized away
+            let mut key = vec![0u8; key_len];
+            rng.fill_bytes(&mut key);
+            let key = black_box(SecureBytes::new(key));
+            timer.record(start);
+            key_size = key.len();
+        }
+        let mut times = timer.into_samples();
+
+        // Calculate statistics
+        let dropped_samples = drop_non_finite(&mut times);
+        let iterations = times.len();
+
+        let metrics = StandardMetrics::from_samples_with(
+            &times,
+            key_size as u64,
+            iterations as u64,
+            self.trim_outliers,
+        );
+        let keys_per_second = metrics.ops_per_second.unwrap_or_default();
+        let metrics = metrics
+            .with_custom("keys_per_second", keys_per_second)
+            .with_custom("algorithm", "AES-256-GCM")
+            .with_custom("seed", self.seed)
+            .with_custom("warmup_iterations", warmup as u64)
+            .with_custom("dropped_samples", dropped_samples as u64);
+
+        BenchmarkResult::new(&self.id, metrics.to_json_value())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::timing::inject_nan_samples;
+    use crate::adapters::BenchTarget;
+
+    #[tokio::test]
+    async fn test_keygen_benchmark() {
+        let benchmark = KeyGenBenchmark::new("test-keygen").with_iterations(20);
+
+        let result = benchmark.run().await;
+
+        assert_eq!(result.target_id, "test-keygen");
+        assert_eq!(result.metrics["data_size_bytes"].as_u64().unwrap(), 32);
+        assert!(result.metrics["keys_per_second"].as_f64().unwrap() > 0.0);
+        assert_eq!(result.metrics["keys_per_second"], result.metrics["ops_per_second"]);
+        assert!(result.metrics["cv"].is_number());
+        assert_eq!(result.metrics["seed"], 0);
+    }
+
+    #[tokio::test]
+    async fn test_nan_sample_is_dropped() {
+        inject_nan_samples(1);
+        let benchmark = KeyGenBenchmark::new("test-keygen-nan").with_iterations(10).with_warmup(0);
+
+        let result = benchmark.run().await;
+
+        assert!(!result.is_failed(), "{:?}", result.error);
+        assert_eq!(result.metrics["dropped_samples"], 1);
+        assert_eq!(result.metrics["iterations"], 9);
+        assert!(result.metrics["duration_ms"].as_f64().unwrap().is_finite());
+    }
+
+    #[test]
+    fn test_seeded_keys_are_reproducible() {
+        let first_key = |benchmark: &KeyGenBenchmark| {
+            let mut key = [0u8; 32];
+            benchmark.key_rng().fill_bytes(&mut key);
+            key
+        };
+        let a = KeyGenBenchmark::new("test-seed-a").with_seed(7);
+        let b = KeyGenBenchmark::new("test-seed-b").with_seed(7);
+        let other = KeyGenBenchmark::new("test-seed-other").with_seed(8);
+
+        assert_eq!(first_key(&a), first_key(&b));
+        assert_ne!(first_key(&a), first_key(&other));
+    }
+}
@@ -8,14 +8,38 @@ mod encryption;
 mod hashing;
 mod anonymization;
 mod storage;
+mod keygen;
+mod kdf;
+mod pipeline;
+mod compression;
+mod pacing;
+mod payload;
+mod spec;
+mod ordering;
+mod context;
+mod timing;
 
-pub use encryption::EncryptionBenchmark;
+pub use encryption::{CipherKind, EncryptionBenchmark, EncryptionMode};
 pub use hashing::HashingBenchmark;
 pub use anonymization::AnonymizationBenchmark;
 pub use storage::StorageBenchmark;
+pub use keygen::KeyGenBenchmark;
+pub use kdf::{Kdf, KeyDerivationBenchmark};
+pub use pipeline::PipelineBenchmark;
+pub use compression::{Codec, CompressionBenchmark, DataKind};
+pub use payload::{max_buffer_size, DataPattern, DEFAULT_MAX_BUFFER_SIZE, MAX_BUFFER_SIZE_ENV};
+pub use context::RunContext;
+pub use ordering::order_by_dependencies;
+pub(crate) use ordering::dependency_waves;
+pub use spec::{
+    parse_size, size_label, size_sweep_specs, TargetOverride, TargetOverrides, TargetSpec,
+    DEFAULT_SEED, DEFAULT_TARGETS_FILE, SIZE_SWEEP_FAMILIES,
+};
+use spec::default_warmup;
 
 use crate::BenchmarkResult;
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 
 /// Canonical benchmark target trait.
 ///
@@ -37,9 +61,28 @@ pub trait BenchTarget: Send + Sync {
         ""
     }
 
+    /// Returns the ids of targets that must run before this one.
+    ///
+    /// The runner orders targets with [`order_by_dependencies`], so a target
+    /// can rely on its dependencies having completed first.
+    fn depends_on(&self) -> &[&str] {
+        &[]
+    }
+
     /// Runs the benchmark and returns the result.
     async fn run(&self) -> BenchmarkResult;
 
+    /// Runs the benchmark with access to state shared across the run.
+    ///
+    /// The default ignores the context and calls [`run`](Self::run). Targets
+    /// that publish or consume shared fixtures override this; see
+    /// [`RunContext`] for the sharing contract. Such targets should implement
+    /// `run` by delegating here with an empty context.
+    async fn run_with_context(&self, ctx: &RunContext) -> BenchmarkResult {
+        let _ = ctx;
+        self.run().await
+    }
+
     /// Performs any necessary setup before running the benchmark.
     async fn setup(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
         Ok(())
@@ -51,34 +94,318 @@ pub trait BenchTarget: Send + Sync {
     }
 }
 
-/// Registry of all benchmark targets.
+/// Identifying details of a benchmark target, for listings and tooling.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct TargetInfo {
+    /// Unique target identifier.
+    pub id: String,
+    /// Human-readable name.
+    pub name: String,
+    /// What the target measures.
+    pub description: String,
+}
+
+impl TargetInfo {
+    /// Captures the id, name, and description of a target.
+    #[must_use]
+    pub fn from_target(target: &dyn BenchTarget) -> Self {
+        Self {
+            id: target.id().to_string(),
+            name: target.name().to_string(),
+            description: target.description().to_string(),
+        }
+    }
+}
+
+/// Built-in target specifications.
 ///
-/// Returns a vector of all available benchmark targets implementing
-/// the canonical BenchTarget trait.
-pub fn all_targets() -> Vec<Box<dyn BenchTarget>> {
+/// This table is the fallback used when no overrides are configured; see
+/// [`TargetOverrides`] for tuning it from a config file.
+pub fn default_specs() -> Vec<TargetSpec> {
+    const KB: usize = 1024;
+    const MB: usize = 1024 * 1024;
+
     vec![
         // Encryption benchmarks
-        Box::new(EncryptionBenchmark::new(1024, "encryption-1kb")),
-        Box::new(EncryptionBenchmark::new(1024 * 1024, "encryption-1mb")),
-        Box::new(EncryptionBenchmark::new(10 * 1024 * 1024, "encryption-10mb")),
+        TargetSpec::new("encryption-1kb", KB, 100, encryption),
+        TargetSpec::new("encryption-1mb", MB, 100, encryption),
+        TargetSpec::new("encryption-10mb", 10 * MB, 100, encryption),
+        TargetSpec::new("encryption-chacha20-1mb", MB, 100, encryption_chacha20),
+        TargetSpec::new("decryption-1mb", MB, 100, decryption),
+        TargetSpec::new("decryption-batch-small", 256, 100, decryption_batch),
+
+        // Key generation benchmarks (size is the key length)
+        TargetSpec::new("keygen-aes256", 32, 1000, keygen_aes256),
+        TargetSpec::new("key-derivation-argon2id", 32, 20, key_derivation_argon2id),
 
         // Hashing benchmarks
-        Box::new(HashingBenchmark::blake3(1024 * 1024, "hashing-blake3-1mb")),
-        Box::new(HashingBenchmark::sha256(1024 * 1024, "hashing-sha256-1mb")),
-        Box::new(HashingBenchmark::checksum(1024 * 1024, "checksum-verification-1mb")),
+        TargetSpec::new("hashing-blake3-1mb", MB, 1000, hashing_blake3),
+        TargetSpec::new("hashing-sha256-1mb", MB, 1000, hashing_sha256),
+        TargetSpec::new("hashing-sha512-1mb", MB, 1000, hashing_sha512),
+        TargetSpec::new("hashing-blake3-streaming-1mb", MB, 1000, hashing_blake3_streaming),
+        // Each iteration hashes the buffer once per algorithm
+        TargetSpec::new("hashing-comparison-1mb", MB, 100, hashing_comparison),
+        TargetSpec::new("checksum-verification-1mb", MB, 1000, checksum_verification),
 
         // Anonymization benchmarks
-        Box::new(AnonymizationBenchmark::new(100, "anonymization-100-records")),
-        Box::new(AnonymizationBenchmark::new(1000, "anonymization-1000-records")),
-        Box::new(AnonymizationBenchmark::pii_detection(1000, "pii-detection-1000-records")),
+        TargetSpec::new("anonymization-100-records", 100, 10, anonymization),
+        TargetSpec::new("anonymization-1000-records", 1000, 10, anonymization),
+        TargetSpec::new("pii-detection-1000-records", 1000, 10, pii_detection),
+        // Building an anonymizer per record is slow, so this runs fewer iterations
+        TargetSpec::new("anonymization-reuse-1000-records", 1000, 3, anonymization_reuse),
 
         // Storage benchmarks
-        Box::new(StorageBenchmark::write(1024 * 1024, "storage-write-1mb")),
-        Box::new(StorageBenchmark::read(1024 * 1024, "storage-read-1mb")),
-        Box::new(StorageBenchmark::content_addressing(1024 * 1024, "content-addressing-1mb")),
+        TargetSpec::new("storage-write-1mb", MB, 100, storage_write),
+        TargetSpec::new("storage-read-1mb", MB, 100, storage_read),
+        TargetSpec::new("storage-read-cold-warm-1mb", MB, 100, storage_read_cold_warm),
+        TargetSpec::new("content-addressing-1mb", MB, 100, content_addressing),
+        TargetSpec::new("storage-mixed-rw-1mb", MB, 100, storage_mixed_rw),
+        // Eviction happens in a bounded write-through cache, not the backend
+        TargetSpec::new("storage-write-under-eviction-1mb", MB, 100, storage_write_under_eviction),
+        // Iterations are per concurrency level, split across that level's tasks
+        TargetSpec::new("storage-write-scaling-1mb", MB, 160, storage_write_scaling),
+        // Size is the per-object size; each iteration writes 1000 objects
+        TargetSpec::new("storage-batch-write-1000", KB, 10, storage_batch_write),
+
+        // Compression benchmarks (size is the uncompressed payload)
+        TargetSpec::new("compression-zstd-1mb", MB, 20, compression_zstd),
+        #[cfg(feature = "gzip")]
+        TargetSpec::new("compression-gzip-1mb", MB, 20, compression_gzip),
+        TargetSpec::new("compression-zstd-json-1mb", MB, 20, compression_zstd_json),
+        TargetSpec::new("compression-zstd-random-1mb", MB, 20, compression_zstd_random),
+
+        // End-to-end benchmarks (size is the record length)
+        TargetSpec::new("pipeline-ingest-1mb", MB, 10, pipeline_ingest),
     ]
 }
 
+/// Applies the run settings every target takes from its spec.
+///
+/// Each adapter implements this once, so factories only build the variant
+/// and cannot drift in which settings they forward.
+trait Configure: Sized {
+    fn configure(self, spec: &TargetSpec) -> Self;
+}
+
+impl Configure for EncryptionBenchmark {
+    fn configure(self, spec: &TargetSpec) -> Self {
+        self.with_seed(spec.seed)
+            .with_data_pattern(spec.data_pattern.unwrap_or(DataPattern::Random))
+            .with_iterations(spec.iterations)
+            .with_trim_outliers(spec.trim_outliers)
+            .with_warmup(spec.warmup_iterations())
+    }
+}
+
+impl Configure for KeyGenBenchmark {
+    fn configure(self, spec: &TargetSpec) -> Self {
+        self.with_seed(spec.seed)
+            .with_iterations(spec.iterations)
+            .with_trim_outliers(spec.trim_outliers)
+            .with_warmup(spec.warmup_iterations())
+    }
+}
+
+impl Configure for KeyDerivationBenchmark {
+    fn configure(self, spec: &TargetSpec) -> Self {
+        self.with_seed(spec.seed)
+            .with_iterations(spec.iterations)
+            .with_trim_outliers(spec.trim_outliers)
+            .with_warmup(spec.warmup_iterations())
+    }
+}
+
+impl Configure for HashingBenchmark {
+    fn configure(self, spec: &TargetSpec) -> Self {
+        self.with_seed(spec.seed)
+            .with_data_pattern(spec.data_pattern.unwrap_or_default())
+            .with_iterations(spec.iterations)
+            .with_trim_outliers(spec.trim_outliers)
+            .with_warmup(spec.warmup_iterations())
+    }
+}
+
+impl Configure for AnonymizationBenchmark {
+    fn configure(self, spec: &TargetSpec) -> Self {
+        self.with_seed(spec.seed)
+            .with_data_pattern(spec.data_pattern)
+            .with_iterations(spec.iterations)
+            .with_trim_outliers(spec.trim_outliers)
+            .with_warmup(spec.warmup_iterations())
+    }
+}
+
+impl Configure for StorageBenchmark {
+    fn configure(self, spec: &TargetSpec) -> Self {
+        self.with_seed(spec.seed)
+            .with_data_pattern(spec.data_pattern.unwrap_or_default())
+            .with_iterations(spec.iterations)
+            .with_trim_outliers(spec.trim_outliers)
+            .with_warmup(spec.warmup_iterations())
+    }
+}
+
+impl Configure for CompressionBenchmark {
+    fn configure(self, spec: &TargetSpec) -> Self {
+        self.with_seed(spec.seed)
+            .with_data_pattern(spec.data_pattern)
+            .with_iterations(spec.iterations)
+            .with_trim_outliers(spec.trim_outliers)
+            .with_warmup(spec.warmup_iterations())
+    }
+}
+
+impl Configure for PipelineBenchmark {
+    fn configure(self, spec: &TargetSpec) -> Self {
+        self.with_seed(spec.seed)
+            .with_data_pattern(spec.data_pattern)
+            .with_iterations(spec.iterations)
+            .with_trim_outliers(spec.trim_outliers)
+            .with_warmup(spec.warmup_iterations())
+    }
+}
+
+fn encryption(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(EncryptionBenchmark::new(spec.size, &spec.id).configure(spec))
+}
+
+fn encryption_chacha20(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(
+        EncryptionBenchmark::with_cipher(spec.size, &spec.id, CipherKind::ChaCha20)
+            .configure(spec),
+    )
+}
+
+fn decryption(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(EncryptionBenchmark::decrypt_only(spec.size, &spec.id).configure(spec))
+}
+
+fn decryption_batch(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(EncryptionBenchmark::decrypt_batch(spec.size, 1000, &spec.id).configure(spec))
+}
+
+fn keygen_aes256(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(KeyGenBenchmark::new(&spec.id).configure(spec))
+}
+
+fn key_derivation_argon2id(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(KeyDerivationBenchmark::new(Kdf::Argon2id, spec.size, &spec.id).configure(spec))
+}
+
+fn hashing_blake3(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(HashingBenchmark::blake3(spec.size, &spec.id).configure(spec))
+}
+
+fn hashing_sha256(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(HashingBenchmark::sha256(spec.size, &spec.id).configure(spec))
+}
+
+fn hashing_sha512(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(HashingBenchmark::sha512(spec.size, &spec.id).configure(spec))
+}
+
+fn hashing_blake3_streaming(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(HashingBenchmark::streaming(spec.size, 64 * 1024, &spec.id).configure(spec))
+}
+
+fn hashing_comparison(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(HashingBenchmark::comparison(spec.size, &spec.id).configure(spec))
+}
+
+fn checksum_verification(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(HashingBenchmark::checksum(spec.size, &spec.id).configure(spec))
+}
+
+fn anonymization(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(AnonymizationBenchmark::new(spec.size, &spec.id).configure(spec))
+}
+
+fn pii_detection(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(AnonymizationBenchmark::pii_detection(spec.size, &spec.id).configure(spec))
+}
+
+fn anonymization_reuse(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(AnonymizationBenchmark::reuse(spec.size, &spec.id).configure(spec))
+}
+
+fn storage_write(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(StorageBenchmark::write(spec.size, &spec.id).configure(spec))
+}
+
+fn storage_read(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(StorageBenchmark::read(spec.size, &spec.id).configure(spec))
+}
+
+fn storage_read_cold_warm(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(StorageBenchmark::cold_warm_read(spec.size, &spec.id).configure(spec))
+}
+
+fn content_addressing(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(StorageBenchmark::content_addressing(spec.size, &spec.id).configure(spec))
+}
+
+fn storage_mixed_rw(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(StorageBenchmark::mixed_read_write(spec.size, 0.8, &spec.id).configure(spec))
+}
+
+fn storage_write_under_eviction(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(
+        StorageBenchmark::write(spec.size, &spec.id)
+            .with_capacity(16 * spec.size)
+            .configure(spec),
+    )
+}
+
+fn storage_write_scaling(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(StorageBenchmark::scaling(spec.size, vec![1, 2, 4, 8, 16], &spec.id).configure(spec))
+}
+
+fn storage_batch_write(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(StorageBenchmark::batch_write(spec.size, 1000, &spec.id).configure(spec))
+}
+
+fn compression_zstd(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(CompressionBenchmark::new(Codec::Zstd, spec.size, &spec.id).configure(spec))
+}
+
+#[cfg(feature = "gzip")]
+fn compression_gzip(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(CompressionBenchmark::new(Codec::Gzip, spec.size, &spec.id).configure(spec))
+}
+
+fn compression_zstd_json(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(
+        CompressionBenchmark::new(Codec::Zstd, spec.size, &spec.id)
+            .with_data_kind(DataKind::Json)
+            .configure(spec),
+    )
+}
+
+fn compression_zstd_random(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(
+        CompressionBenchmark::new(Codec::Zstd, spec.size, &spec.id)
+            .with_data_kind(DataKind::Random)
+            .configure(spec),
+    )
+}
+
+fn pipeline_ingest(spec: &TargetSpec) -> Box<dyn BenchTarget> {
+    Box::new(PipelineBenchmark::new(spec.size, &spec.id).configure(spec))
+}
+
+/// Registry of all benchmark targets.
+///
+/// Returns a vector of all available benchmark targets implementing
+/// the canonical BenchTarget trait, built from [`default_specs`].
+pub fn all_targets() -> Vec<Box<dyn BenchTarget>> {
+    targets_from_specs(&default_specs())
+}
+
+/// Builds benchmark targets from a spec table.
+pub fn targets_from_specs(specs: &[TargetSpec]) -> Vec<Box<dyn BenchTarget>> {
+    specs.iter().map(TargetSpec::build).collect()
+}
+
 /// Returns targets filtered by ID prefix.
 pub fn targets_by_prefix(prefix: &str) -> Vec<Box<dyn BenchTarget>> {
     all_targets()
@@ -112,6 +439,19 @@ mod tests {
         assert_eq!(ids.len(), original_len, "Duplicate target IDs found");
     }
 
+    #[test]
+    fn test_target_info_from_target() {
+        let target = target_by_id("hashing-blake3-1mb").unwrap();
+        let info = TargetInfo::from_target(target.as_ref());
+
+        assert_eq!(info.id, "hashing-blake3-1mb");
+        assert_eq!(info.name, target.name());
+        assert!(!info.description.is_empty());
+
+        let json = serde_json::to_value(&info).unwrap();
+        assert_eq!(json["id"], "hashing-blake3-1mb");
+    }
+
     #[test]
     fn test_target_by_prefix() {
         let encryption_targets = targets_by_prefix("encryption");
@@ -125,4 +465,33 @@ mod tests {
         assert!(target.is_some());
         assert_eq!(target.unwrap().id(), "encryption-1kb");
     }
+
+    #[tokio::test]
+    async fn test_factories_thread_spec_settings() {
+        let ids = [
+            "keygen-aes256",
+            "key-derivation-argon2id",
+            "anonymization-100-records",
+            "compression-zstd-json-1mb",
+            "pipeline-ingest-1mb",
+        ];
+        for mut spec in default_specs().into_iter().filter(|s| ids.contains(&s.id.as_str())) {
+            if !spec.id.starts_with("key") {
+                spec.size = spec.size.min(4096);
+            }
+            spec.iterations = 1;
+            spec.warmup = Some(0);
+            spec.seed = 42;
+            spec.data_pattern = Some(DataPattern::Text);
+            spec.trim_outliers = true;
+
+            let result = spec.build().run().await;
+            assert!(!result.is_failed(), "{}: {:?}", spec.id, result.error);
+            assert_eq!(result.metrics["seed"], 42, "{}", spec.id);
+            assert_eq!(result.metrics["outliers_removed"], 0, "{}", spec.id);
+            if !spec.id.starts_with("key") {
+                assert_eq!(result.metrics["data_pattern"], "text", "{}", spec.id);
+            }
+        }
+    }
 }
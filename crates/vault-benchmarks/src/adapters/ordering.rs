@@ -0,0 +1,157 @@
+//! Dependency ordering for benchmark targets.
+//!
+//! Targets may declare other targets they depend on via
+//! [`BenchTarget::depends_on`](super::BenchTarget::depends_on). Before a run,
+//! [`order_by_dependencies`] sorts the selected targets so every dependency
+//! runs before its dependents.
+
+use super::BenchTarget;
+use std::io;
+
+/// Orders targets so that each runs after all of its dependencies.
+///
+/// The sort is stable: targets with no ordering constraint between them keep
+/// their original relative order. Fails with `InvalidInput` if a target
+/// depends on a target that is not in `targets`, or if the dependencies form
+/// a cycle.
+pub fn order_by_dependencies(
+    targets: Vec<Box<dyn BenchTarget>>,
+) -> io::Result<Vec<Box<dyn BenchTarget>>> {
+    let index_of = |id: &str| targets.iter().position(|t| t.id() == id);
+
+    // deps[i] holds the indices of the targets that target i depends on
+    let mut deps = Vec::with_capacity(targets.len());
+    for target in &targets {
+        let mut indices = Vec::new();
+        for dep in target.depends_on() {
+            let index = index_of(dep).ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!(
+                        "target '{}' depends on '{}', which is not selected",
+                        target.id(),
+                        dep
+                    ),
+                )
+            })?;
+            indices.push(index);
+        }
+        deps.push(indices);
+    }
+
+    let mut done = vec![false; targets.len()];
+    let mut order = Vec::with_capacity(targets.len());
+
+    while order.len() < targets.len() {
+        // Take the earliest remaining target whose dependencies have all run
+        let next = (0..targets.len())
+            .find(|&i| !done[i] && deps[i].iter().all(|&d| done[d]))
+            .ok_or_else(|| {
+                let blocked: Vec<&str> = (0..targets.len())
+                    .filter(|&i| !done[i])
+                    .map(|i| targets[i].id())
+                    .collect();
+                io::Error::new(
+                    io::ErrorKind::InvalidInput,
+                    format!("dependency cycle among targets: {}", blocked.join(", ")),
+                )
+            })?;
+        done[next] = true;
+        order.push(next);
+    }
+
+    let mut slots: Vec<Option<Box<dyn BenchTarget>>> = targets.into_iter().map(Some).collect();
+    Ok(order.into_iter().filter_map(|i| slots[i].take()).collect())
+}
+
+/// Groups dependency-ordered targets into waves that can run concurrently.
+///
+/// Every target lands in the wave after the latest of its dependencies, so
+/// running the waves in sequence honours all dependencies. Each target keeps
+/// its position in `ordered` alongside it.
+pub(crate) fn dependency_waves(
+    ordered: Vec<Box<dyn BenchTarget>>,
+) -> Vec<Vec<(usize, Box<dyn BenchTarget>)>> {
+    let mut levels: Vec<usize> = Vec::with_capacity(ordered.len());
+    for target in &ordered {
+        let level = target
+            .depends_on()
+            .iter()
+            .filter_map(|dep| ordered.iter().position(|t| t.id() == *dep))
+            .map(|i| levels[i] + 1)
+            .max()
+            .unwrap_or(0);
+        levels.push(level);
+    }
+
+    let mut waves: Vec<Vec<(usize, Box<dyn BenchTarget>)>> = Vec::new();
+    for (index, (target, level)) in ordered.into_iter().zip(levels).enumerate() {
+        if waves.len() <= level {
+            waves.resize_with(level + 1, Vec::new);
+        }
+        waves[level].push((index, target));
+    }
+
+    waves
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::BenchmarkResult;
+    use async_trait::async_trait;
+
+    struct Fake {
+        id: &'static str,
+        deps: &'static [&'static str],
+    }
+
+    #[async_trait]
+    impl BenchTarget for Fake {
+        fn id(&self) -> &str {
+            self.id
+        }
+
+        fn depends_on(&self) -> &[&str] {
+            self.deps
+        }
+
+        async fn run(&self) -> BenchmarkResult {
+            BenchmarkResult::new(self.id, serde_json::json!({}))
+        }
+    }
+
+    fn fakes(spec: &[(&'static str, &'static [&'static str])]) -> Vec<Box<dyn BenchTarget>> {
+        spec.iter()
+            .map(|&(id, deps)| Box::new(Fake { id, deps }) as Box<dyn BenchTarget>)
+            .collect()
+    }
+
+    #[test]
+    fn test_order_by_dependencies() {
+        let targets = fakes(&[("read", &["write"]), ("hash", &[]), ("write", &[])]);
+
+        let ordered = order_by_dependencies(targets).unwrap();
+        let ids: Vec<&str> = ordered.iter().map(|t| t.id()).collect();
+        assert_eq!(ids, ["hash", "write", "read"]);
+
+        let cycle = fakes(&[("a", &["b"]), ("b", &["a"]), ("c", &[])]);
+        let err = order_by_dependencies(cycle).err().unwrap();
+        assert!(err.to_string().contains("cycle among targets: a, b"));
+
+        let missing = fakes(&[("read", &["write"])]);
+        assert!(order_by_dependencies(missing).is_err());
+    }
+
+    #[test]
+    fn test_dependency_waves() {
+        let targets = fakes(&[("hash", &[]), ("write", &[]), ("read", &["write"])]);
+
+        let waves = dependency_waves(targets);
+        let ids: Vec<Vec<(usize, &str)>> = waves
+            .iter()
+            .map(|wave| wave.iter().map(|(i, t)| (*i, t.id())).collect())
+            .collect();
+        assert_eq!(ids, [vec![(0, "hash"), (1, "write")], vec![(2, "read")]]);
+    }
+}
@@ -0,0 +1,136 @@
+//! Open-loop pacing for benchmark loops.
+//!
+//! By default adapters run closed-loop: the next operation starts as soon as
+//! the previous one finishes, which measures peak speed but hides queueing.
+//! With a [`Pacer`], operations arrive on a fixed schedule regardless of
+//! completion, and each latency sample is measured from the operation's
+//! scheduled arrival, so time spent waiting behind slower operations counts.
+
+use crate::StandardMetrics;
+use std::time::{Duration, Instant};
+
+/// Issues operation arrivals at a fixed rate.
+pub(crate) struct Pacer {
+    rate: f64,
+    interval: Duration,
+    origin: Option<Instant>,
+    issued: u32,
+    last_scheduled: Option<Instant>,
+    starts: Vec<Instant>,
+    total_wait: Duration,
+}
+
+impl Pacer {
+    /// Creates a pacer issuing `rate` operations per second.
+    ///
+    /// Returns `None` for rates that are not finite and positive, or whose
+    /// interval does not fit a [`Duration`]; callers then run closed-loop.
+    pub(crate) fn new(rate: f64) -> Option<Self> {
+        if !(rate.is_finite() && rate > 0.0) {
+            return None;
+        }
+        let interval = Duration::try_from_secs_f64(1.0 / rate).ok()?;
+        Some(Self {
+            rate,
+            interval,
+            origin: None,
+            issued: 0,
+            last_scheduled: None,
+            starts: Vec::new(),
+            total_wait: Duration::ZERO,
+        })
+    }
+
+    /// Waits for the next scheduled arrival and returns its instant.
+    ///
+    /// If the previous operation overran, this returns immediately with an
+    /// arrival in the past; the caller measures latency from that instant.
+    pub(crate) async fn next_arrival(&mut self) -> Instant {
+        let origin = *self.origin.get_or_insert_with(Instant::now);
+        // A schedule past what Instant can represent degrades to "now"
+        let scheduled = self
+            .interval
+            .checked_mul(self.issued)
+            .and_then(|offset| origin.checked_add(offset))
+            .unwrap_or_else(Instant::now);
+        self.issued = self.issued.saturating_add(1);
+
+        tokio::time::sleep_until(scheduled.into()).await;
+
+        let started = Instant::now();
+        self.total_wait += started.saturating_duration_since(scheduled);
+        self.starts.push(started);
+        self.last_scheduled = Some(scheduled);
+        scheduled
+    }
+
+    /// Number of operations still waiting to start when the last one arrived.
+    ///
+    /// Zero means the system kept up with the arrival rate.
+    pub(crate) fn backlog(&self) -> u64 {
+        // The final arrival itself is excluded; only earlier operations
+        // that had not started by then count as queued.
+        let earlier = &self.starts[..self.starts.len().saturating_sub(1)];
+        self.last_scheduled.map_or(0, |last| {
+            earlier.iter().filter(|&&s| s > last).count() as u64
+        })
+    }
+
+    /// Adds the open-loop metrics to `metrics`.
+    pub(crate) fn annotate(&self, metrics: StandardMetrics) -> StandardMetrics {
+        let backlog = self.backlog();
+        let queue_wait_avg_ms =
+            self.total_wait.as_secs_f64() * 1000.0 / self.starts.len().max(1) as f64;
+
+        metrics
+            .with_custom("load_model", "open_loop")
+            .with_custom("arrival_rate", self.rate)
+            .with_custom("backlog", backlog)
+            .with_custom("kept_up", backlog == 0)
+            .with_custom("queue_wait_avg_ms", queue_wait_avg_ms)
+    }
+}
+
+/// Returns the instant to measure the next operation from.
+///
+/// This is the scheduled arrival when pacing, and now otherwise.
+pub(crate) async fn arrival(pacer: &mut Option<Pacer>) -> Instant {
+    match pacer {
+        Some(pacer) => pacer.next_arrival().await,
+        None => Instant::now(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_pacer_backlog() {
+        // Operations slower than the arrival interval fall behind
+        let mut pacer = Pacer::new(1000.0).unwrap();
+        for _ in 0..5 {
+            let _arrival = pacer.next_arrival().await;
+            std::thread::sleep(Duration::from_millis(3));
+        }
+        assert!(pacer.backlog() > 0);
+
+        // Operations faster than the interval keep up
+        let mut pacer = Pacer::new(200.0).unwrap();
+        for _ in 0..3 {
+            let _arrival = pacer.next_arrival().await;
+        }
+        assert_eq!(pacer.backlog(), 0);
+
+        let json = pacer.annotate(StandardMetrics::new()).to_json_value();
+        assert_eq!(json["arrival_rate"], 200.0);
+        assert_eq!(json["kept_up"], true);
+    }
+
+    #[test]
+    fn test_pacer_rejects_invalid_rates() {
+        for rate in [0.0, -5.0, f64::NAN, f64::INFINITY, f64::MIN_POSITIVE] {
+            assert!(Pacer::new(rate).is_none(), "rate {rate} accepted");
+        }
+    }
+}
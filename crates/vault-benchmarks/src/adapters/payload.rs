@@ -0,0 +1,223 @@
+//! Test payload generation with a guard against giant allocations.
+//!
+//! Adapters never allocate more than [`max_buffer_size`] bytes for a single
+//! payload. Byte-throughput targets (encryption, hashing) whose `data_size`
+//! exceeds the limit process one shared tile repeatedly until `data_size`
+//! bytes have been covered, and report `tiled: true`. Targets that must hold
+//! the whole object (storage) refuse to run instead.
+//!
+//! The limit defaults to [`DEFAULT_MAX_BUFFER_SIZE`] and can be overridden
+//! with the `VAULT_BENCH_MAX_BUFFER_BYTES` environment variable.
+//!
+//! Payload contents follow a [`DataPattern`]. The historical byte ramp is
+//! highly compressible and branch-predictor friendly, so targets whose cost
+//! may depend on content can switch to random or text-like bytes instead.
+
+use rand::rngs::StdRng;
+use rand::{Rng, RngCore, SeedableRng};
+use std::fmt;
+use std::str::FromStr;
+
+/// Default ceiling for a single payload allocation (256 MiB).
+pub const DEFAULT_MAX_BUFFER_SIZE: usize = 256 * 1024 * 1024;
+
+/// Environment variable overriding [`DEFAULT_MAX_BUFFER_SIZE`].
+pub const MAX_BUFFER_SIZE_ENV: &str = "VAULT_BENCH_MAX_BUFFER_BYTES";
+
+/// Returns the active payload size limit in bytes.
+///
+/// Invalid or zero values in [`MAX_BUFFER_SIZE_ENV`] are ignored.
+pub fn max_buffer_size() -> usize {
+    std::env::var(MAX_BUFFER_SIZE_ENV)
+        .ok()
+        .and_then(|v| v.trim().parse::<usize>().ok())
+        .filter(|&v| v > 0)
+        .unwrap_or(DEFAULT_MAX_BUFFER_SIZE)
+}
+
+/// Contents of a generated byte payload.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum DataPattern {
+    /// The repeating ramp `0, 1, ..., 255, 0, 1, ...`.
+    #[default]
+    Sequential,
+    /// All zero bytes.
+    Zeros,
+    /// Uniformly random bytes drawn from the target's seed.
+    Random,
+    /// Space-separated words from a small vocabulary, drawn from the
+    /// target's seed.
+    Text,
+}
+
+impl DataPattern {
+    /// Every pattern, in declaration order.
+    pub const ALL: [Self; 4] = [Self::Sequential, Self::Zeros, Self::Random, Self::Text];
+
+    /// Returns the name recorded in the `data_pattern` metric.
+    #[must_use]
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Sequential => "sequential",
+            Self::Zeros => "zeros",
+            Self::Random => "random",
+            Self::Text => "text",
+        }
+    }
+
+    /// Generates `len` bytes of this pattern.
+    ///
+    /// `seed` only affects [`Random`](Self::Random) and [`Text`](Self::Text);
+    /// the same seed always produces the same bytes.
+    #[must_use]
+    pub fn generate(self, len: usize, seed: u64) -> Vec<u8> {
+        const WORDS: &[&str] = &[
+            "vault", "record", "dataset", "encrypted", "the", "of", "and", "storage", "model",
+            "training", "access", "policy", "a", "to", "content", "version", "lineage", "key",
+        ];
+
+        match self {
+            Self::Sequential => (0..len).map(|i| (i % 256) as u8).collect(),
+            Self::Zeros => vec![0; len],
+            Self::Random => {
+                let mut data = vec![0; len];
+                StdRng::seed_from_u64(seed).fill_bytes(&mut data);
+                data
+            }
+            Self::Text => {
+                let mut rng = StdRng::seed_from_u64(seed);
+                let mut data = Vec::with_capacity(len + 16);
+                while data.len() < len {
+                    data.extend_from_slice(WORDS[rng.gen_range(0..WORDS.len())].as_bytes());
+                    data.push(b' ');
+                }
+                data.truncate(len);
+                data
+            }
+        }
+    }
+
+    /// Generates `len` bytes of this pattern as printable ASCII, for targets
+    /// whose input must be text.
+    ///
+    /// Bytes outside the printable range are folded into it, so
+    /// [`Text`](Self::Text) is unchanged and [`Zeros`](Self::Zeros) becomes
+    /// spaces.
+    #[must_use]
+    pub fn generate_text(self, len: usize, seed: u64) -> String {
+        self.generate(len, seed)
+            .into_iter()
+            .map(|b| {
+                if b == b' ' || b.is_ascii_graphic() {
+                    char::from(b)
+                } else {
+                    char::from(b' ' + b % 95)
+                }
+            })
+            .collect()
+    }
+}
+
+impl fmt::Display for DataPattern {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+impl FromStr for DataPattern {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|pattern| pattern.name().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| {
+                format!(
+                    "unknown data pattern '{s}' (expected sequential, zeros, random, or text)"
+                )
+            })
+    }
+}
+
+/// A payload of `data_size` bytes, backed by at most one tile of memory.
+pub(crate) struct Payload {
+    tile: Vec<u8>,
+    data_size: usize,
+}
+
+impl Payload {
+    /// Builds a payload, tiling when `data_size` exceeds `max_buffer_size()`.
+    pub(crate) fn new(data_size: usize, pattern: DataPattern, seed: u64) -> Self {
+        Self::with_limit(data_size, max_buffer_size(), pattern, seed)
+    }
+
+    fn with_limit(data_size: usize, limit: usize, pattern: DataPattern, seed: u64) -> Self {
+        Self {
+            tile: pattern.generate(data_size.min(limit), seed),
+            data_size,
+        }
+    }
+
+    /// Returns true if the payload is processed as repeated tiles.
+    pub(crate) fn is_tiled(&self) -> bool {
+        self.data_size > self.tile.len()
+    }
+
+    /// Returns the number of tiles needed to cover `data_size`.
+    pub(crate) fn tile_count(&self) -> usize {
+        if self.tile.is_empty() {
+            1
+        } else {
+            self.data_size.div_ceil(self.tile.len())
+        }
+    }
+
+    /// Iterates over slices that together cover exactly `data_size` bytes.
+    pub(crate) fn chunks(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        let tile_len = self.tile.len();
+        (0..self.tile_count()).map(move |i| {
+            let remaining = self.data_size - i * tile_len;
+            &self.tile[..remaining.min(tile_len)]
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_payload_tiling() {
+        let small = Payload::with_limit(100, 1024, DataPattern::Sequential, 0);
+        assert!(!small.is_tiled());
+        assert_eq!(small.chunks().count(), 1);
+
+        let large = Payload::with_limit(2500, 1024, DataPattern::Sequential, 0);
+        assert!(large.is_tiled());
+        assert_eq!(large.tile_count(), 3);
+        let lens: Vec<usize> = large.chunks().map(<[u8]>::len).collect();
+        assert_eq!(lens, vec![1024, 1024, 452]);
+    }
+
+    #[test]
+    fn test_data_patterns() {
+        assert_eq!(DataPattern::Sequential.generate(258, 0)[255..], [255, 0, 1]);
+        assert!(DataPattern::Zeros.generate(64, 0).iter().all(|&b| b == 0));
+
+        let random = DataPattern::Random.generate(64, 7);
+        assert_eq!(random, DataPattern::Random.generate(64, 7));
+        assert_ne!(random, DataPattern::Random.generate(64, 8));
+
+        let text = DataPattern::Text.generate(100, 7);
+        assert_eq!(text.len(), 100);
+        assert!(text.iter().all(|b| b.is_ascii_lowercase() || *b == b' '));
+        assert_eq!(DataPattern::Text.generate_text(100, 7).as_bytes(), text);
+
+        assert_eq!(DataPattern::Zeros.generate_text(3, 0), "   ");
+        let printable = DataPattern::Random.generate_text(256, 7);
+        assert!(printable.bytes().all(|b| b == b' ' || b.is_ascii_graphic()));
+
+        assert_eq!(" Random".parse::<DataPattern>(), Ok(DataPattern::Random));
+        assert!("ramp".parse::<DataPattern>().unwrap_err().contains("ramp"));
+    }
+}
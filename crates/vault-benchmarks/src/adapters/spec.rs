@@ -0,0 +1,314 @@
+//! Declarative target specifications and config-file overrides.
+//!
+//! Every registered target is described by a [`TargetSpec`] carrying its id,
+//! payload size, and run length. The built-in table (see
+//! [`default_specs`](super::default_specs)) is the fallback; teams can tune
+//! run length per hardware tier with a TOML file such as:
+//!
+//! ```toml
+//! [targets.encryption-1mb]
+//! iterations = 500
+//! warmup = 20
+//!
+//! [targets.hashing-blake3-1mb]
+//! size = 4194304
+//! ```
+//!
+//! Only the fields present in the file are overridden.
+
+use super::{BenchTarget, DataPattern};
+use serde::Deserialize;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::Path;
+
+/// Default location of the target override file.
+pub const DEFAULT_TARGETS_FILE: &str = "benchmarks/targets.toml";
+
+/// Seed for generated test data when none is given.
+///
+/// Fixed rather than random so that the suite's inputs are identical from
+/// run to run out of the box.
+pub const DEFAULT_SEED: u64 = 0;
+
+/// Target families that support size sweeping.
+///
+/// Each entry maps a family name to the registered target used as the
+/// template; a sweep reuses its constructor and iteration count and only
+/// varies the payload size. Anonymization targets are sized in records
+/// rather than bytes and are deliberately not sweepable.
+pub const SIZE_SWEEP_FAMILIES: &[(&str, &str)] = &[
+    ("encryption", "encryption-1kb"),
+    ("hashing-blake3", "hashing-blake3-1mb"),
+    ("hashing-sha256", "hashing-sha256-1mb"),
+    ("hashing-sha512", "hashing-sha512-1mb"),
+    ("checksum-verification", "checksum-verification-1mb"),
+    ("storage-write", "storage-write-1mb"),
+    ("storage-read", "storage-read-1mb"),
+    ("content-addressing", "content-addressing-1mb"),
+];
+
+/// Specification of a single benchmark target.
+#[derive(Debug, Clone)]
+pub struct TargetSpec {
+    /// Unique target identifier.
+    pub id: String,
+    /// Payload size: bytes for byte-oriented targets, records for anonymization.
+    pub size: usize,
+    /// Number of timed iterations.
+    pub iterations: usize,
+    /// Number of untimed warmup iterations; `None` uses the default of 10%
+    /// of `iterations`.
+    pub warmup: Option<usize>,
+    /// Seed for generated test data, including keys and salts. Targets
+    /// whose input is a fixed byte pattern ignore it.
+    pub seed: u64,
+    /// Payload contents; `None` keeps each target's default. Text targets
+    /// (anonymization, pipeline) use it as filler between PII, and key
+    /// generation and derivation targets ignore it.
+    pub data_pattern: Option<DataPattern>,
+    /// Reports the mean with outliers trimmed; see
+    /// [`StandardMetrics::from_samples_with`](crate::StandardMetrics::from_samples_with).
+    pub trim_outliers: bool,
+    factory: fn(&TargetSpec) -> Box<dyn BenchTarget>,
+}
+
+impl TargetSpec {
+    /// Creates a spec with the default warmup, seed, and data pattern, and
+    /// without outlier trimming.
+    #[must_use]
+    pub fn new(
+        id: impl Into<String>,
+        size: usize,
+        iterations: usize,
+        factory: fn(&TargetSpec) -> Box<dyn BenchTarget>,
+    ) -> Self {
+        Self {
+            id: id.into(),
+            size,
+            iterations,
+            warmup: None,
+            seed: DEFAULT_SEED,
+            data_pattern: None,
+            trim_outliers: false,
+            factory,
+        }
+    }
+
+    /// Returns the number of untimed warmup iterations for this spec.
+    #[must_use]
+    pub fn warmup_iterations(&self) -> usize {
+        self.warmup.unwrap_or_else(|| default_warmup(self.iterations))
+    }
+
+    /// Constructs the benchmark target described by this spec.
+    #[must_use]
+    pub fn build(&self) -> Box<dyn BenchTarget> {
+        (self.factory)(self)
+    }
+}
+
+/// Returns the default warmup for a run of `iterations`: 10%, rounded down.
+pub(crate) fn default_warmup(iterations: usize) -> usize {
+    iterations / 10
+}
+
+/// Per-target fields that may be overridden from a config file.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TargetOverride {
+    /// Payload size override.
+    pub size: Option<usize>,
+    /// Timed iteration count override.
+    pub iterations: Option<usize>,
+    /// Warmup iteration count override.
+    pub warmup: Option<usize>,
+}
+
+/// A set of target overrides keyed by target id.
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TargetOverrides {
+    /// Overrides keyed by target id.
+    #[serde(default)]
+    pub targets: BTreeMap<String, TargetOverride>,
+}
+
+impl TargetOverrides {
+    /// Parses overrides from TOML text.
+    pub fn from_toml(content: &str) -> io::Result<Self> {
+        toml::from_str(content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Loads overrides from a TOML file.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_toml(&std::fs::read_to_string(path)?)
+    }
+
+    /// Applies the overrides to `specs` in place.
+    ///
+    /// Fails without modifying anything if an override names an unknown
+    /// target, so typos in the config file are caught rather than ignored.
+    pub fn apply(&self, specs: &mut [TargetSpec]) -> io::Result<()> {
+        if let Some(unknown) = self
+            .targets
+            .keys()
+            .find(|id| !specs.iter().any(|s| &s.id == *id))
+        {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("unknown target id in overrides: {}", unknown),
+            ));
+        }
+
+        for spec in specs.iter_mut() {
+            if let Some(o) = self.targets.get(&spec.id) {
+                spec.size = o.size.unwrap_or(spec.size);
+                spec.iterations = o.iterations.unwrap_or(spec.iterations);
+                spec.warmup = o.warmup.or(spec.warmup);
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Parses a byte size such as `512`, `1kb`, `4mb`, or `1gb` (case-insensitive).
+///
+/// Returns `None` for zero, since no target can run on an empty payload.
+#[must_use]
+pub fn parse_size(text: &str) -> Option<usize> {
+    let text = text.trim().to_ascii_lowercase();
+    let (digits, multiplier) = if let Some(n) = text.strip_suffix("gb") {
+        (n, 1024 * 1024 * 1024)
+    } else if let Some(n) = text.strip_suffix("mb") {
+        (n, 1024 * 1024)
+    } else if let Some(n) = text.strip_suffix("kb") {
+        (n, 1024)
+    } else {
+        (text.strip_suffix('b').unwrap_or(&text), 1)
+    };
+
+    digits.trim().parse::<usize>().ok()?.checked_mul(multiplier).filter(|&size| size > 0)
+}
+
+/// Formats a byte size as the suffix used in target ids (e.g. `1kb`, `10mb`).
+#[must_use]
+pub fn size_label(bytes: usize) -> String {
+    const KB: usize = 1024;
+    const MB: usize = 1024 * 1024;
+    const GB: usize = 1024 * 1024 * 1024;
+
+    match bytes {
+        b if b >= GB && b % GB == 0 => format!("{}gb", b / GB),
+        b if b >= MB && b % MB == 0 => format!("{}mb", b / MB),
+        b if b >= KB && b % KB == 0 => format!("{}kb", b / KB),
+        b => format!("{}b", b),
+    }
+}
+
+/// Derives one spec per size for a sweepable target family.
+///
+/// The template is looked up in `specs`, so overrides already applied to it
+/// (iterations, warmup) carry over to every size. Generated ids have the
+/// form `<family>-<size>`, e.g. `encryption-10mb`. Zero sizes are rejected.
+pub fn size_sweep_specs(
+    specs: &[TargetSpec],
+    family: &str,
+    sizes: &[usize],
+) -> io::Result<Vec<TargetSpec>> {
+    if sizes.contains(&0) {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "sweep sizes must be greater than zero",
+        ));
+    }
+
+    let template_id = SIZE_SWEEP_FAMILIES
+        .iter()
+        .find(|(name, _)| *name == family)
+        .map(|(_, template)| *template)
+        .ok_or_else(|| {
+            let supported: Vec<&str> = SIZE_SWEEP_FAMILIES.iter().map(|(name, _)| *name).collect();
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!(
+                    "target family '{}' does not support size sweeps (supported: {})",
+                    family,
+                    supported.join(", ")
+                ),
+            )
+        })?;
+
+    let template = specs
+        .iter()
+        .find(|s| s.id == template_id)
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                format!("template target '{}' is not registered", template_id),
+            )
+        })?;
+
+    Ok(sizes
+        .iter()
+        .map(|&size| TargetSpec {
+            id: format!("{}-{}", family, size_label(size)),
+            size,
+            ..template.clone()
+        })
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::adapters::default_specs;
+
+    #[tokio::test]
+    async fn test_overrides_apply() {
+        let overrides = TargetOverrides::from_toml(
+            r#"
+            [targets.encryption-1kb]
+            iterations = 3
+            warmup = 1
+            "#,
+        )
+        .unwrap();
+
+        let mut specs = default_specs();
+        overrides.apply(&mut specs).unwrap();
+
+        let spec = specs.iter().find(|s| s.id == "encryption-1kb").unwrap();
+        assert_eq!((spec.size, spec.iterations, spec.warmup), (1024, 3, Some(1)));
+
+        let result = spec.build().run().await;
+        assert_eq!(result.metrics["iterations"].as_u64().unwrap(), 3);
+        assert_eq!(result.metrics["warmup_iterations"].as_u64().unwrap(), 1);
+    }
+
+    #[test]
+    fn test_size_sweep_specs() {
+        let sizes: Vec<usize> = ["1kb", "1MB", "10mb"].iter().map(|s| parse_size(s).unwrap()).collect();
+
+        let sweep = size_sweep_specs(&default_specs(), "encryption", &sizes).unwrap();
+
+        let ids: Vec<&str> = sweep.iter().map(|s| s.id.as_str()).collect();
+        assert_eq!(ids, ["encryption-1kb", "encryption-1mb", "encryption-10mb"]);
+        assert_eq!(sweep[2].size, 10 * 1024 * 1024);
+        assert_eq!(parse_size("1.5mb"), None);
+        assert_eq!(parse_size("0b"), None);
+        assert!(size_sweep_specs(&default_specs(), "anonymization", &sizes).is_err());
+
+        let err = size_sweep_specs(&default_specs(), "storage-write", &[0]).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+
+    #[test]
+    fn test_overrides_reject_unknown_target() {
+        let overrides = TargetOverrides::from_toml("[targets.no-such-target]\niterations = 1\n").unwrap();
+
+        let err = overrides.apply(&mut default_specs()).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
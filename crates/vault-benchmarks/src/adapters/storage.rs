@@ -2,12 +2,21 @@
 //!
 //! Benchmarks content-addressable storage operations including
 //! read/write throughput and content addressing without modifying
-//! any existing storage logic.
+//! any existing storage logic. Stores are in memory unless a disk
+//! directory is configured, in which case a filesystem backend is used.
 
+use super::pacing::{arrival, Pacer};
+use super::payload::DataPattern;
+use super::timing::IterationTimer;
+use crate::stats::{drop_non_finite, latency_percentiles};
 use crate::{BenchmarkResult, StandardMetrics};
 use async_trait::async_trait;
+use std::hint::black_box;
+use std::path::{Path, PathBuf};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
+use tokio::task::JoinSet;
+use vault_storage::StorageBackend;
 
 /// Storage operation type to benchmark.
 #[derive(Debug, Clone, Copy)]
@@ -18,6 +27,18 @@ pub enum StorageOperation {
     Read,
     /// Content addressing (hash computation).
     ContentAddressing,
+    /// Concurrent mix of reads and writes against one store.
+    MixedReadWrite,
+    /// Many objects written with one batch call per iteration.
+    BatchWrite,
+    /// Writes where a fixed fraction of payloads repeat earlier content.
+    Dedup,
+    /// Unique writes from several concurrent tasks against one store.
+    ConcurrentWrite,
+    /// First reads of uncached objects against repeat reads served by a cache.
+    ColdWarmRead,
+    /// Concurrent writes repeated at increasing task counts.
+    Scaling,
 }
 
 /// Storage benchmark measuring read/write throughput.
@@ -25,49 +46,744 @@ pub struct StorageBenchmark {
     data_size: usize,
     id: String,
     operation: StorageOperation,
+    data_pattern: DataPattern,
+    seed: u64,
     iterations: usize,
+    warmup: Option<usize>,
+    max_total_time: Option<Duration>,
+    trim_outliers: bool,
+    read_ratio: f64,
+    concurrency: usize,
+    capacity: Option<usize>,
+    batch_count: usize,
+    duplicate_fraction: f64,
+    arrival_rate: Option<f64>,
+    disk_root: Option<PathBuf>,
+    scaling_levels: Vec<usize>,
 }
 
 impl StorageBenchmark {
-    /// Creates a write benchmark.
-    #[must_use]
-    pub fn write(data_size: usize, id: impl Into<String>) -> Self {
+    fn with_operation(data_size: usize, id: impl Into<String>, operation: StorageOperation) -> Self {
         Self {
             data_size,
             id: id.into(),
-            operation: StorageOperation::Write,
+            operation,
+            data_pattern: DataPattern::Sequential,
+            seed: super::DEFAULT_SEED,
             iterations: 100,
+            warmup: None,
+            max_total_time: None,
+            trim_outliers: false,
+            read_ratio: 0.0,
+            concurrency: 1,
+            capacity: None,
+            batch_count: 1,
+            duplicate_fraction: 0.0,
+            arrival_rate: None,
+            disk_root: None,
+            scaling_levels: Vec::new(),
         }
     }
 
+    /// Creates a write benchmark.
+    #[must_use]
+    pub fn write(data_size: usize, id: impl Into<String>) -> Self {
+        Self::with_operation(data_size, id, StorageOperation::Write)
+    }
+
     /// Creates a read benchmark.
     #[must_use]
     pub fn read(data_size: usize, id: impl Into<String>) -> Self {
-        Self {
-            data_size,
-            id: id.into(),
-            operation: StorageOperation::Read,
-            iterations: 100,
-        }
+        Self::with_operation(data_size, id, StorageOperation::Read)
+    }
+
+    /// Creates a cold versus warm read benchmark.
+    ///
+    /// Every iteration reads a distinct, previously written object twice
+    /// through a read cache: the first read misses and goes to the backend
+    /// (`cold_read_ms`), the second is served from the cache (`warm_read_ms`).
+    /// The standard latency metrics describe the cold reads.
+    /// Objects are written straight to the backend, bypassing the cache, so
+    /// no object is read warm before its cold read.
+    #[must_use]
+    pub fn cold_warm_read(data_size: usize, id: impl Into<String>) -> Self {
+        Self::with_operation(data_size, id, StorageOperation::ColdWarmRead)
     }
 
     /// Creates a content addressing benchmark.
     #[must_use]
     pub fn content_addressing(data_size: usize, id: impl Into<String>) -> Self {
+        Self::with_operation(data_size, id, StorageOperation::ContentAddressing)
+    }
+
+    /// Creates a mixed read/write benchmark against a shared store.
+    ///
+    /// `read_ratio` is the fraction of operations (0.0 to 1.0) that are reads;
+    /// the remainder are writes of unique payloads. Operations are spread
+    /// across concurrent tasks so reads and writes contend for the store.
+    #[must_use]
+    pub fn mixed_read_write(data_size: usize, read_ratio: f64, id: impl Into<String>) -> Self {
         Self {
-            data_size,
-            id: id.into(),
-            operation: StorageOperation::ContentAddressing,
-            iterations: 100,
+            read_ratio: read_ratio.clamp(0.0, 1.0),
+            concurrency: 4,
+            ..Self::with_operation(data_size, id, StorageOperation::MixedReadWrite)
+        }
+    }
+
+    /// Creates a concurrent writers benchmark against a shared store.
+    ///
+    /// `concurrency` tasks split the iterations between them, each writing
+    /// unique payloads. Throughput is aggregated over wall time; latency
+    /// percentiles cover every write, and `task_p99_min_ms` and
+    /// `task_p99_max_ms` show how evenly the tasks were served.
+    #[must_use]
+    pub fn concurrent_write(data_size: usize, concurrency: usize, id: impl Into<String>) -> Self {
+        Self {
+            concurrency: concurrency.max(1),
+            ..Self::with_operation(data_size, id, StorageOperation::ConcurrentWrite)
         }
     }
 
+    /// Creates a concurrency scaling benchmark.
+    ///
+    /// Runs the concurrent writers workload once per entry in `levels`, each
+    /// against a fresh shared store, and records the aggregate throughput at
+    /// every level as `scaling: [{concurrency, ops_per_second}, ...]`. The
+    /// standard metrics cover all writes; `ops_per_second` is the best level,
+    /// reported with `peak_concurrency`.
+    #[must_use]
+    pub fn scaling(data_size: usize, levels: Vec<usize>, id: impl Into<String>) -> Self {
+        Self {
+            scaling_levels: levels.into_iter().map(|level| level.max(1)).collect(),
+            ..Self::with_operation(data_size, id, StorageOperation::Scaling)
+        }
+    }
+
+    /// Creates a batch write benchmark.
+    ///
+    /// Each iteration writes `batch_count` unique objects of `object_size`
+    /// bytes with a single `ContentStore::put_batch` call. The same objects
+    /// are also written one `put` at a time so `speedup` can report how much
+    /// the batch path saves over per-object writes.
+    #[must_use]
+    pub fn batch_write(object_size: usize, batch_count: usize, id: impl Into<String>) -> Self {
+        Self {
+            batch_count: batch_count.max(1),
+            ..Self::with_operation(object_size, id, StorageOperation::BatchWrite)
+        }
+    }
+
+    /// Creates a deduplication benchmark.
+    ///
+    /// `duplicate_fraction` (0.0 to 1.0) of the writes repeat content already
+    /// in the store, spread evenly across iterations; the rest are unique.
+    /// The result reports `dedup_ratio`, the bytes logically written divided
+    /// by the bytes the backend physically holds, including metadata.
+    #[must_use]
+    pub fn dedup(data_size: usize, duplicate_fraction: f64, id: impl Into<String>) -> Self {
+        Self {
+            duplicate_fraction: duplicate_fraction.clamp(0.0, 1.0),
+            ..Self::with_operation(data_size, id, StorageOperation::Dedup)
+        }
+    }
+
+    /// Creates a write benchmark against a filesystem backend.
+    ///
+    /// Objects are written to a fresh temporary directory inside `dir`,
+    /// which `teardown()` removes. See [`with_disk_dir`](Self::with_disk_dir).
+    #[must_use]
+    pub fn on_disk(data_size: usize, id: impl Into<String>, dir: impl AsRef<Path>) -> Self {
+        Self::write(data_size, id).with_disk_dir(dir)
+    }
+
     /// Sets the number of iterations.
     #[must_use]
     pub fn with_iterations(mut self, iterations: usize) -> Self {
         self.iterations = iterations;
         self
     }
+
+    /// Sets the number of untimed warmup iterations run before timing starts.
+    ///
+    /// Defaults to 10% of the iteration count. The mixed read/write, concurrent
+    /// write, and scaling modes run their tasks concurrently and take no warmup.
+    #[must_use]
+    pub fn with_warmup(mut self, warmup: usize) -> Self {
+        self.warmup = Some(warmup);
+        self
+    }
+
+    /// Caps the cumulative time spent in the iteration loop.
+    ///
+    /// Once the budget is reached the loop stops early and the `iterations`
+    /// metric reports the number of iterations actually completed.
+    #[must_use]
+    pub fn with_max_total_time(mut self, max_total_time: Duration) -> Self {
+        self.max_total_time = Some(max_total_time);
+        self
+    }
+
+    /// Reports the mean with the top and bottom 5% of samples dropped; see
+    /// [`StandardMetrics::from_samples_with`].
+    #[must_use]
+    pub fn with_trim_outliers(mut self, trim_outliers: bool) -> Self {
+        self.trim_outliers = trim_outliers;
+        self
+    }
+
+    /// Sets the number of concurrent tasks used by multi-task modes.
+    #[must_use]
+    pub fn with_concurrency(mut self, concurrency: usize) -> Self {
+        self.concurrency = concurrency.max(1);
+        self
+    }
+
+    /// Puts a write-through LRU cache bounded to `capacity` bytes in front of
+    /// the backend.
+    ///
+    /// Only the cache is bounded: writes still land in the unbounded backend,
+    /// so this measures cache eviction overhead, not a store running out of
+    /// space. Write benchmarks fill the cache before timing starts, so every
+    /// measured write has to evict an older cached entry. The `evictions`
+    /// metric reports how many cache entries were evicted during the timed loop.
+    #[must_use]
+    pub fn with_capacity(mut self, capacity: usize) -> Self {
+        self.capacity = Some(capacity);
+        self
+    }
+
+    /// Switches to open-loop mode with a fixed arrival rate.
+    ///
+    /// Applies to the write, read, and content addressing modes. Operations
+    /// are issued on schedule regardless of completion and latencies include
+    /// queue wait; see [`HashingBenchmark::with_arrival_rate`](super::HashingBenchmark::with_arrival_rate).
+    /// Rates that are not finite and positive are ignored.
+    #[must_use]
+    pub fn with_arrival_rate(mut self, ops_per_sec: f64) -> Self {
+        self.arrival_rate = Some(ops_per_sec);
+        self
+    }
+
+    /// Sets the contents of stored objects. Defaults to
+    /// [`DataPattern::Sequential`].
+    ///
+    /// Writes still stamp each object's leading bytes to keep them unique, so
+    /// [`DataPattern::Zeros`] does not cause accidental deduplication.
+    #[must_use]
+    pub fn with_data_pattern(mut self, pattern: DataPattern) -> Self {
+        self.data_pattern = pattern;
+        self
+    }
+
+    /// Sets the seed used to generate random and text objects. Defaults to
+    /// [`DEFAULT_SEED`](super::DEFAULT_SEED).
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Stores objects on disk instead of in memory.
+    ///
+    /// A uniquely named temporary directory is created inside `dir` during
+    /// `setup()` and removed in `teardown()`. Results report
+    /// `backend: "filesystem"`.
+    #[must_use]
+    pub fn with_disk_dir(mut self, dir: impl AsRef<Path>) -> Self {
+        let name = format!("vault-bench-{}", uuid::Uuid::new_v4());
+        self.disk_root = Some(dir.as_ref().join(name));
+        self
+    }
+
+    /// Creates a backend for one store, on disk under `name` if configured.
+    ///
+    /// Returns the error to report if the backend cannot be created.
+    async fn backend(&self, name: &str) -> Result<Arc<dyn StorageBackend>, String> {
+        match &self.disk_root {
+            Some(root) => match vault_storage::FilesystemBackend::new(root.join(name)).await {
+                Ok(backend) => Ok(Arc::new(backend)),
+                Err(e) => Err(format!("Failed to create filesystem backend: {e}")),
+            },
+            None => Ok(Arc::new(vault_storage::InMemoryBackend::new())),
+        }
+    }
+
+    /// Returns the `backend` metric value.
+    fn backend_label(&self, cached: bool) -> String {
+        let base = if self.disk_root.is_some() { "filesystem" } else { "in-memory" };
+        if cached {
+            format!("{base}+lru-cache")
+        } else {
+            base.to_string()
+        }
+    }
+
+    /// Returns the warmup iteration count, defaulting to 10% of iterations.
+    fn warmup_iterations(&self) -> usize {
+        self.warmup.unwrap_or_else(|| super::default_warmup(self.iterations))
+    }
+
+    /// Returns a loop driver honoring the warmup and time budget settings.
+    fn timer(&self) -> IterationTimer {
+        IterationTimer::new(self.iterations, self.warmup_iterations())
+            .with_max_total_time(self.max_total_time)
+    }
+}
+
+impl StorageBenchmark {
+    /// Runs reader and writer tasks concurrently against one shared store.
+    async fn run_mixed(
+        &self,
+        store: Arc<vault_storage::ContentStore>,
+        data: Vec<u8>,
+    ) -> BenchmarkResult {
+        // Seed the store so readers have something to fetch from the start
+        const SEED_OBJECTS: usize = 16;
+        let mut addresses = Vec::with_capacity(SEED_OBJECTS);
+        for i in 0..SEED_OBJECTS {
+            let mut seed = data.clone();
+            stamp_payload(&mut seed, u64::MAX - i as u64);
+            let metadata = match store.put(&seed).await {
+                Ok(metadata) => metadata,
+                Err(e) => {
+                    return BenchmarkResult::failed(&self.id, format!("Seed write failed: {e}"));
+                }
+            };
+            addresses.push(metadata.address);
+        }
+        let addresses = Arc::new(addresses);
+
+        let concurrency = self.concurrency;
+        let ops_per_task = (self.iterations / concurrency).max(1);
+        let read_percent = (self.read_ratio * 100.0).round() as usize;
+        let data = Arc::new(data);
+
+        let wall_start = Instant::now();
+        let mut tasks = JoinSet::new();
+
+        for task in 0..concurrency {
+            let store = Arc::clone(&store);
+            let addresses = Arc::clone(&addresses);
+            let data = Arc::clone(&data);
+
+            tasks.spawn(async move {
+                let mut read_times = Vec::new();
+                let mut write_times = Vec::new();
+
+                for op in 0..ops_per_task {
+                    let k = op * concurrency + task;
+                    // Spread reads evenly through the op sequence at the requested ratio
+                    let is_read = ((k + 1) * read_percent) / 100 > (k * read_percent) / 100;
+
+                    if is_read {
+                        let address = &addresses[k % addresses.len()];
+                        let start = Instant::now();
+                        let _content =
+                            store.get(address).await.map_err(|e| format!("Read failed: {e}"))?;
+                        read_times.push(start.elapsed().as_secs_f64() * 1000.0);
+                    } else {
+                        let mut payload = data.as_ref().clone();
+                        stamp_payload(&mut payload, k as u64);
+
+                        let start = Instant::now();
+                        let _metadata =
+                            store.put(&payload).await.map_err(|e| format!("Write failed: {e}"))?;
+                        write_times.push(start.elapsed().as_secs_f64() * 1000.0);
+                    }
+                }
+
+                Ok::<_, String>((read_times, write_times))
+            });
+        }
+
+        // Returning early drops the set, which aborts the remaining tasks
+        let mut read_times = Vec::new();
+        let mut write_times = Vec::new();
+        while let Some(joined) = tasks.join_next().await {
+            let (reads, writes) = match joined {
+                Ok(Ok(times)) => times,
+                Ok(Err(message)) => return BenchmarkResult::failed(&self.id, message),
+                Err(e) => {
+                    let message = format!("Mixed workload task failed: {e}");
+                    return BenchmarkResult::failed(&self.id, message);
+                }
+            };
+            read_times.extend(reads);
+            write_times.extend(writes);
+        }
+
+        let wall_ms = wall_start.elapsed().as_secs_f64() * 1000.0;
+        let dropped_samples = drop_non_finite(&mut read_times) + drop_non_finite(&mut write_times);
+        let total_ops = read_times.len() + write_times.len();
+        let ops_per_second = (total_ops as f64 / wall_ms) * 1000.0;
+        let throughput_bps = ((total_ops * self.data_size) as f64 / wall_ms) * 1000.0;
+
+        let all_times: Vec<f64> = read_times.iter().chain(write_times.iter()).copied().collect();
+
+        // Throughput is measured against wall time, since tasks overlap
+        let metrics = StandardMetrics::from_samples_with(
+            &all_times,
+            self.data_size as u64,
+            total_ops as u64,
+            self.trim_outliers,
+        );
+        let mut metrics = metrics
+            .with_bytes_per_second(throughput_bps)
+            .with_ops_per_second(ops_per_second)
+            .with_custom("operation", "mixed_read_write")
+            .with_custom("data_pattern", self.data_pattern.name())
+            .with_custom("throughput_bps", throughput_bps)
+            .with_custom("backend", self.backend_label(false))
+            .with_custom("concurrency", concurrency as u64)
+            .with_custom("read_ratio", self.read_ratio)
+            .with_custom("read_count", read_times.len() as u64)
+            .with_custom("write_count", write_times.len() as u64)
+            .with_custom("dropped_samples", dropped_samples as u64);
+
+        for (prefix, times) in [("read", &mut read_times), ("write", &mut write_times)] {
+            if times.is_empty() {
+                continue;
+            }
+            let avg = times.iter().sum::<f64>() / times.len() as f64;
+            let (p50, p95, p99) = latency_percentiles(times);
+            metrics = metrics
+                .with_custom(format!("{prefix}_avg_ms"), avg)
+                .with_custom(format!("{prefix}_latency_p50_ms"), p50)
+                .with_custom(format!("{prefix}_latency_p95_ms"), p95)
+                .with_custom(format!("{prefix}_latency_p99_ms"), p99);
+        }
+
+        BenchmarkResult::new(&self.id, metrics.to_json_value())
+    }
+
+    /// Runs writer tasks concurrently against one shared store.
+    async fn run_concurrent_write(
+        &self,
+        store: Arc<vault_storage::ContentStore>,
+        data: Vec<u8>,
+    ) -> BenchmarkResult {
+        let concurrency = self.concurrency;
+        let ops_per_task = (self.iterations / concurrency).max(1);
+
+        let outcome = write_concurrently(store, Arc::new(data), concurrency, ops_per_task).await;
+        let (mut task_times, wall_ms) = match outcome {
+            Ok(outcome) => outcome,
+            Err(message) => return BenchmarkResult::failed(&self.id, message),
+        };
+        let dropped_samples: usize = task_times.iter_mut().map(drop_non_finite).sum();
+        let all_times: Vec<f64> = task_times.iter().flatten().copied().collect();
+        let total_ops = all_times.len();
+        let ops_per_second = (total_ops as f64 / wall_ms) * 1000.0;
+        let throughput_bps = ((total_ops * self.data_size) as f64 / wall_ms) * 1000.0;
+
+        let task_p99s: Vec<f64> = task_times
+            .iter_mut()
+            .filter(|times| !times.is_empty())
+            .map(|times| latency_percentiles(times).2)
+            .collect();
+        let task_p99_min = task_p99s.iter().copied().fold(f64::INFINITY, f64::min);
+        let task_p99_max = task_p99s.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+        // Throughput is measured against wall time, since tasks overlap
+        let metrics = StandardMetrics::from_samples_with(
+            &all_times,
+            self.data_size as u64,
+            total_ops as u64,
+            self.trim_outliers,
+        );
+        let metrics = metrics
+            .with_bytes_per_second(throughput_bps)
+            .with_ops_per_second(ops_per_second)
+            .with_custom("operation", "concurrent_write")
+            .with_custom("data_pattern", self.data_pattern.name())
+            .with_custom("throughput_bps", throughput_bps)
+            .with_custom("backend", self.backend_label(false))
+            .with_custom("concurrency", concurrency as u64)
+            .with_custom("ops_per_task", ops_per_task as u64)
+            .with_custom("task_p99_min_ms", task_p99_min)
+            .with_custom("task_p99_max_ms", task_p99_max)
+            .with_custom("dropped_samples", dropped_samples as u64);
+
+        BenchmarkResult::new(&self.id, metrics.to_json_value())
+    }
+
+    /// Runs the concurrent writers workload at each configured level.
+    async fn run_scaling(&self, data: Vec<u8>) -> BenchmarkResult {
+        let data = Arc::new(data);
+        let mut all_times = Vec::new();
+        let mut dropped_samples = 0;
+        let mut scaling = Vec::with_capacity(self.scaling_levels.len());
+        let mut peak: Option<(usize, f64)> = None;
+
+        for &concurrency in &self.scaling_levels {
+            // A fresh store per level keeps earlier levels' objects from
+            // slowing later ones down
+            let backend = match self.backend(&format!("scaling-{concurrency}")).await {
+                Ok(backend) => backend,
+                Err(message) => return BenchmarkResult::failed(&self.id, message),
+            };
+            let store = Arc::new(vault_storage::ContentStore::new(backend));
+            let ops_per_task = (self.iterations / concurrency).max(1);
+
+            let outcome =
+                write_concurrently(store, Arc::clone(&data), concurrency, ops_per_task).await;
+            let (mut task_times, wall_ms) = match outcome {
+                Ok(outcome) => outcome,
+                Err(message) => return BenchmarkResult::failed(&self.id, message),
+            };
+            dropped_samples += task_times.iter_mut().map(drop_non_finite).sum::<usize>();
+            let level_ops: usize = task_times.iter().map(Vec::len).sum();
+            let ops_per_second = (level_ops as f64 / wall_ms) * 1000.0;
+
+            scaling.push(serde_json::json!({
+                "concurrency": concurrency,
+                "ops_per_second": crate::metric_f64(ops_per_second),
+            }));
+            if peak.map_or(true, |(_, best)| ops_per_second > best) {
+                peak = Some((concurrency, ops_per_second));
+            }
+            all_times.extend(task_times.into_iter().flatten());
+        }
+
+        let Some((peak_concurrency, peak_ops_per_second)) = peak else {
+            return BenchmarkResult::failed(&self.id, "No concurrency levels configured");
+        };
+
+        // Throughput is measured against wall time, since tasks overlap
+        let total_ops = all_times.len();
+        let metrics = StandardMetrics::from_samples_with(
+            &all_times,
+            self.data_size as u64,
+            total_ops as u64,
+            self.trim_outliers,
+        );
+        let metrics = metrics
+            .with_ops_per_second(peak_ops_per_second)
+            .with_bytes_per_second(peak_ops_per_second * self.data_size as f64)
+            .with_custom("operation", "scaling_write")
+            .with_custom("data_pattern", self.data_pattern.name())
+            .with_custom("backend", self.backend_label(false))
+            .with_custom("scaling", serde_json::Value::Array(scaling))
+            .with_custom("peak_concurrency", peak_concurrency as u64)
+            .with_custom("dropped_samples", dropped_samples as u64);
+
+        BenchmarkResult::new(&self.id, metrics.to_json_value())
+    }
+}
+
+/// Writes unique payloads from `concurrency` tasks against one shared store.
+///
+/// Each task performs `ops_per_task` writes. Returns each task's per-write
+/// latencies and the wall time of the whole run, both in milliseconds, or a
+/// message describing the first failed write or task.
+async fn write_concurrently(
+    store: Arc<vault_storage::ContentStore>,
+    data: Arc<Vec<u8>>,
+    concurrency: usize,
+    ops_per_task: usize,
+) -> Result<(Vec<Vec<f64>>, f64), String> {
+    let wall_start = Instant::now();
+    let mut tasks = JoinSet::new();
+
+    for task in 0..concurrency {
+        let store = Arc::clone(&store);
+        let data = Arc::clone(&data);
+
+        tasks.spawn(async move {
+            let mut times = Vec::with_capacity(ops_per_task);
+            for op in 0..ops_per_task {
+                let mut payload = data.as_ref().clone();
+                stamp_payload(&mut payload, (op * concurrency + task) as u64);
+
+                let start = Instant::now();
+                let _metadata =
+                    store.put(&payload).await.map_err(|e| format!("Write failed: {e}"))?;
+                times.push(start.elapsed().as_secs_f64() * 1000.0);
+            }
+            Ok::<_, String>(times)
+        });
+    }
+
+    // Returning early drops the set, which aborts the remaining writers
+    let mut task_times = Vec::with_capacity(concurrency);
+    while let Some(joined) = tasks.join_next().await {
+        match joined {
+            Ok(times) => task_times.push(times?),
+            Err(e) => return Err(format!("Concurrent writer task failed: {e}")),
+        }
+    }
+
+    Ok((task_times, wall_start.elapsed().as_secs_f64() * 1000.0))
+}
+
+impl StorageBenchmark {
+    /// Times the first read of each object against an immediate repeat read.
+    async fn run_cold_warm(
+        &self,
+        backend: Arc<dyn StorageBackend>,
+        data: Vec<u8>,
+    ) -> BenchmarkResult {
+        use vault_storage::{CacheConfig, ContentStore, StorageCache};
+
+        // Writes bypass the cache so every object starts out cold for readers
+        let writer = ContentStore::new(backend.clone());
+        let cache = Arc::new(StorageCache::new(backend, CacheConfig::default()));
+        let reader = ContentStore::new(cache.clone());
+
+        // One distinct object per iteration, warmup included
+        let warmup = self.warmup_iterations();
+        let mut addresses = Vec::with_capacity(warmup + self.iterations);
+        for i in 0..warmup + self.iterations {
+            let mut payload = data.clone();
+            stamp_payload(&mut payload, i as u64);
+            match writer.put(&payload).await {
+                Ok(metadata) => addresses.push(metadata.address),
+                Err(e) => {
+                    let message = format!("Initial write failed: {e}");
+                    return BenchmarkResult::failed(&self.id, message);
+                }
+            }
+        }
+
+        let mut timer = self.timer();
+        let mut warm_times = Vec::with_capacity(self.iterations);
+        while let Some(i) = timer.next_iteration() {
+            let address = &addresses[i];
+
+            let start = Instant::now();
+            if let Err(e) = reader.get(address).await {
+                return BenchmarkResult::failed(&self.id, format!("Cold read failed: {e}"));
+            }
+            let cold_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+            let start = Instant::now();
+            if let Err(e) = reader.get(address).await {
+                return BenchmarkResult::failed(&self.id, format!("Warm read failed: {e}"));
+            }
+            let warm_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+            if !timer.is_warmup() {
+                warm_times.push(warm_ms);
+            }
+            timer.record_ms(cold_ms);
+        }
+        let mut cold_times = timer.into_samples();
+
+        // Calculate statistics; the standard metrics describe cold reads
+        let dropped_samples = drop_non_finite(&mut cold_times) + drop_non_finite(&mut warm_times);
+        let iterations = cold_times.len();
+        let metrics = StandardMetrics::from_samples_with(
+            &cold_times,
+            self.data_size as u64,
+            iterations as u64,
+            self.trim_outliers,
+        );
+        let cold_avg_ms = metrics.duration_ms.unwrap_or_default();
+        let warm_avg_ms = warm_times.iter().sum::<f64>() / warm_times.len() as f64;
+        let stats = cache.cache_stats();
+
+        let metrics = metrics
+            .with_custom("operation", "cold_warm_read")
+            .with_custom("data_pattern", self.data_pattern.name())
+            .with_custom("cold_read_ms", cold_avg_ms)
+            .with_custom("warm_read_ms", warm_avg_ms)
+            .with_custom("warm_speedup", cold_avg_ms / warm_avg_ms)
+            .with_custom("cache_hits", stats.hits)
+            .with_custom("cache_misses", stats.misses)
+            .with_custom("backend", self.backend_label(true))
+            .with_custom("warmup_iterations", warmup as u64)
+            .with_custom("dropped_samples", dropped_samples as u64);
+
+        BenchmarkResult::new(&self.id, metrics.to_json_value())
+    }
+
+    /// Times batch writes against the equivalent sequence of single writes.
+    async fn run_batch(&self, data: Vec<u8>) -> BenchmarkResult {
+        use vault_storage::ContentStore;
+
+        let (batch_store, single_store) =
+            match (self.backend("batch").await, self.backend("single").await) {
+                (Ok(batch), Ok(single)) => (ContentStore::new(batch), ContentStore::new(single)),
+                (Err(message), _) | (_, Err(message)) => {
+                    return BenchmarkResult::failed(&self.id, message);
+                }
+            };
+
+        let mut timer = self.timer();
+        let mut single_times = Vec::with_capacity(self.iterations);
+        let warmup = self.warmup_iterations();
+
+        while let Some(i) = timer.next_iteration() {
+            // Unique payloads per iteration so nothing is deduplicated
+            let payloads: Vec<Vec<u8>> = (0..self.batch_count)
+                .map(|j| {
+                    let mut payload = data.clone();
+                    stamp_payload(&mut payload, (i * self.batch_count + j) as u64);
+                    payload
+                })
+                .collect();
+            let items: Vec<&[u8]> = payloads.iter().map(Vec::as_slice).collect();
+
+            let start = Instant::now();
+            if let Err(e) = batch_store.put_batch(&items).await {
+                return BenchmarkResult::failed(&self.id, format!("Batch write failed: {e}"));
+            }
+            let batch_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+            let start = Instant::now();
+            for item in &items {
+                if let Err(e) = single_store.put(item).await {
+                    return BenchmarkResult::failed(&self.id, format!("Write failed: {e}"));
+                }
+            }
+            let single_ms = start.elapsed().as_secs_f64() * 1000.0;
+
+            if !timer.is_warmup() {
+                single_times.push(single_ms);
+            }
+            timer.record_ms(batch_ms);
+        }
+        let mut batch_times = timer.into_samples();
+
+        // Calculate statistics
+        let dropped_samples = drop_non_finite(&mut batch_times) + drop_non_finite(&mut single_times);
+        let iterations = batch_times.len();
+        let batch_bytes = (self.batch_count * self.data_size) as u64;
+        let metrics = StandardMetrics::from_samples_with(
+            &batch_times,
+            batch_bytes,
+            iterations as u64,
+            self.trim_outliers,
+        );
+        let avg_ms = metrics.duration_ms.unwrap_or_default();
+        let throughput_bps = metrics.bytes_per_second.unwrap_or_default();
+        let single_avg_ms = single_times.iter().sum::<f64>() / single_times.len() as f64;
+        let objects_per_second = (self.batch_count as f64 / avg_ms) * 1000.0;
+        let speedup = single_avg_ms / avg_ms;
+
+        let metrics = metrics
+            .with_ops_per_second(objects_per_second)
+            .with_custom("operation", "batch_write")
+            .with_custom("data_pattern", self.data_pattern.name())
+            .with_custom("throughput_bps", throughput_bps)
+            .with_custom("backend", self.backend_label(false))
+            .with_custom("object_size", self.data_size as u64)
+            .with_custom("object_count", self.batch_count as u64)
+            .with_custom("objects_per_second", objects_per_second)
+            .with_custom("per_object_avg_ms", single_avg_ms)
+            .with_custom("speedup", speedup)
+            .with_custom("warmup_iterations", warmup as u64)
+            .with_custom("dropped_samples", dropped_samples as u64);
+
+        BenchmarkResult::new(&self.id, metrics.to_json_value())
+    }
+}
+
+/// Makes a payload unique by writing `tag` into its leading bytes.
+fn stamp_payload(payload: &mut [u8], tag: u64) {
+    let bytes = tag.to_le_bytes();
+    let len = payload.len().min(bytes.len());
+    payload[..len].copy_from_slice(&bytes[..len]);
 }
 
 #[async_trait]
@@ -81,6 +797,12 @@ impl super::BenchTarget for StorageBenchmark {
             StorageOperation::Write => "Storage Write",
             StorageOperation::Read => "Storage Read",
             StorageOperation::ContentAddressing => "Content Addressing",
+            StorageOperation::MixedReadWrite => "Storage Mixed Read/Write",
+            StorageOperation::BatchWrite => "Storage Batch Write",
+            StorageOperation::Dedup => "Storage Deduplication",
+            StorageOperation::ConcurrentWrite => "Storage Concurrent Writes",
+            StorageOperation::ColdWarmRead => "Storage Cold vs Warm Read",
+            StorageOperation::Scaling => "Storage Concurrency Scaling",
         }
     }
 
@@ -89,24 +811,97 @@ impl super::BenchTarget for StorageBenchmark {
             StorageOperation::Write => "Measures storage write throughput",
             StorageOperation::Read => "Measures storage read throughput",
             StorageOperation::ContentAddressing => "Measures content addressing (hash + store) throughput",
+            StorageOperation::MixedReadWrite => "Measures read and write latency under concurrent contention",
+            StorageOperation::BatchWrite => "Measures batched write throughput against per-object writes",
+            StorageOperation::Dedup => "Measures write throughput and space savings with repeated content",
+            StorageOperation::ConcurrentWrite => "Measures write scaling across concurrent writer tasks",
+            StorageOperation::ColdWarmRead => "Compares first-read latency against cached repeat reads",
+            StorageOperation::Scaling => "Measures write throughput at increasing concurrency levels",
         }
     }
 
+    async fn setup(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        // Stored objects must be materialized whole, so they cannot be tiled
+        let limit = super::payload::max_buffer_size();
+        if self.data_size > limit {
+            return Err(format!(
+                "data_size {} exceeds the {} byte payload limit (override with {})",
+                self.data_size,
+                limit,
+                super::payload::MAX_BUFFER_SIZE_ENV,
+            )
+            .into());
+        }
+        if let Some(root) = &self.disk_root {
+            tokio::fs::create_dir_all(root).await?;
+        }
+        Ok(())
+    }
+
+    async fn teardown(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+        if let Some(root) = &self.disk_root {
+            match tokio::fs::remove_dir_all(root).await {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.into()),
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
     async fn run(&self) -> BenchmarkResult {
-        use vault_storage::{ContentStore, InMemoryBackend, ContentAddress, HashAlgorithm};
+        use vault_storage::{CacheConfig, ContentAddress, ContentStore, HashAlgorithm, StorageCache};
 
-        // Create in-memory backend for benchmarking
-        let backend = Arc::new(InMemoryBackend::new());
-        let store = ContentStore::new(backend);
+        // Create the backend for benchmarking, optionally behind a bounded
+        // cache; the backend itself stays unbounded, so eviction is cache-only
+        let backend = match self.backend("objects").await {
+            Ok(backend) => backend,
+            Err(message) => return BenchmarkResult::failed(&self.id, message),
+        };
+        let cache = self.capacity.map(|capacity| {
+            let config = CacheConfig {
+                // Keep the object limit just above what fits, so the byte bound evicts first
+                max_objects: capacity / self.data_size.max(1) + 2,
+                max_size: capacity,
+                ..CacheConfig::default()
+            };
+            Arc::new(StorageCache::new(backend.clone(), config))
+        });
+        let store = match &cache {
+            Some(cache) => Arc::new(ContentStore::new(cache.clone())),
+            None => Arc::new(ContentStore::new(backend.clone())),
+        };
 
         // Generate test data
-        let data: Vec<u8> = (0..self.data_size).map(|i| (i % 256) as u8).collect();
+        let data = self.data_pattern.generate(self.data_size, self.seed);
 
-        let mut times = Vec::with_capacity(self.iterations);
+        let mut timer = self.timer();
+        let mut evictions = None;
+        let mut dedup = None;
+        let mut pacer = self.arrival_rate.and_then(Pacer::new);
+        let warmup = self.warmup_iterations();
 
         match self.operation {
             StorageOperation::Write => {
-                for i in 0..self.iterations {
+                if let Some(cache) = &cache {
+                    // Fill the cache until it starts evicting
+                    let mut tag = u64::MAX;
+                    while cache.cache_stats().evictions == 0 {
+                        let mut seed = data.clone();
+                        stamp_payload(&mut seed, tag);
+                        if let Err(e) = store.put(&seed).await {
+                            let message = format!("Fill write failed: {e}");
+                            return BenchmarkResult::failed(&self.id, message);
+                        }
+                        tag -= 1;
+                    }
+                }
+                let mut evictions_before = 0;
+
+                while let Some(i) = timer.next_iteration() {
+                    if timer.is_first_timed() {
+                        evictions_before = cache.as_ref().map_or(0, |c| c.cache_stats().evictions);
+                    }
+
                     // Generate unique data for each iteration to avoid deduplication
                     let mut unique_data = data.clone();
                     unique_data[0] = (i % 256) as u8;
@@ -114,23 +909,37 @@ impl super::BenchTarget for StorageBenchmark {
                         unique_data[1] = ((i / 256) % 256) as u8;
                     }
 
-                    let start = Instant::now();
-                    let _metadata = store.put(&unique_data).await.expect("Write failed");
-                    times.push(start.elapsed().as_secs_f64() * 1000.0);
+                    let start = arrival(&mut pacer).await;
+                    if let Err(e) = store.put(&unique_data).await {
+                        return BenchmarkResult::failed(&self.id, format!("Write failed: {e}"));
+                    }
+                    timer.record(start);
                 }
+
+                evictions = cache
+                    .as_ref()
+                    .map(|c| c.cache_stats().evictions - evictions_before);
             }
             StorageOperation::Read => {
                 // First, write data to read back
-                let metadata = store.put(&data).await.expect("Initial write failed");
+                let metadata = match store.put(&data).await {
+                    Ok(metadata) => metadata,
+                    Err(e) => {
+                        let message = format!("Initial write failed: {e}");
+                        return BenchmarkResult::failed(&self.id, message);
+                    }
+                };
 
-                for _ in 0..self.iterations {
-                    let start = Instant::now();
-                    let _content = store.get(&metadata.address).await.expect("Read failed");
-                    times.push(start.elapsed().as_secs_f64() * 1000.0);
+                while timer.next_iteration().is_some() {
+                    let start = arrival(&mut pacer).await;
+                    if let Err(e) = store.get(&metadata.address).await {
+                        return BenchmarkResult::failed(&self.id, format!("Read failed: {e}"));
+                    }
+                    timer.record(start);
                 }
             }
             StorageOperation::ContentAddressing => {
-                for i in 0..self.iterations {
+                while let Some(i) = timer.next_iteration() {
                     // Generate unique data
                     let mut unique_data = data.clone();
                     unique_data[0] = (i % 256) as u8;
@@ -138,46 +947,99 @@ impl super::BenchTarget for StorageBenchmark {
                         unique_data[1] = ((i / 256) % 256) as u8;
                     }
 
-                    let start = Instant::now();
+                    let start = arrival(&mut pacer).await;
                     // Compute content address (hash)
-                    let _address = ContentAddress::from_data(HashAlgorithm::Blake3, &unique_data);
-                    times.push(start.elapsed().as_secs_f64() * 1000.0);
+                    // The address is otherwise unused; black_box keeps the hash
+                    let input = black_box(&unique_data);
+                    black_box(ContentAddress::from_data(HashAlgorithm::Blake3, input));
+                    timer.record(start);
                 }
             }
-        }
+            StorageOperation::Dedup => {
+                // Duplicates repeat the base payload, which is stored up front
+                if let Err(e) = store.put(&data).await {
+                    return BenchmarkResult::failed(&self.id, format!("Initial write failed: {e}"));
+                }
+                let mut logical_bytes = data.len() as u64;
 
-        // Calculate statistics
-        let avg_ms = times.iter().sum::<f64>() / self.iterations as f64;
-        let throughput_bps = (self.data_size as f64 / avg_ms) * 1000.0;
-        let ops_per_second = 1000.0 / avg_ms;
+                while let Some(i) = timer.next_iteration() {
+                    // Spread duplicates evenly: iteration i repeats content when
+                    // the running duplicate count ticks over
+                    let duplicates_before = (i as f64 * self.duplicate_fraction).floor();
+                    let duplicates_after = ((i + 1) as f64 * self.duplicate_fraction).floor();
+                    let mut payload = data.clone();
+                    if duplicates_after == duplicates_before {
+                        stamp_payload(&mut payload, i as u64 + 1);
+                    }
 
-        // Sort for percentiles
-        times.sort_by(|a, b| a.partial_cmp(b).unwrap());
+                    let start = arrival(&mut pacer).await;
+                    if let Err(e) = store.put(&payload).await {
+                        return BenchmarkResult::failed(&self.id, format!("Write failed: {e}"));
+                    }
+                    logical_bytes += payload.len() as u64;
+                    timer.record(start);
+                }
 
-        let p50_idx = self.iterations / 2;
-        let p95_idx = (self.iterations as f64 * 0.95) as usize;
-        let p99_idx = (self.iterations as f64 * 0.99) as usize;
+                let physical_bytes = backend.stats().await.map_or(0, |stats| stats.total_size);
+                dedup = Some((logical_bytes, physical_bytes));
+            }
+            StorageOperation::MixedReadWrite => return self.run_mixed(store, data).await,
+            StorageOperation::ConcurrentWrite => {
+                return self.run_concurrent_write(store, data).await;
+            }
+            StorageOperation::BatchWrite => return self.run_batch(data).await,
+            StorageOperation::ColdWarmRead => return self.run_cold_warm(backend, data).await,
+            StorageOperation::Scaling => return self.run_scaling(data).await,
+        }
+        let mut times = timer.into_samples();
+
+        // Calculate statistics
+        let dropped_samples = drop_non_finite(&mut times);
+        let iterations = times.len();
 
         let operation_name = match self.operation {
             StorageOperation::Write => "write",
             StorageOperation::Read => "read",
             StorageOperation::ContentAddressing => "content_addressing",
+            StorageOperation::MixedReadWrite => "mixed_read_write",
+            StorageOperation::BatchWrite => "batch_write",
+            StorageOperation::Dedup => "dedup_write",
+            StorageOperation::ConcurrentWrite => "concurrent_write",
+            StorageOperation::ColdWarmRead => "cold_warm_read",
+            StorageOperation::Scaling => "scaling_write",
         };
 
-        let metrics = StandardMetrics::new()
-            .with_duration_ms(avg_ms)
-            .with_data_size(self.data_size as u64)
-            .with_iterations(self.iterations as u64)
-            .with_bytes_per_second(throughput_bps)
-            .with_ops_per_second(ops_per_second)
-            .with_latencies(
-                times[p50_idx],
-                times[p95_idx.min(self.iterations - 1)],
-                times[p99_idx.min(self.iterations - 1)],
-            )
+        let mut metrics = StandardMetrics::from_samples_with(
+            &times,
+            self.data_size as u64,
+            iterations as u64,
+            self.trim_outliers,
+        );
+        let throughput_bps = metrics.bytes_per_second.unwrap_or_default();
+        metrics = metrics
             .with_custom("operation", operation_name)
+            .with_custom("data_pattern", self.data_pattern.name())
             .with_custom("throughput_bps", throughput_bps)
-            .with_custom("backend", "in-memory");
+            .with_custom("backend", self.backend_label(cache.is_some()))
+            .with_custom("warmup_iterations", warmup as u64)
+            .with_custom("dropped_samples", dropped_samples as u64);
+
+        if let Some(capacity) = self.capacity {
+            metrics = metrics.with_custom("capacity_bytes", capacity as u64);
+        }
+        if let Some(evictions) = evictions {
+            metrics = metrics.with_custom("evictions", evictions);
+        }
+        if let Some((logical_bytes, physical_bytes)) = dedup {
+            metrics = metrics
+                .with_custom("duplicate_fraction", self.duplicate_fraction)
+                .with_custom("logical_bytes", logical_bytes)
+                .with_custom("physical_bytes", physical_bytes)
+                .with_custom("dedup_ratio", logical_bytes as f64 / physical_bytes as f64);
+        }
+        if let Some(pacer) = &pacer {
+            metrics = pacer.annotate(metrics);
+        }
 
         BenchmarkResult::new(&self.id, metrics.to_json_value())
     }
@@ -199,6 +1061,110 @@ mod tests {
         assert!(result.metrics["throughput_bps"].as_f64().unwrap() > 0.0);
     }
 
+    #[tokio::test]
+    async fn test_cold_warm_read_benchmark() {
+        let benchmark = StorageBenchmark::cold_warm_read(64 * 1024, "test-cold-warm")
+            .with_iterations(20)
+            .with_warmup(2);
+
+        let result = benchmark.run().await;
+
+        assert!(!result.is_failed(), "{:?}", result.error);
+        assert_eq!(result.metrics["iterations"], 20);
+        assert!(result.metrics["cold_read_ms"].as_f64().unwrap() > 0.0);
+        assert!(result.metrics["warm_read_ms"].as_f64().unwrap() > 0.0);
+        // Each read looks up metadata and content: both miss cold, both hit warm
+        assert_eq!(result.metrics["cache_misses"], 44);
+        assert_eq!(result.metrics["cache_hits"], 44);
+    }
+
+    #[tokio::test]
+    async fn test_dedup_benchmark() {
+        let unique = StorageBenchmark::dedup(1024, 0.0, "test-dedup-unique")
+            .with_iterations(20)
+            .run()
+            .await;
+        let repeated = StorageBenchmark::dedup(1024, 0.75, "test-dedup-repeated")
+            .with_iterations(20)
+            .run()
+            .await;
+
+        let ratio = |r: &BenchmarkResult| r.metrics["dedup_ratio"].as_f64().unwrap();
+        assert!(ratio(&unique) < 1.0, "metadata overhead keeps unique writes below 1.0");
+        assert!(ratio(&repeated) > 2.0);
+        assert_eq!(repeated.metrics["operation"], "dedup_write");
+    }
+
+    #[tokio::test]
+    async fn test_concurrent_write_benchmark() {
+        let benchmark = StorageBenchmark::concurrent_write(1024, 4, "test-concurrent-write")
+            .with_iterations(40);
+
+        let result = benchmark.run().await;
+
+        assert_eq!(result.metrics["concurrency"], 4);
+        assert_eq!(result.metrics["iterations"], 40);
+        let task_min = result.metrics["task_p99_min_ms"].as_f64().unwrap();
+        let task_max = result.metrics["task_p99_max_ms"].as_f64().unwrap();
+        assert!(task_min <= task_max);
+    }
+
+    #[tokio::test]
+    async fn test_write_concurrently_reports_storage_errors() {
+        let backend = Arc::new(vault_storage::InMemoryBackend::with_max_size(4 * 1024));
+        let store = Arc::new(vault_storage::ContentStore::new(backend));
+
+        let outcome = write_concurrently(store, Arc::new(vec![0; 1024]), 4, 4).await;
+
+        assert!(outcome.unwrap_err().starts_with("Write failed"));
+    }
+
+    #[tokio::test]
+    async fn test_scaling_benchmark() {
+        let benchmark = StorageBenchmark::scaling(1024, vec![1, 2, 4], "test-scaling")
+            .with_iterations(8);
+
+        let result = benchmark.run().await;
+
+        assert!(!result.is_failed(), "{:?}", result.error);
+        let scaling = result.metrics["scaling"].as_array().unwrap();
+        let levels: Vec<u64> = scaling.iter().map(|s| s["concurrency"].as_u64().unwrap()).collect();
+        assert_eq!(levels, [1, 2, 4]);
+        assert!(scaling.iter().all(|s| s["ops_per_second"].as_f64().unwrap() > 0.0));
+        assert_eq!(result.metrics["iterations"], 24);
+        assert!(levels.contains(&result.metrics["peak_concurrency"].as_u64().unwrap()));
+    }
+
+    #[tokio::test]
+    async fn test_on_disk_benchmark() {
+        let dir = tempfile::TempDir::new().unwrap();
+        let benchmark = StorageBenchmark::on_disk(1024, "test-on-disk", dir.path())
+            .with_iterations(5);
+
+        benchmark.setup().await.unwrap();
+        let result = benchmark.run().await;
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 1);
+        benchmark.teardown().await.unwrap();
+
+        assert_eq!(result.metrics["backend"], "filesystem");
+        assert_eq!(result.metrics["iterations"], 5);
+        assert_eq!(std::fs::read_dir(dir.path()).unwrap().count(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_unusable_disk_dir_fails_run() {
+        // A regular file cannot hold the backend's directory
+        let file = tempfile::NamedTempFile::new().unwrap();
+        let result = StorageBenchmark::on_disk(1024, "test-bad-disk", file.path())
+            .with_iterations(5)
+            .run()
+            .await;
+
+        assert!(result.is_failed());
+        let error = result.error.unwrap();
+        assert!(error.starts_with("Failed to create filesystem backend"), "{error}");
+    }
+
     #[tokio::test]
     async fn test_read_benchmark() {
         let benchmark = StorageBenchmark::read(1024, "test-read")
@@ -220,4 +1186,77 @@ mod tests {
         assert_eq!(result.target_id, "test-content-addr");
         assert!(result.metrics["ops_per_second"].as_f64().unwrap() > 0.0);
     }
+
+    #[tokio::test]
+    async fn test_write_under_eviction() {
+        let benchmark = StorageBenchmark::write(1024, "test-eviction")
+            .with_iterations(10)
+            .with_capacity(8 * 1024);
+
+        let result = benchmark.run().await;
+
+        assert_eq!(result.metrics["capacity_bytes"].as_u64().unwrap(), 8 * 1024);
+        assert!(result.metrics["evictions"].as_u64().unwrap() >= 10);
+    }
+
+    #[tokio::test]
+    async fn test_batch_write_benchmark() {
+        let benchmark = StorageBenchmark::batch_write(256, 50, "test-batch-write")
+            .with_iterations(3);
+
+        let result = benchmark.run().await;
+
+        assert_eq!(result.metrics["operation"], "batch_write");
+        assert_eq!(result.metrics["object_count"].as_u64().unwrap(), 50);
+        assert!(result.metrics["objects_per_second"].as_f64().unwrap() > 0.0);
+        assert!(result.metrics["speedup"].as_f64().unwrap() > 0.0);
+    }
+
+    #[tokio::test]
+    async fn test_open_loop_write() {
+        let benchmark = StorageBenchmark::write(1024, "test-open-loop")
+            .with_iterations(10)
+            .with_arrival_rate(2000.0);
+
+        let result = benchmark.run().await;
+
+        assert_eq!(result.metrics["load_model"], "open_loop");
+        assert_eq!(result.metrics["arrival_rate"], 2000.0);
+        assert!(result.metrics["backlog"].is_u64());
+
+        for rate in [0.0, f64::NAN] {
+            let closed_loop = StorageBenchmark::write(1024, "test-invalid-rate")
+                .with_iterations(3)
+                .with_arrival_rate(rate)
+                .run()
+                .await;
+            assert!(!closed_loop.is_failed());
+            assert!(closed_loop.metrics.get("load_model").is_none());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_oversized_payload_rejected() {
+        let benchmark = StorageBenchmark::write(usize::MAX / 2, "test-oversized");
+
+        let err = benchmark.setup().await.unwrap_err();
+
+        assert!(err.to_string().contains("payload limit"));
+    }
+
+    #[tokio::test]
+    async fn test_mixed_read_write_benchmark() {
+        let benchmark = StorageBenchmark::mixed_read_write(1024, 0.5, "test-mixed-rw")
+            .with_iterations(40)
+            .with_concurrency(4);
+
+        let result = benchmark.run().await;
+
+        assert_eq!(result.target_id, "test-mixed-rw");
+        assert_eq!(result.metrics["iterations"].as_u64().unwrap(), 40);
+        assert!(result.metrics["read_count"].as_u64().unwrap() > 0);
+        assert!(result.metrics["write_count"].as_u64().unwrap() > 0);
+        assert!(result.metrics["read_latency_p50_ms"].as_f64().is_some());
+        assert!(result.metrics["write_latency_p50_ms"].as_f64().is_some());
+    }
 }
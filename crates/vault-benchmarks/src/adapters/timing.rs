@@ -0,0 +1,164 @@
+//! Shared timing harness for benchmark loops.
+//!
+//! Every adapter runs the same loop shape: untimed warmup iterations, then
+//! timed iterations until the count or the time budget runs out. Per-iteration
+//! setup (cloning a payload, stamping it unique) belongs before the clock
+//! starts, so only the operation under test is measured. [`IterationTimer`]
+//! drives every such loop, including ones that await, pace arrivals, or bail
+//! out on errors, which is why there is no closure-based helper.
+
+#[cfg(test)]
+use std::cell::Cell;
+use std::time::{Duration, Instant};
+
+#[cfg(test)]
+thread_local! {
+    /// Timed samples left to record as NaN on this thread; see
+    /// [`inject_nan_samples`].
+    static INJECTED_NANS: Cell<usize> = const { Cell::new(0) };
+}
+
+/// Makes the next `count` timed samples recorded on this thread NaN, as a
+/// degenerate clock reading would.
+#[cfg(test)]
+pub(crate) fn inject_nan_samples(count: usize) {
+    INJECTED_NANS.with(|left| left.set(count));
+}
+
+/// Returns true if the next sample should be recorded as NaN.
+#[cfg(test)]
+fn take_injected_nan() -> bool {
+    INJECTED_NANS.with(|left| left.replace(left.get().saturating_sub(1)) > 0)
+}
+
+/// Drives a warmup-then-timed benchmark loop and collects its samples.
+///
+/// ```ignore
+/// let mut timer = IterationTimer::new(iterations, warmup);
+/// while let Some(i) = timer.next_iteration() {
+///     let input = prepare(i);
+///     let start = Instant::now();
+///     operation(input);
+///     timer.record(start);
+/// }
+/// let times = timer.into_samples();
+/// ```
+pub(crate) struct IterationTimer {
+    iterations: usize,
+    warmup: usize,
+    max_total_time: Option<Duration>,
+    current: Option<usize>,
+    loop_start: Option<Instant>,
+    times: Vec<f64>,
+    exhausted: bool,
+}
+
+impl IterationTimer {
+    /// Creates a timer for `warmup` untimed then `iterations` timed iterations.
+    pub(crate) fn new(iterations: usize, warmup: usize) -> Self {
+        Self {
+            iterations,
+            warmup,
+            max_total_time: None,
+            current: None,
+            loop_start: None,
+            times: Vec::with_capacity(iterations),
+            exhausted: false,
+        }
+    }
+
+    /// Stops the loop once `max_total_time` has been spent, counted from the
+    /// first iteration and checked after each timed one.
+    pub(crate) fn with_max_total_time(mut self, max_total_time: Option<Duration>) -> Self {
+        self.max_total_time = max_total_time;
+        self
+    }
+
+    /// Advances to the next iteration and returns its index, or `None` once
+    /// the loop is finished.
+    pub(crate) fn next_iteration(&mut self) -> Option<usize> {
+        self.loop_start.get_or_insert_with(Instant::now);
+        let next = self.current.map_or(0, |i| i + 1);
+        if self.exhausted || next >= self.warmup + self.iterations {
+            return None;
+        }
+        self.current = Some(next);
+        Some(next)
+    }
+
+    /// Returns true while the current iteration is a warmup iteration.
+    pub(crate) fn is_warmup(&self) -> bool {
+        self.current.is_some_and(|i| i < self.warmup)
+    }
+
+    /// Returns true on the first timed iteration, where per-run counters
+    /// that should exclude warmup get reset.
+    pub(crate) fn is_first_timed(&self) -> bool {
+        self.current == Some(self.warmup)
+    }
+
+    /// Records the current iteration as having started at `start`.
+    pub(crate) fn record(&mut self, start: Instant) {
+        self.record_ms(start.elapsed().as_secs_f64() * 1000.0);
+    }
+
+    /// Records an already measured duration for the current iteration.
+    ///
+    /// Warmup iterations are discarded.
+    pub(crate) fn record_ms(&mut self, elapsed_ms: f64) {
+        if self.is_warmup() {
+            return;
+        }
+        #[cfg(test)]
+        let elapsed_ms = if take_injected_nan() { f64::NAN } else { elapsed_ms };
+        self.times.push(elapsed_ms);
+
+        let budget_spent = self
+            .max_total_time
+            .zip(self.loop_start)
+            .is_some_and(|(max, loop_start)| loop_start.elapsed() >= max);
+        if budget_spent {
+            self.exhausted = true;
+        }
+    }
+
+    /// Returns the timed samples, in milliseconds.
+    pub(crate) fn into_samples(self) -> Vec<f64> {
+        self.times
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iteration_timer_discards_warmup() {
+        let mut timer = IterationTimer::new(5, 3);
+        let mut calls = 0;
+        while timer.next_iteration().is_some() {
+            let start = Instant::now();
+            calls += 1;
+            timer.record(start);
+        }
+
+        let times = timer.into_samples();
+        assert_eq!(calls, 8);
+        assert_eq!(times.len(), 5);
+        assert!(times.iter().all(|t| *t >= 0.0));
+    }
+
+    #[test]
+    fn test_iteration_timer_stops_at_budget() {
+        let mut timer = IterationTimer::new(50, 2).with_max_total_time(Some(Duration::ZERO));
+        let mut seen = Vec::new();
+        while let Some(i) = timer.next_iteration() {
+            seen.push((i, timer.is_warmup(), timer.is_first_timed()));
+            timer.record_ms(1.0);
+        }
+
+        // The budget is only checked after a timed iteration
+        assert_eq!(seen, [(0, true, false), (1, true, false), (2, false, true)]);
+        assert_eq!(timer.into_samples(), [1.0]);
+    }
+}
@@ -0,0 +1,114 @@
+//! GitHub Actions workflow-command annotations.
+//!
+//! Printing `::error::` and `::warning::` lines to stdout makes GitHub
+//! Actions attach the message to the run and the pull request, so threshold
+//! violations and regressions show up inline instead of only in the log.
+
+use crate::regression::Regression;
+use crate::thresholds::Violation;
+
+/// Environment variable GitHub Actions sets to `true` on its runners.
+pub const GITHUB_ACTIONS_ENV: &str = "GITHUB_ACTIONS";
+
+/// Severity of an annotation.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AnnotationLevel {
+    /// Rendered as a warning; does not fail the step by itself.
+    Warning,
+    /// Rendered as an error.
+    Error,
+}
+
+impl AnnotationLevel {
+    /// Returns the workflow command name.
+    fn command(self) -> &'static str {
+        match self {
+            Self::Warning => "warning",
+            Self::Error => "error",
+        }
+    }
+}
+
+/// Returns true when running inside GitHub Actions.
+#[must_use]
+pub fn running_in_github_actions() -> bool {
+    std::env::var(GITHUB_ACTIONS_ENV).is_ok_and(|v| v == "true")
+}
+
+/// Renders a threshold violation as a workflow command.
+#[must_use]
+pub fn violation_annotation(violation: &Violation, level: AnnotationLevel) -> String {
+    let message = format!("{} ({:+.1}%)", violation, violation.percent_change());
+    annotation(level, "Benchmark threshold breached", &message)
+}
+
+/// Renders a baseline regression as a workflow command.
+#[must_use]
+pub fn regression_annotation(regression: &Regression, level: AnnotationLevel) -> String {
+    let message = format!(
+        "{}: {} {:.2} -> {:.2} ({:+.1}%)",
+        regression.target_id,
+        regression.metric,
+        regression.baseline,
+        regression.current,
+        regression.percent_change
+    );
+    annotation(level, "Benchmark regression", &message)
+}
+
+/// Formats one workflow command line.
+fn annotation(level: AnnotationLevel, title: &str, message: &str) -> String {
+    format!(
+        "::{} title={}::{}",
+        level.command(),
+        escape_property(title),
+        escape_data(message)
+    )
+}
+
+/// Escapes a command message.
+fn escape_data(value: &str) -> String {
+    value.replace('%', "%25").replace('\r', "%0D").replace('\n', "%0A")
+}
+
+/// Escapes a command property value, which additionally reserves `:` and `,`.
+fn escape_property(value: &str) -> String {
+    escape_data(value).replace(':', "%3A").replace(',', "%2C")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::thresholds::Bound;
+
+    #[test]
+    fn test_annotations() {
+        let violation = Violation {
+            target_id: "storage-read-1mb".to_string(),
+            metric: "latency_p99_ms".to_string(),
+            bound: Bound::Max,
+            limit: 4.0,
+            actual: 5.0,
+        };
+        assert_eq!(
+            violation_annotation(&violation, AnnotationLevel::Error),
+            "::error title=Benchmark threshold breached::storage-read-1mb: latency_p99_ms 5.00 \
+             is above the maximum of 4.00 (+25.0%25)"
+        );
+
+        let regression = Regression {
+            target_id: "hashing-blake3-1mb".to_string(),
+            metric: "ops_per_second".to_string(),
+            baseline: 1000.0,
+            current: 850.0,
+            percent_change: -15.0,
+        };
+        assert_eq!(
+            regression_annotation(&regression, AnnotationLevel::Warning),
+            "::warning title=Benchmark regression::hashing-blake3-1mb: ops_per_second \
+             1000.00 -> 850.00 (-15.0%25)"
+        );
+
+        assert_eq!(escape_property("a:b,c%\n"), "a%3Ab%2Cc%25%0A");
+    }
+}
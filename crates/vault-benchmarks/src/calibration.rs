@@ -0,0 +1,105 @@
+//! Iteration-count calibration against a target wall-clock duration.
+//!
+//! Fixed iteration counts give very different run times across payload sizes
+//! and hosts. Calibration runs a short probe of each target, estimates the
+//! cost of one iteration from its `duration_ms`, and picks the iteration
+//! count that roughly fills the requested duration.
+
+use crate::{run_target_isolated, BenchmarkResult, RunContext, TargetSpec};
+use std::sync::Arc;
+use std::time::Duration;
+
+/// Timed iterations in a calibration probe.
+pub const PROBE_ITERATIONS: usize = 3;
+
+/// Fewest iterations calibration will choose, so percentiles stay meaningful.
+pub const MIN_CALIBRATED_ITERATIONS: usize = 10;
+
+/// Most iterations calibration will choose.
+pub const MAX_CALIBRATED_ITERATIONS: usize = 1_000_000;
+
+/// Returns the iteration count that fills `target` at `per_iteration_ms` each.
+///
+/// The count is clamped to [`MIN_CALIBRATED_ITERATIONS`] and
+/// [`MAX_CALIBRATED_ITERATIONS`]; a non-positive cost yields the maximum.
+#[must_use]
+pub fn iterations_for_duration(per_iteration_ms: f64, target: Duration) -> usize {
+    if per_iteration_ms.is_nan() || per_iteration_ms <= 0.0 {
+        return MAX_CALIBRATED_ITERATIONS;
+    }
+
+    let iterations = (target.as_secs_f64() * 1000.0 / per_iteration_ms).ceil();
+    (iterations as usize).clamp(MIN_CALIBRATED_ITERATIONS, MAX_CALIBRATED_ITERATIONS)
+}
+
+/// Probes a spec and returns the iteration count that roughly fills `target`.
+///
+/// Returns `None` if the probe fails or reports no `duration_ms`.
+pub async fn calibrate_iterations(spec: &TargetSpec, target: Duration) -> Option<usize> {
+    let mut probe = spec.clone();
+    probe.iterations = PROBE_ITERATIONS;
+    probe.warmup = Some(1);
+
+    let result = run_target_isolated(probe.build(), Arc::new(RunContext::new()), false).await;
+    if result.is_failed() {
+        return None;
+    }
+
+    let per_iteration_ms = result.metrics.get("duration_ms")?.as_f64()?;
+    Some(iterations_for_duration(per_iteration_ms, target))
+}
+
+/// Calibrates every spec in place, returning the chosen count per target id.
+///
+/// Specs whose probe fails keep their configured iteration count.
+pub async fn calibrate_specs(specs: &mut [TargetSpec], target: Duration) -> Vec<(String, usize)> {
+    let mut chosen = Vec::with_capacity(specs.len());
+    for spec in specs {
+        match calibrate_iterations(spec, target).await {
+            Some(iterations) => {
+                spec.iterations = iterations;
+                chosen.push((spec.id.clone(), iterations));
+            }
+            None => tracing::warn!(
+                target_id = %spec.id,
+                "calibration probe failed; keeping the configured iteration count"
+            ),
+        }
+    }
+    chosen
+}
+
+/// Records the calibrated iteration count and target duration on each result.
+pub(crate) fn record_calibration(
+    results: &mut [BenchmarkResult],
+    chosen: &[(String, usize)],
+    target: Duration,
+) {
+    for result in results {
+        let Some((_, iterations)) = chosen.iter().find(|(id, _)| *id == result.target_id) else {
+            continue;
+        };
+        if let Some(obj) = result.metrics.as_object_mut() {
+            obj.insert("calibrated_iterations".to_string(), (*iterations as u64).into());
+            obj.insert(
+                "target_duration_ms".to_string(),
+                (target.as_secs_f64() * 1000.0).into(),
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_iterations_for_duration() {
+        let second = Duration::from_secs(1);
+        assert_eq!(iterations_for_duration(2.0, second), 500);
+        assert_eq!(iterations_for_duration(3.0, second), 334);
+        assert_eq!(iterations_for_duration(500.0, second), MIN_CALIBRATED_ITERATIONS);
+        assert_eq!(iterations_for_duration(1e-9, second), MAX_CALIBRATED_ITERATIONS);
+        assert_eq!(iterations_for_duration(0.0, second), MAX_CALIBRATED_ITERATIONS);
+    }
+}
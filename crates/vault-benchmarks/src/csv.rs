@@ -0,0 +1,123 @@
+//! CSV export of benchmark results.
+//!
+//! Renders one row per result so spreadsheets and notebooks can load a run,
+//! or a whole history of runs, without parsing the JSON. Columns are the
+//! result's identity followed by every scalar metric any result reported.
+
+use crate::BenchmarkResult;
+use std::collections::BTreeSet;
+use std::fmt::Write;
+
+/// Columns written before the metrics, in output order.
+pub const LEADING_COLUMNS: &[&str] = &["target_id", "timestamp", "commit", "error"];
+
+/// Renders `results` as CSV with a header row, one row per result.
+///
+/// Metric columns are sorted by name. Nested metrics (objects and arrays)
+/// are left out, and a result without a metric leaves its cell empty.
+/// Non-finite sentinels are written as stored.
+#[must_use]
+pub fn to_csv(results: &[BenchmarkResult]) -> String {
+    let columns = metric_columns(results);
+
+    let mut out = String::new();
+    write_row(&mut out, LEADING_COLUMNS.iter().chain(&columns).copied());
+    for result in results {
+        write_row(&mut out, row_cells(result, &columns).iter().map(String::as_str));
+    }
+
+    out
+}
+
+/// Writes one line of escaped, comma-separated cells.
+fn write_row<'a>(out: &mut String, cells: impl Iterator<Item = &'a str>) {
+    let cells: Vec<String> = cells.map(escape).collect();
+    let _ = writeln!(out, "{}", cells.join(","));
+}
+
+/// Returns the sorted names of every scalar metric in `results`, leaving out
+/// names already covered by [`LEADING_COLUMNS`].
+pub(crate) fn metric_columns(results: &[BenchmarkResult]) -> Vec<&str> {
+    let names: BTreeSet<&str> = results
+        .iter()
+        .filter_map(|r| r.metrics.as_object())
+        .flat_map(|metrics| metrics.iter())
+        .filter(|(name, value)| {
+            !LEADING_COLUMNS.contains(&name.as_str()) && scalar_cell(value).is_some()
+        })
+        .map(|(name, _)| name.as_str())
+        .collect();
+    names.into_iter().collect()
+}
+
+/// Returns the unescaped cells of one result: the leading columns, then one
+/// cell per metric column.
+pub(crate) fn row_cells(result: &BenchmarkResult, columns: &[&str]) -> Vec<String> {
+    let mut cells = vec![
+        result.target_id.clone(),
+        result.timestamp.to_rfc3339(),
+        result.commit.clone().unwrap_or_default(),
+        // Legacy results recorded failures as an `error` metric
+        result
+            .error
+            .as_deref()
+            .or_else(|| result.metrics["error"].as_str())
+            .unwrap_or_default()
+            .to_string(),
+    ];
+    cells.extend(
+        columns
+            .iter()
+            .map(|name| result.metrics.get(*name).and_then(scalar_cell).unwrap_or_default()),
+    );
+    cells
+}
+
+/// Renders a scalar metric value, or `None` for nested values.
+fn scalar_cell(value: &serde_json::Value) -> Option<String> {
+    match value {
+        serde_json::Value::Null => Some(String::new()),
+        serde_json::Value::Bool(b) => Some(b.to_string()),
+        serde_json::Value::Number(n) => Some(n.to_string()),
+        serde_json::Value::String(s) => Some(s.clone()),
+        serde_json::Value::Array(_) | serde_json::Value::Object(_) => None,
+    }
+}
+
+/// Quotes a field if it contains a delimiter, quote, or line break.
+fn escape(field: &str) -> String {
+    if field.contains([',', '"', '\n', '\r']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else {
+        field.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_to_csv() {
+        let results = vec![
+            BenchmarkResult::new(
+                "hashing-blake3-1mb",
+                json!({"ops_per_second": 12.5, "algorithm": "BLAKE3, keyed", "lifecycle": {}}),
+            )
+            .with_commit("abc123"),
+            BenchmarkResult::failed("storage-read-1mb", "disk \"full\""),
+        ];
+
+        let csv = to_csv(&results);
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(
+            lines[0],
+            "target_id,timestamp,commit,error,algorithm,ops_per_second,success_rate"
+        );
+        assert!(lines[1].starts_with("hashing-blake3-1mb,"));
+        assert!(lines[1].ends_with(",abc123,,\"BLAKE3, keyed\",12.5,"));
+        assert!(lines[2].ends_with(",,\"disk \"\"full\"\"\",,,0.0"));
+        assert_eq!(lines.len(), 3);
+    }
+}
@@ -0,0 +1,108 @@
+//! Optional CPU cycle counting for per-byte cost metrics.
+//!
+//! With the `cycle-counter` feature enabled on x86/x86_64 hosts that expose an
+//! invariant TSC, timed operations are bracketed with raw TSC reads so that
+//! adapters can report a `cycles_per_byte` metric. Everywhere else the tally
+//! stays inert and the metric is omitted.
+
+#[cfg(all(feature = "cycle-counter", any(target_arch = "x86", target_arch = "x86_64")))]
+mod imp {
+    /// TSC-backed counter.
+    pub struct Counter {
+        clock: quanta::Clock,
+    }
+
+    impl Counter {
+        /// Returns a counter only when the TSC is invariant, since that is
+        /// the only case in which `quanta` reads the TSC directly.
+        pub fn detect() -> Option<Self> {
+            let invariant = raw_cpuid::CpuId::new()
+                .get_advanced_power_mgmt_info()
+                .is_some_and(|info| info.has_invariant_tsc());
+
+            invariant.then(|| Self { clock: quanta::Clock::new() })
+        }
+
+        pub fn read(&self) -> u64 {
+            self.clock.raw()
+        }
+    }
+}
+
+#[cfg(not(all(feature = "cycle-counter", any(target_arch = "x86", target_arch = "x86_64"))))]
+mod imp {
+    /// Placeholder used when no cycle counter is compiled in.
+    pub struct Counter;
+
+    impl Counter {
+        pub fn detect() -> Option<Self> {
+            None
+        }
+
+        pub fn read(&self) -> u64 {
+            0
+        }
+    }
+}
+
+/// Accumulates cycles and bytes across timed operations.
+pub(crate) struct CycleTally {
+    counter: Option<imp::Counter>,
+    cycles: u64,
+    bytes: u64,
+}
+
+impl CycleTally {
+    /// Creates a tally, probing for a usable cycle counter.
+    pub(crate) fn new() -> Self {
+        Self {
+            counter: imp::Counter::detect(),
+            cycles: 0,
+            bytes: 0,
+        }
+    }
+
+    /// Reads the counter before a timed operation.
+    pub(crate) fn start(&self) -> Option<u64> {
+        self.counter.as_ref().map(imp::Counter::read)
+    }
+
+    /// Records the cycles elapsed since `start` for an operation over `bytes`.
+    pub(crate) fn record(&mut self, start: Option<u64>, bytes: usize) {
+        if let (Some(counter), Some(start)) = (&self.counter, start) {
+            self.cycles += counter.read().saturating_sub(start);
+            self.bytes += bytes as u64;
+        }
+    }
+
+    /// Discards everything recorded so far, e.g. once warmup is over.
+    pub(crate) fn reset(&mut self) {
+        self.cycles = 0;
+        self.bytes = 0;
+    }
+
+    /// Returns the average cycles per byte, if a counter was available.
+    pub(crate) fn cycles_per_byte(&self) -> Option<f64> {
+        (self.bytes > 0).then(|| self.cycles as f64 / self.bytes as f64)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tally_reports_only_with_counter() {
+        let mut tally = CycleTally::new();
+        let available = tally.counter.is_some();
+
+        let start = tally.start();
+        let _sum: u64 = (0..1024u64).sum();
+        tally.record(start, 1024);
+
+        assert_eq!(tally.cycles_per_byte().is_some(), available);
+        if !cfg!(feature = "cycle-counter") {
+            assert!(tally.cycles_per_byte().is_none());
+        }
+    }
+}
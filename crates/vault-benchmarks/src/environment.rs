@@ -0,0 +1,124 @@
+//! Host environment metadata attached to benchmark results.
+//!
+//! Numbers from different machines are not comparable, so each suite run
+//! records where it ran, and which commit it measured. Fields that cannot be
+//! determined on the current platform are left as `None`.
+
+use serde::{Deserialize, Serialize};
+use std::process::Command;
+
+/// Environment variable that overrides the detected git commit.
+pub const COMMIT_ENV: &str = "VAULT_BENCH_COMMIT";
+
+/// Returns the commit being benchmarked.
+///
+/// Uses [`COMMIT_ENV`] if set, otherwise `git rev-parse HEAD`. Returns `None`
+/// if neither is available; a missing commit never fails the run.
+#[must_use]
+pub fn detect_commit() -> Option<String> {
+    if let Some(commit) = std::env::var(COMMIT_ENV).ok().filter(|c| !c.trim().is_empty()) {
+        return Some(commit.trim().to_string());
+    }
+
+    let output = Command::new("git").args(["rev-parse", "HEAD"]).output().ok()?;
+    if !output.status.success() {
+        return None;
+    }
+    let commit = String::from_utf8(output.stdout).ok()?;
+    Some(commit.trim().to_string()).filter(|c| !c.is_empty())
+}
+
+/// Description of the machine and toolchain a benchmark ran on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct EnvironmentInfo {
+    /// Operating system (e.g. `linux`, `macos`).
+    pub os: String,
+    /// CPU architecture (e.g. `x86_64`, `aarch64`).
+    pub arch: String,
+    /// CPU model name, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cpu_model: Option<String>,
+    /// Number of logical cores available to the process.
+    pub logical_cores: usize,
+    /// Total physical memory in bytes, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub total_memory_bytes: Option<u64>,
+    /// Version of the compiler that built the benchmarks, if known.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub rustc_version: Option<String>,
+}
+
+impl EnvironmentInfo {
+    /// Detects the current host environment.
+    ///
+    /// CPU model and memory are read from `/proc` and are only available on
+    /// Linux.
+    #[must_use]
+    pub fn detect() -> Self {
+        Self {
+            os: std::env::consts::OS.to_string(),
+            arch: std::env::consts::ARCH.to_string(),
+            cpu_model: cpu_model(),
+            logical_cores: std::thread::available_parallelism().map_or(1, |n| n.get()),
+            total_memory_bytes: total_memory_bytes(),
+            rustc_version: option_env!("VAULT_BENCH_RUSTC_VERSION")
+                .filter(|v| !v.is_empty())
+                .map(str::to_string),
+        }
+    }
+
+    /// Returns a one-line description for report headers.
+    #[must_use]
+    pub fn describe(&self) -> String {
+        let mut parts = vec![format!("{}/{}", self.os, self.arch)];
+        if let Some(cpu) = &self.cpu_model {
+            parts.push(cpu.clone());
+        }
+        parts.push(format!("{} logical cores", self.logical_cores));
+        if let Some(bytes) = self.total_memory_bytes {
+            parts.push(format!("{:.1} GiB RAM", bytes as f64 / (1u64 << 30) as f64));
+        }
+        if let Some(rustc) = &self.rustc_version {
+            parts.push(rustc.clone());
+        }
+        parts.join(", ")
+    }
+}
+
+/// Reads the CPU model name from `/proc/cpuinfo`.
+fn cpu_model() -> Option<String> {
+    let cpuinfo = std::fs::read_to_string("/proc/cpuinfo").ok()?;
+    cpuinfo
+        .lines()
+        .find(|line| line.starts_with("model name"))
+        .and_then(|line| line.split_once(':'))
+        .map(|(_, model)| model.trim().to_string())
+}
+
+/// Reads total memory from `/proc/meminfo`, which reports it in KiB.
+fn total_memory_bytes() -> Option<u64> {
+    let meminfo = std::fs::read_to_string("/proc/meminfo").ok()?;
+    let line = meminfo.lines().find(|line| line.starts_with("MemTotal:"))?;
+    let kib: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+    Some(kib * 1024)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_environment() {
+        let env = EnvironmentInfo::detect();
+        assert_eq!(env.os, std::env::consts::OS);
+        assert!(env.logical_cores >= 1);
+        assert!(env.describe().starts_with(&format!("{}/{}", env.os, env.arch)));
+    }
+
+    #[test]
+    fn test_commit_env_override() {
+        std::env::set_var(COMMIT_ENV, " abc123 \n");
+        assert_eq!(detect_commit().as_deref(), Some("abc123"));
+        std::env::remove_var(COMMIT_ENV);
+    }
+}
@@ -0,0 +1,89 @@
+//! HTML export of benchmark results.
+//!
+//! Renders a standalone page with one table row per result, for attaching a
+//! run to a report or sharing it with people who will not open the JSON.
+//! The columns match the [CSV export](crate::csv).
+
+use crate::csv::{metric_columns, row_cells, LEADING_COLUMNS};
+use crate::BenchmarkResult;
+use std::fmt::Write;
+
+/// Title of the generated page.
+pub const PAGE_TITLE: &str = "Benchmark Results";
+
+/// Renders `results` as a self-contained HTML document.
+///
+/// Failed results are highlighted. The page has no scripts or external
+/// resources, so it renders the same from disk or as a CI artifact.
+#[must_use]
+pub fn to_html(results: &[BenchmarkResult]) -> String {
+    let columns = metric_columns(results);
+
+    let mut html = String::new();
+    html.push_str("<!DOCTYPE html>\n");
+    html.push_str("<html lang=\"en\">\n<head>\n<meta charset=\"utf-8\">\n");
+    let _ = writeln!(html, "<title>{PAGE_TITLE}</title>");
+    html.push_str(
+        "<style>\n\
+         body { font-family: sans-serif; }\n\
+         table { border-collapse: collapse; }\n\
+         th, td { border: 1px solid #ccc; padding: 4px 8px; text-align: right; }\n\
+         th { background: #f4f4f4; }\n\
+         tr.failed td { background: #fde8e8; }\n\
+         </style>\n",
+    );
+    html.push_str("</head>\n<body>\n");
+    let _ = writeln!(html, "<h1>{PAGE_TITLE}</h1>");
+    let _ = writeln!(html, "<p>{} result(s)</p>", results.len());
+
+    html.push_str("<table>\n<thead>\n<tr>");
+    for column in LEADING_COLUMNS.iter().chain(&columns) {
+        let _ = write!(html, "<th>{}</th>", escape(column));
+    }
+    html.push_str("</tr>\n</thead>\n<tbody>\n");
+
+    for result in results {
+        html.push_str(if result.is_failed() { "<tr class=\"failed\">" } else { "<tr>" });
+        for cell in row_cells(result, &columns) {
+            let _ = write!(html, "<td>{}</td>", escape(&cell));
+        }
+        html.push_str("</tr>\n");
+    }
+
+    html.push_str("</tbody>\n</table>\n</body>\n</html>\n");
+    html
+}
+
+/// Escapes text for use in HTML attributes and character data.
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&#39;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_to_html() {
+        let results = vec![
+            BenchmarkResult::new("hashing-<x>", json!({"ops_per_second": 12.5})),
+            BenchmarkResult::failed("storage-read-1mb", "disk & full"),
+        ];
+
+        let html = to_html(&results);
+        assert!(html.starts_with("<!DOCTYPE html>\n"));
+        assert!(html.ends_with("</html>\n"));
+        assert!(html.contains("<th>target_id</th><th>timestamp</th>"));
+        assert!(html.contains("<th>ops_per_second</th><th>success_rate</th></tr>"));
+        assert!(html.contains("<tr><td>hashing-&lt;x&gt;</td>"));
+        assert!(html.contains("<td>12.5</td>"));
+        assert!(html.contains("<tr class=\"failed\"><td>storage-read-1mb</td>"));
+        assert!(html.contains("<td>disk &amp; full</td>"));
+    }
+}
@@ -3,10 +3,16 @@
 //! This module provides utilities for reading and writing benchmark results
 //! to the canonical output directories.
 
-use crate::BenchmarkResult;
+use crate::{BenchmarkResult, DailyRollup, RunStatus};
+use chrono::{DateTime, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::fs;
-use std::io::{self, Write};
+use std::io::{self, Read, Write};
 use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, SystemTime};
 
 /// Default output directory for benchmark results.
 pub const DEFAULT_OUTPUT_DIR: &str = "benchmarks/output";
@@ -17,10 +23,59 @@ pub const RAW_OUTPUT_DIR: &str = "benchmarks/output/raw";
 /// Summary file name.
 pub const SUMMARY_FILE: &str = "summary.md";
 
+/// Machine-readable summary file name, written next to [`SUMMARY_FILE`].
+pub const SUMMARY_JSON_FILE: &str = "summary.json";
+
+/// Run status file name.
+pub const STATUS_FILE: &str = "status.json";
+
+/// Daily rollup file name.
+pub const DAILY_ROLLUP_FILE: &str = "history_daily.ndjson";
+
+/// Append-only results history file name.
+pub const NDJSON_FILE: &str = "results.ndjson";
+
+/// Index of raw result files, kept in the output directory.
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+/// `strftime` format of the timestamp embedded in raw result filenames.
+///
+/// Result timestamps are always UTC; the trailing `Z` makes that explicit so
+/// artifacts copied between machines in different zones stay unambiguous.
+pub const RESULT_TIMESTAMP_FORMAT: &str = "%Y%m%d_%H%M%SZ";
+
+/// Default location of the per-target limits file.
+pub const DEFAULT_THRESHOLDS_FILE: &str = "benchmarks/thresholds.toml";
+
+/// Percentage a metric may worsen against the baseline before a comparison
+/// counts it as a regression.
+pub const DEFAULT_REGRESSION_PERCENT: f64 = 10.0;
+
+/// One raw result file listed in [`MANIFEST_FILE`].
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    /// Target the result belongs to.
+    pub target_id: String,
+    /// When the result was recorded.
+    pub timestamp: DateTime<Utc>,
+    /// Path of the result file, relative to the output directory when the
+    /// raw directory is inside it.
+    pub path: String,
+}
+
+/// Serializes read-modify-write updates of the manifest within the process.
+static MANIFEST_LOCK: Mutex<()> = Mutex::new(());
+
+/// Result files that failed to parse, with the reason for each.
+type Malformed = Vec<(PathBuf, serde_json::Error)>;
+
 /// Benchmark I/O handler.
 pub struct BenchmarkIO {
     output_dir: PathBuf,
     raw_dir: PathBuf,
+    verify_after_write: bool,
+    #[cfg(feature = "gzip")]
+    compress: bool,
 }
 
 impl BenchmarkIO {
@@ -36,9 +91,34 @@ impl BenchmarkIO {
         Self {
             output_dir: output_dir.into(),
             raw_dir: raw_dir.into(),
+            verify_after_write: false,
+            #[cfg(feature = "gzip")]
+            compress: false,
         }
     }
 
+    /// Re-reads every result file right after writing it.
+    ///
+    /// A write fails with `InvalidData` if the file does not parse back into
+    /// the same result, or if any metric was serialized as `null` (which is
+    /// how `serde_json` encodes NaN and infinite floats).
+    #[must_use]
+    pub fn with_verify_after_write(mut self, verify: bool) -> Self {
+        self.verify_after_write = verify;
+        self
+    }
+
+    /// Writes raw results gzip-compressed, as `.json.gz` files.
+    ///
+    /// Reading always accepts both `.json` and `.json.gz` files, whatever
+    /// this is set to.
+    #[cfg(feature = "gzip")]
+    #[must_use]
+    pub fn with_compression(mut self, compress: bool) -> Self {
+        self.compress = compress;
+        self
+    }
+
     /// Ensures output directories exist.
     pub fn ensure_directories(&self) -> io::Result<()> {
         fs::create_dir_all(&self.output_dir)?;
@@ -47,18 +127,28 @@ impl BenchmarkIO {
     }
 
     /// Writes a single benchmark result to the raw output directory.
+    ///
+    /// If a file for the same target and second already exists, a `-1`, `-2`,
+    /// ... sequence suffix is added rather than overwriting it. The file only
+    /// appears under its final name once fully written, after which it is
+    /// added to [`MANIFEST_FILE`].
     pub fn write_result(&self, result: &BenchmarkResult) -> io::Result<PathBuf> {
         self.ensure_directories()?;
 
-        let filename = format!(
-            "{}_{}.json",
-            result.target_id.replace('/', "_").replace(':', "_"),
-            result.timestamp.format("%Y%m%d_%H%M%S")
-        );
-        let path = self.raw_dir.join(&filename);
-
         let json = result.to_json().map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        fs::write(&path, json)?;
+        let (name, contents) = self.encode_result(result_filename(result), json)?;
+        let path = write_unique(&self.raw_dir, &name, &contents)?;
+
+        if self.verify_after_write {
+            verify_written(&path, result)?;
+        }
+
+        let entry = ManifestEntry {
+            target_id: result.target_id.clone(),
+            timestamp: result.timestamp,
+            path: self.manifest_path(&path),
+        };
+        self.update_manifest(|entries| entries.push(entry))?;
 
         Ok(path)
     }
@@ -68,30 +158,77 @@ impl BenchmarkIO {
         results.iter().map(|r| self.write_result(r)).collect()
     }
 
+    /// Returns the filename and file contents for a serialized result,
+    /// compressing both when enabled.
+    fn encode_result(&self, name: String, json: String) -> io::Result<(String, Vec<u8>)> {
+        #[cfg(feature = "gzip")]
+        if self.compress {
+            let mut encoder =
+                flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+            encoder.write_all(json.as_bytes())?;
+            return Ok((format!("{name}.{GZIP_EXTENSION}"), encoder.finish()?));
+        }
+
+        Ok((name, json.into_bytes()))
+    }
+
     /// Reads all benchmark results from the raw output directory.
+    ///
+    /// Files that fail to parse are skipped with a warning naming the file;
+    /// use [`read_results_strict`](Self::read_results_strict) to fail instead.
     pub fn read_results(&self) -> io::Result<Vec<BenchmarkResult>> {
+        let (results, malformed) = self.scan_results()?;
+
+        for (path, e) in &malformed {
+            tracing::warn!(path = %path.display(), error = %e, "skipping malformed benchmark result");
+        }
+
+        Ok(results)
+    }
+
+    /// Reads all benchmark results, failing if any file does not parse.
+    ///
+    /// The `InvalidData` error lists every offending path with its parse error.
+    pub fn read_results_strict(&self) -> io::Result<Vec<BenchmarkResult>> {
+        let (results, malformed) = self.scan_results()?;
+
+        if !malformed.is_empty() {
+            let details: Vec<String> = malformed
+                .iter()
+                .map(|(path, e)| format!("{}: {}", path.display(), e))
+                .collect();
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!("malformed result files: {}", details.join("; ")),
+            ));
+        }
+
+        Ok(results)
+    }
+
+    /// Parses every result file in the raw directory, sorted by timestamp.
+    ///
+    /// Returns the parsed results alongside the files that failed to parse.
+    fn scan_results(&self) -> io::Result<(Vec<BenchmarkResult>, Malformed)> {
         let mut results = Vec::new();
+        let mut malformed = Vec::new();
 
         if !self.raw_dir.exists() {
-            return Ok(results);
+            return Ok((results, malformed));
         }
 
-        for entry in fs::read_dir(&self.raw_dir)? {
-            let entry = entry?;
-            let path = entry.path();
-
-            if path.extension().map_or(false, |ext| ext == "json") {
-                let content = fs::read_to_string(&path)?;
-                if let Ok(result) = BenchmarkResult::from_json(&content) {
-                    results.push(result);
-                }
+        for path in self.result_paths()? {
+            let content = read_result_file(&path)?;
+            match BenchmarkResult::from_json(&content) {
+                Ok(result) => results.push(result),
+                Err(e) => malformed.push((path, e)),
             }
         }
 
         // Sort by timestamp
         results.sort_by(|a, b| a.timestamp.cmp(&b.timestamp));
 
-        Ok(results)
+        Ok((results, malformed))
     }
 
     /// Writes a summary file with all results.
@@ -99,17 +236,172 @@ impl BenchmarkIO {
         self.ensure_directories()?;
 
         let path = self.output_dir.join(SUMMARY_FILE);
-        fs::write(&path, content)?;
+        write_atomic(&path, content.as_bytes())?;
 
         // Also write a JSON summary
-        let json_path = self.output_dir.join("summary.json");
+        let json_path = self.output_dir.join(SUMMARY_JSON_FILE);
         let json = serde_json::to_string_pretty(results)
             .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
-        fs::write(&json_path, json)?;
+        write_atomic(&json_path, json.as_bytes())?;
+
+        Ok(path)
+    }
+
+    /// Writes the compact run verdict to the output directory.
+    pub fn write_status(&self, status: &RunStatus) -> io::Result<PathBuf> {
+        self.ensure_directories()?;
+
+        let path = self.output_dir.join(STATUS_FILE);
+        let json = serde_json::to_string_pretty(status)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        write_atomic(&path, json.as_bytes())?;
 
         Ok(path)
     }
 
+    /// Aggregates all raw results into per-target daily rollups.
+    ///
+    /// Writes one JSON record per line to [`DAILY_ROLLUP_FILE`] in the output
+    /// directory, replacing any previous rollup. Raw results are kept.
+    pub fn rollup_daily(&self) -> io::Result<Vec<DailyRollup>> {
+        let rollups = DailyRollup::aggregate(&self.read_results()?);
+
+        self.ensure_directories()?;
+        let mut buf = Vec::new();
+        for rollup in &rollups {
+            serde_json::to_writer(&mut buf, rollup)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            buf.push(b'\n');
+        }
+        write_atomic(&self.output_dir.join(DAILY_ROLLUP_FILE), &buf)?;
+
+        Ok(rollups)
+    }
+
+    /// Reads daily rollups written by [`BenchmarkIO::rollup_daily`].
+    ///
+    /// Returns an empty list if no rollup file exists.
+    pub fn read_daily_rollups(&self) -> io::Result<Vec<DailyRollup>> {
+        let path = self.output_dir.join(DAILY_ROLLUP_FILE);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        fs::read_to_string(path)?
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                serde_json::from_str(line).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            })
+            .collect()
+    }
+
+    /// Appends results to [`NDJSON_FILE`] in the output directory, one
+    /// compact JSON object per line.
+    ///
+    /// All lines are written with a single append-mode write, so concurrent
+    /// runs appending to the same file do not interleave partial lines.
+    pub fn append_ndjson(&self, results: &[BenchmarkResult]) -> io::Result<PathBuf> {
+        self.ensure_directories()?;
+
+        let mut buf = Vec::new();
+        for result in results {
+            serde_json::to_writer(&mut buf, result)
+                .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+            buf.push(b'\n');
+        }
+
+        let path = self.output_dir.join(NDJSON_FILE);
+        let mut file = fs::OpenOptions::new().append(true).create(true).open(&path)?;
+        file.write_all(&buf)?;
+
+        Ok(path)
+    }
+
+    /// Reads results appended by [`BenchmarkIO::append_ndjson`], in file order.
+    ///
+    /// Returns an empty list if no history file exists.
+    pub fn read_ndjson(&self) -> io::Result<Vec<BenchmarkResult>> {
+        let path = self.output_dir.join(NDJSON_FILE);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        fs::read_to_string(path)?
+            .lines()
+            .filter(|line| !line.trim().is_empty())
+            .map(|line| {
+                BenchmarkResult::from_json(line).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+            })
+            .collect()
+    }
+
+    /// Reads the entries of [`MANIFEST_FILE`], oldest write first.
+    ///
+    /// Returns an empty list if no manifest exists.
+    pub fn read_manifest(&self) -> io::Result<Vec<ManifestEntry>> {
+        let path = self.output_dir.join(MANIFEST_FILE);
+        if !path.exists() {
+            return Ok(Vec::new());
+        }
+
+        serde_json::from_str(&fs::read_to_string(path)?)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Returns the path recorded in the manifest for a result file.
+    fn manifest_path(&self, path: &Path) -> String {
+        path.strip_prefix(&self.output_dir).unwrap_or(path).to_string_lossy().into_owned()
+    }
+
+    /// Applies `update` to the manifest entries and atomically rewrites the
+    /// file.
+    ///
+    /// Updates are serialized within the process; separate processes writing
+    /// to the same output directory can still lose each other's entries.
+    fn update_manifest(&self, update: impl FnOnce(&mut Vec<ManifestEntry>)) -> io::Result<()> {
+        let _guard = MANIFEST_LOCK.lock().unwrap_or_else(|e| e.into_inner());
+
+        let mut entries = self.read_manifest()?;
+        update(&mut entries);
+        let json = serde_json::to_string_pretty(&entries)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        write_atomic(&self.output_dir.join(MANIFEST_FILE), json.as_bytes())
+    }
+
+    /// Drops manifest entries whose files no longer exist.
+    fn prune_manifest(&self) -> io::Result<()> {
+        if !self.output_dir.join(MANIFEST_FILE).exists() {
+            return Ok(());
+        }
+        self.update_manifest(|entries| {
+            entries.retain(|entry| self.output_dir.join(&entry.path).exists());
+        })
+    }
+
+    /// Scaffolds the output directories and a thresholds template.
+    ///
+    /// Refuses to replace an existing thresholds file unless `force` is set,
+    /// returning an [`io::ErrorKind::AlreadyExists`] error instead.
+    pub fn init_layout(&self, thresholds_path: &Path, force: bool) -> io::Result<PathBuf> {
+        if thresholds_path.exists() && !force {
+            return Err(io::Error::new(
+                io::ErrorKind::AlreadyExists,
+                format!("{} already exists", thresholds_path.display()),
+            ));
+        }
+
+        self.ensure_directories()?;
+        if let Some(parent) = thresholds_path.parent() {
+            fs::create_dir_all(parent)?;
+        }
+
+        let template = thresholds_template(&crate::list_benchmark_ids());
+        fs::write(thresholds_path, template)?;
+
+        Ok(thresholds_path.to_path_buf())
+    }
+
     /// Returns the output directory path.
     #[must_use]
     pub fn output_dir(&self) -> &Path {
@@ -122,26 +414,429 @@ impl BenchmarkIO {
         &self.raw_dir
     }
 
-    /// Clears all benchmark results.
-    pub fn clear_results(&self) -> io::Result<()> {
+    /// Returns the number of result files in the raw directory.
+    pub fn result_file_count(&self) -> io::Result<usize> {
+        Ok(self.result_paths()?.len())
+    }
+
+    /// Clears all benchmark results, returning how many files were removed.
+    pub fn clear_results(&self) -> io::Result<usize> {
+        let paths = self.result_paths()?;
+        for path in &paths {
+            fs::remove_file(path)?;
+        }
+        self.prune_manifest()?;
+        Ok(paths.len())
+    }
+
+    /// Deletes all but the `keep_latest` most recent result files of each
+    /// target, returning how many were removed.
+    ///
+    /// Recency comes from the timestamp in the filename, with the file's
+    /// modification time breaking ties. Files whose names do not parse with
+    /// [`parse_result_filename`] are never touched.
+    pub fn prune(&self, keep_latest: usize) -> io::Result<usize> {
+        let mut by_target: BTreeMap<String, Vec<ResultFile>> = BTreeMap::new();
+        for file in self.result_files()? {
+            by_target.entry(file.target_id.clone()).or_default().push(file);
+        }
+
+        let mut removed = 0;
+        for files in by_target.values_mut() {
+            files.sort_by(|a, b| {
+                b.timestamp.cmp(&a.timestamp).then_with(|| b.modified.cmp(&a.modified))
+            });
+            for file in files.iter().skip(keep_latest) {
+                fs::remove_file(&file.path)?;
+                removed += 1;
+            }
+        }
+        self.prune_manifest()?;
+        Ok(removed)
+    }
+
+    /// Deletes result files whose filename timestamp is more than `max_age`
+    /// in the past, returning how many were removed.
+    ///
+    /// Like [`prune`](Self::prune), this ignores files with unparseable names.
+    pub fn prune_older_than(&self, max_age: Duration) -> io::Result<usize> {
+        let max_age = chrono::Duration::from_std(max_age)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidInput, e))?;
+        let cutoff = Utc::now() - max_age;
+
+        let mut removed = 0;
+        for file in self.result_files()? {
+            if file.timestamp < cutoff {
+                fs::remove_file(&file.path)?;
+                removed += 1;
+            }
+        }
+        self.prune_manifest()?;
+        Ok(removed)
+    }
+
+    /// Lists the result files whose names parse, with their target and time.
+    fn result_files(&self) -> io::Result<Vec<ResultFile>> {
+        let mut files = Vec::new();
+        for path in self.result_paths()? {
+            let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+                continue;
+            };
+            let Some((target_id, timestamp)) = parse_result_filename(name) else {
+                continue;
+            };
+            let modified = fs::metadata(&path).and_then(|m| m.modified()).ok();
+            files.push(ResultFile { path, target_id, timestamp, modified });
+        }
+        Ok(files)
+    }
+
+    /// Lists the result files in the raw directory.
+    fn result_paths(&self) -> io::Result<Vec<PathBuf>> {
+        let mut paths = Vec::new();
         if self.raw_dir.exists() {
             for entry in fs::read_dir(&self.raw_dir)? {
-                let entry = entry?;
-                if entry.path().extension().map_or(false, |ext| ext == "json") {
-                    fs::remove_file(entry.path())?;
+                let path = entry?.path();
+                if is_result_file(&path) {
+                    paths.push(path);
                 }
             }
         }
-        Ok(())
+        Ok(paths)
     }
 }
 
+/// A raw result file identified by its parsed filename.
+struct ResultFile {
+    path: PathBuf,
+    target_id: String,
+    timestamp: DateTime<Utc>,
+    modified: Option<SystemTime>,
+}
+
 impl Default for BenchmarkIO {
     fn default() -> Self {
         Self::new()
     }
 }
 
+/// Returns the raw result filename, e.g. `encryption-1kb_20240101_120000Z.json`.
+///
+/// The target id is passed through [`sanitize_target_id`].
+#[must_use]
+pub fn result_filename(result: &BenchmarkResult) -> String {
+    format!(
+        "{}_{}.json",
+        sanitize_target_id(&result.target_id),
+        result.timestamp.format(RESULT_TIMESTAMP_FORMAT)
+    )
+}
+
+/// Device names Windows reserves regardless of extension.
+const RESERVED_DEVICE_NAMES: &[&str] = &[
+    "CON", "PRN", "AUX", "NUL", "COM1", "COM2", "COM3", "COM4", "COM5", "COM6", "COM7", "COM8",
+    "COM9", "LPT1", "LPT2", "LPT3", "LPT4", "LPT5", "LPT6", "LPT7", "LPT8", "LPT9",
+];
+
+/// Makes a target id safe to use as a filename on every platform.
+///
+/// Path separators, the characters Windows reserves (`<>:"|?*`), and control
+/// characters become `_`, as do trailing dots and spaces. Reserved device
+/// names such as `CON` or `lpt1.x` get a leading `_`, and an empty id
+/// becomes `_`. The result id is stored inside the file, so this mapping
+/// does not need to be reversible.
+#[must_use]
+pub fn sanitize_target_id(target_id: &str) -> String {
+    let mut name: String = target_id
+        .chars()
+        .map(|c| match c {
+            '/' | '\\' | '<' | '>' | ':' | '"' | '|' | '?' | '*' => '_',
+            c if c.is_control() => '_',
+            c => c,
+        })
+        .collect();
+
+    let kept = name.trim_end_matches(['.', ' ']).len();
+    let trailing = name.len() - kept;
+    name.truncate(kept);
+    name.push_str(&"_".repeat(trailing));
+
+    let device = name.split('.').next().unwrap_or_default();
+    if RESERVED_DEVICE_NAMES.iter().any(|r| r.eq_ignore_ascii_case(device)) {
+        name.insert(0, '_');
+    }
+
+    if name.is_empty() {
+        name.push('_');
+    }
+    name
+}
+
+/// Extension appended to compressed result files.
+const GZIP_EXTENSION: &str = "gz";
+
+/// Returns true for `.json` result files, and `.json.gz` ones when the
+/// `gzip` feature is enabled. The manifest, summary, and status files are
+/// never result files, even if the raw and output directories are the same.
+fn is_result_file(path: &Path) -> bool {
+    let Some(name) = path.file_name().and_then(|n| n.to_str()) else {
+        return false;
+    };
+    if [MANIFEST_FILE, SUMMARY_JSON_FILE, STATUS_FILE].contains(&name) {
+        return false;
+    }
+    name.ends_with(".json")
+        || (cfg!(feature = "gzip") && name.ends_with(&format!(".json.{GZIP_EXTENSION}")))
+}
+
+/// Reads a result file, decompressing `.json.gz` files.
+fn read_result_file(path: &Path) -> io::Result<String> {
+    let mut json = String::new();
+
+    #[cfg(feature = "gzip")]
+    if path.extension().is_some_and(|ext| ext == GZIP_EXTENSION) {
+        flate2::read::GzDecoder::new(fs::File::open(path)?).read_to_string(&mut json)?;
+        return Ok(json);
+    }
+
+    fs::File::open(path)?.read_to_string(&mut json)?;
+    Ok(json)
+}
+
+/// Extension of the staging files written before a commit.
+///
+/// Scans only pick up `.json` files, so a staging file left behind by a
+/// crash is never mistaken for a result.
+const STAGING_EXTENSION: &str = "tmp";
+
+/// Writes `contents` to a fresh staging file in the same directory as `path`.
+fn write_staging(path: &Path, contents: &[u8]) -> io::Result<PathBuf> {
+    static SEQ: AtomicU64 = AtomicU64::new(0);
+
+    let name = path.file_name().and_then(|n| n.to_str()).unwrap_or("output");
+    let staging = path.with_file_name(format!(
+        ".{name}.{}-{}.{STAGING_EXTENSION}",
+        std::process::id(),
+        SEQ.fetch_add(1, Ordering::Relaxed)
+    ));
+
+    let mut file = fs::OpenOptions::new().write(true).create_new(true).open(&staging)?;
+    file.write_all(contents)?;
+    file.sync_all()?;
+    Ok(staging)
+}
+
+/// Replaces `path` with `contents` so readers see the old or new file, never
+/// a partial one.
+fn write_atomic(path: &Path, contents: &[u8]) -> io::Result<()> {
+    write_atomic_with(path, contents, |_| Ok(()))
+}
+
+/// [`write_atomic`], running `before_commit` between staging and rename.
+fn write_atomic_with(
+    path: &Path,
+    contents: &[u8],
+    before_commit: impl FnOnce(&Path) -> io::Result<()>,
+) -> io::Result<()> {
+    let staging = write_staging(path, contents)?;
+    let committed = before_commit(&staging).and_then(|()| fs::rename(&staging, path));
+    if committed.is_err() {
+        let _ = fs::remove_file(&staging);
+    }
+    committed
+}
+
+/// Writes `contents` to `name` in `dir`, adding a `-N` sequence suffix if
+/// the name is taken, and returns the final path.
+fn write_unique(dir: &Path, name: &str, contents: &[u8]) -> io::Result<PathBuf> {
+    write_unique_with(dir, name, contents, |_| Ok(()))
+}
+
+/// [`write_unique`], running `before_commit` between staging and commit.
+///
+/// The staged file is hard-linked to its final name, which fails instead of
+/// replacing an existing file, so two writers racing for the same name
+/// cannot both claim it.
+fn write_unique_with(
+    dir: &Path,
+    name: &str,
+    contents: &[u8],
+    before_commit: impl FnOnce(&Path) -> io::Result<()>,
+) -> io::Result<PathBuf> {
+    let staging = write_staging(&dir.join(name), contents)?;
+    let committed = before_commit(&staging).and_then(|()| link_unique(&staging, dir, name));
+    let _ = fs::remove_file(&staging);
+    committed
+}
+
+/// Links `staging` to `name` in `dir`, trying `-1`, `-2`, ... if it is taken.
+///
+/// The sequence number goes before the `.json` (or `.json.gz`) extension.
+fn link_unique(staging: &Path, dir: &Path, name: &str) -> io::Result<PathBuf> {
+    link_unique_with(staging, dir, name, |from, to| fs::hard_link(from, to))
+}
+
+/// [`link_unique`], creating links with `link`.
+///
+/// Filesystems without hard links (FAT, some network mounts) fail with
+/// `Unsupported` or `PermissionDenied`; names are then claimed with
+/// [`claim_by_rename`] instead.
+fn link_unique_with(
+    staging: &Path,
+    dir: &Path,
+    name: &str,
+    link: impl Fn(&Path, &Path) -> io::Result<()>,
+) -> io::Result<PathBuf> {
+    let (stem, extension) = name.rfind(".json").map_or((name, ""), |i| name.split_at(i));
+    let mut seq = 0u32;
+    let mut links_supported = true;
+
+    loop {
+        let path = if seq == 0 {
+            dir.join(name)
+        } else {
+            dir.join(format!("{stem}-{seq}{extension}"))
+        };
+        let claimed = if links_supported {
+            match link(staging, &path) {
+                Err(e)
+                    if matches!(
+                        e.kind(),
+                        io::ErrorKind::Unsupported | io::ErrorKind::PermissionDenied
+                    ) =>
+                {
+                    // Retry the same name without links
+                    links_supported = false;
+                    continue;
+                }
+                claimed => claimed,
+            }
+        } else {
+            claim_by_rename(staging, &path)
+        };
+        match claimed {
+            Ok(()) => return Ok(path),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => seq += 1,
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Claims `path` for `staging` without a hard link: reserves the name with
+/// an exclusive create, then moves the staged file over the placeholder.
+///
+/// Until the rename lands, scans see an empty file under the name and skip
+/// it as malformed.
+fn claim_by_rename(staging: &Path, path: &Path) -> io::Result<()> {
+    fs::OpenOptions::new().write(true).create_new(true).open(path)?;
+    fs::rename(staging, path).map_err(|e| {
+        let _ = fs::remove_file(path);
+        e
+    })
+}
+
+/// Splits a raw result filename into its target id and UTC timestamp.
+///
+/// Accepts the current `...Z.json` form, optionally with a `-N` collision
+/// suffix or a `.gz` extension, and the legacy form without the `Z`, which
+/// was also written in UTC.
+#[must_use]
+pub fn parse_result_filename(filename: &str) -> Option<(String, DateTime<Utc>)> {
+    let filename = filename
+        .strip_suffix(&format!(".{GZIP_EXTENSION}"))
+        .unwrap_or(filename);
+    let stem = filename.strip_suffix(".json")?;
+    let stem = match stem.rsplit_once("Z-") {
+        Some((head, seq)) if !seq.is_empty() && seq.bytes().all(|b| b.is_ascii_digit()) => head,
+        _ => stem.strip_suffix('Z').unwrap_or(stem),
+    };
+
+    // The timestamp is the last two `_`-separated fields: date and time
+    let (rest, time) = stem.rsplit_once('_')?;
+    let (target_id, date) = rest.rsplit_once('_')?;
+
+    let naive = NaiveDateTime::parse_from_str(&format!("{date}_{time}"), "%Y%m%d_%H%M%S").ok()?;
+    Some((target_id.to_string(), naive.and_utc()))
+}
+
+/// Renders a thresholds file with a commented-out limits table per target.
+///
+/// The file uses the schema [`Thresholds`](crate::Thresholds) reads, so
+/// uncommenting a table enables its limits as written.
+#[must_use]
+pub fn thresholds_template(target_ids: &[String]) -> String {
+    let mut toml = String::new();
+
+    toml.push_str("# Benchmark thresholds.\n");
+    toml.push_str("#\n");
+    toml.push_str("# Expected layout:\n");
+    toml.push_str(&format!(
+        "#   {}/            {}, {}, {}\n",
+        DEFAULT_OUTPUT_DIR, SUMMARY_FILE, SUMMARY_JSON_FILE, STATUS_FILE
+    ));
+    toml.push_str(&format!("#   {}/        one JSON file per target run\n", RAW_OUTPUT_DIR));
+    toml.push_str("#\n");
+    toml.push_str("# Absolute limits, checked on every run; a result outside them is\n");
+    toml.push_str("# reported as a violation. Supported keys: min_ops_per_second,\n");
+    toml.push_str("# min_bytes_per_second, max_duration_ms, max_latency_p95_ms,\n");
+    toml.push_str("# max_latency_p99_ms. Uncomment and edit the targets to check.\n");
+
+    for id in target_ids {
+        toml.push_str(&format!("\n# [limits.\"{}\"]\n", id));
+        toml.push_str("# min_ops_per_second = 1000.0\n");
+        toml.push_str("# max_latency_p99_ms = 5.0\n");
+    }
+
+    toml
+}
+
+/// Checks that the file at `path` parses back into `expected`.
+fn verify_written(path: &Path, expected: &BenchmarkResult) -> io::Result<()> {
+    let invalid = |msg: String| {
+        io::Error::new(io::ErrorKind::InvalidData, format!("{}: {}", path.display(), msg))
+    };
+
+    let content = read_result_file(path)?;
+    let actual = BenchmarkResult::from_json(&content)
+        .map_err(|e| invalid(format!("does not parse back: {}", e)))?;
+
+    if let Some(key) = expected
+        .metrics
+        .as_object()
+        .and_then(|m| m.iter().find(|(_, v)| v.is_null()).map(|(k, _)| k))
+    {
+        return Err(invalid(format!("metric '{}' is null (non-finite value?)", key)));
+    }
+
+    let expected = serde_json::to_value(expected).map_err(|e| invalid(e.to_string()))?;
+    let actual = serde_json::to_value(&actual).map_err(|e| invalid(e.to_string()))?;
+    if !values_match(&expected, &actual) {
+        return Err(invalid("does not round-trip".to_string()));
+    }
+
+    Ok(())
+}
+
+/// Compares JSON values, allowing last-digit float parsing differences.
+fn values_match(a: &serde_json::Value, b: &serde_json::Value) -> bool {
+    use serde_json::Value;
+
+    match (a, b) {
+        (Value::Number(x), Value::Number(y)) => match (x.as_f64(), y.as_f64()) {
+            (Some(x), Some(y)) => x == y || (x - y).abs() <= f64::EPSILON * x.abs().max(y.abs()),
+            _ => x == y,
+        },
+        (Value::Array(x), Value::Array(y)) => {
+            x.len() == y.len() && x.iter().zip(y).all(|(x, y)| values_match(x, y))
+        }
+        (Value::Object(x), Value::Object(y)) => {
+            x.len() == y.len()
+                && x.iter().all(|(k, v)| y.get(k).is_some_and(|w| values_match(v, w)))
+        }
+        _ => a == b,
+    }
+}
+
 /// Writes benchmark results to stdout in a human-readable format.
 pub fn print_results(results: &[BenchmarkResult]) {
     println!("\n{}", "=".repeat(60));
@@ -151,6 +846,9 @@ pub fn print_results(results: &[BenchmarkResult]) {
     for result in results {
         println!("Target: {}", result.target_id);
         println!("Timestamp: {}", result.timestamp.format("%Y-%m-%d %H:%M:%S UTC"));
+        if let Some(error) = &result.error {
+            println!("FAILED: {}", error);
+        }
         println!("Metrics:");
 
         if let Some(obj) = result.metrics.as_object() {
@@ -189,6 +887,7 @@ fn format_value(value: &serde_json::Value) -> String {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::{StandardMetrics, INFINITY_SENTINEL, NAN_SENTINEL, NEG_INFINITY_SENTINEL};
     use tempfile::TempDir;
 
     #[test]
@@ -212,6 +911,375 @@ mod tests {
         assert_eq!(results[0].target_id, "test-target");
     }
 
+    #[test]
+    fn test_non_finite_metrics_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let io = BenchmarkIO::with_paths(temp_dir.path(), temp_dir.path().join("raw"))
+            .with_verify_after_write(true);
+
+        let metrics = StandardMetrics::new()
+            .with_ops_per_second(f64::INFINITY)
+            .with_custom("throughput_bps", f64::NAN)
+            .with_custom("speedup", f64::NEG_INFINITY)
+            .with_custom("ratio", 2.5);
+        io.write_result(&BenchmarkResult::new("sentinels", metrics.to_json_value())).unwrap();
+
+        let results = io.read_results().unwrap();
+        assert_eq!(results.len(), 1);
+        let metrics = &results[0].metrics;
+        assert_eq!(metrics["ops_per_second"], INFINITY_SENTINEL);
+        assert_eq!(metrics["throughput_bps"], NAN_SENTINEL);
+        assert_eq!(metrics["speedup"], NEG_INFINITY_SENTINEL);
+        assert_eq!(metrics["ratio"], 2.5);
+    }
+
+    #[test]
+    fn test_read_results_strict_reports_malformed_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let io = BenchmarkIO::with_paths(temp_dir.path(), temp_dir.path().join("raw"));
+
+        io.write_result(&BenchmarkResult::new("a", serde_json::json!({}))).unwrap();
+        fs::write(temp_dir.path().join("raw/broken.json"), "{not json").unwrap();
+
+        assert_eq!(io.read_results().unwrap().len(), 1);
+
+        let err = io.read_results_strict().unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("broken.json"));
+    }
+
+    #[test]
+    fn test_verify_after_write() {
+        let temp_dir = TempDir::new().unwrap();
+        let io = BenchmarkIO::with_paths(temp_dir.path(), temp_dir.path().join("raw"))
+            .with_verify_after_write(true);
+
+        let ok = BenchmarkResult::new("test-target", serde_json::json!({"ops_per_second": 0.1 + 0.2}));
+        io.write_result(&ok).unwrap();
+
+        // Built without StandardMetrics, so the infinity serializes as null
+        let bad = BenchmarkResult::new("test-target", serde_json::json!({"ops_per_second": f64::INFINITY}));
+        let err = io.write_result(&bad).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert!(err.to_string().contains("ops_per_second"));
+    }
+
+    #[test]
+    fn test_result_filename_roundtrip() {
+        let timestamp = chrono::TimeZone::with_ymd_and_hms(&Utc, 2024, 3, 9, 14, 5, 7).unwrap();
+        let result = BenchmarkResult::with_timestamp("storage-read-1mb", serde_json::json!({}), timestamp);
+
+        let filename = result_filename(&result);
+        assert_eq!(filename, "storage-read-1mb_20240309_140507Z.json");
+        assert_eq!(
+            parse_result_filename(&filename),
+            Some(("storage-read-1mb".to_string(), timestamp))
+        );
+
+        // Legacy names without the suffix are read as UTC too
+        assert_eq!(
+            parse_result_filename("storage-read-1mb_20240309_140507.json"),
+            Some(("storage-read-1mb".to_string(), timestamp))
+        );
+        assert_eq!(
+            parse_result_filename("storage-read-1mb_20240309_140507Z-2.json"),
+            Some(("storage-read-1mb".to_string(), timestamp))
+        );
+        assert_eq!(parse_result_filename("summary.json"), None);
+    }
+
+    #[test]
+    fn test_sanitize_target_id() {
+        assert_eq!(sanitize_target_id("encryption-1kb"), "encryption-1kb");
+        assert_eq!(sanitize_target_id("a/b\\c:d"), "a_b_c_d");
+        assert_eq!(sanitize_target_id("<x>|\"y\"?*"), "_x___y___");
+        assert_eq!(sanitize_target_id("tab\there"), "tab_here");
+        assert_eq!(sanitize_target_id("trailing. ."), "trailing___");
+        assert_eq!(sanitize_target_id("CON"), "_CON");
+        assert_eq!(sanitize_target_id("lpt1.json"), "_lpt1.json");
+        assert_eq!(sanitize_target_id("console"), "console");
+        assert_eq!(sanitize_target_id(""), "_");
+    }
+
+    #[test]
+    fn test_adversarial_target_ids_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let io = BenchmarkIO::with_paths(temp_dir.path(), temp_dir.path().join("raw"));
+        let ids = ["a\\b", "x*y?", "<con>", "CON", "nul.txt", "dots...", "pipe|", "../escape", ""];
+
+        for id in ids {
+            let path = io.write_result(&BenchmarkResult::new(id, serde_json::json!({}))).unwrap();
+            assert_eq!(path.parent().unwrap(), io.raw_dir());
+        }
+
+        let mut read: Vec<String> =
+            io.read_results().unwrap().into_iter().map(|r| r.target_id).collect();
+        read.sort();
+        let mut expected: Vec<String> = ids.iter().map(|id| id.to_string()).collect();
+        expected.sort();
+        assert_eq!(read, expected);
+    }
+
+    #[cfg(feature = "gzip")]
+    #[test]
+    fn test_compressed_results_round_trip() {
+        let temp_dir = TempDir::new().unwrap();
+        let plain = BenchmarkIO::with_paths(temp_dir.path(), temp_dir.path().join("raw"));
+        let compressed = BenchmarkIO::with_paths(temp_dir.path(), temp_dir.path().join("raw"))
+            .with_compression(true)
+            .with_verify_after_write(true);
+
+        let timestamp = chrono::TimeZone::with_ymd_and_hms(&Utc, 2024, 3, 9, 14, 5, 7).unwrap();
+        let first = BenchmarkResult::with_timestamp("a", serde_json::json!({"duration_ms": 1.0}), timestamp);
+        let second = BenchmarkResult::with_timestamp("a", serde_json::json!({"duration_ms": 2.0}), timestamp);
+        plain.write_result(&first).unwrap();
+        let first_gz = compressed.write_result(&first).unwrap();
+        let second_gz = compressed.write_result(&second).unwrap();
+
+        let name = |path: &Path| path.file_name().unwrap().to_str().unwrap().to_string();
+        assert_eq!(name(&first_gz), "a_20240309_140507Z.json.gz");
+        assert_eq!(name(&second_gz), "a_20240309_140507Z-1.json.gz");
+        assert!(parse_result_filename(&name(&second_gz)).is_some());
+
+        let read = plain.read_results_strict().unwrap();
+        assert_eq!(read.len(), 3);
+        assert_eq!(read.iter().filter(|r| r.metrics["duration_ms"] == 2.0).count(), 1);
+        assert_eq!(plain.clear_results().unwrap(), 3);
+    }
+
+    #[test]
+    fn test_write_result_same_second_keeps_both() {
+        let temp_dir = TempDir::new().unwrap();
+        let io = BenchmarkIO::with_paths(temp_dir.path(), temp_dir.path().join("raw"));
+
+        let timestamp = chrono::TimeZone::with_ymd_and_hms(&Utc, 2024, 3, 9, 14, 5, 7).unwrap();
+        let first = BenchmarkResult::with_timestamp("a", serde_json::json!({"duration_ms": 1.0}), timestamp);
+        let second = BenchmarkResult::with_timestamp("a", serde_json::json!({"duration_ms": 2.0}), timestamp);
+
+        let first_path = io.write_result(&first).unwrap();
+        let second_path = io.write_result(&second).unwrap();
+        assert_ne!(first_path, second_path);
+        assert!(first_path.exists() && second_path.exists());
+        assert_eq!(io.read_results().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_interrupted_write_leaves_no_partial_file() {
+        let temp_dir = TempDir::new().unwrap();
+        let interrupted = || io::Error::new(io::ErrorKind::Interrupted, "simulated crash");
+
+        let summary = temp_dir.path().join(SUMMARY_FILE);
+        let err = write_atomic_with(&summary, b"# Summary", |staging| {
+            assert!(staging.exists());
+            assert!(!summary.exists());
+            Err(interrupted())
+        })
+        .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Interrupted);
+        assert!(!summary.exists());
+
+        let name = "a_20240309_140507Z.json";
+        let err = write_unique_with(temp_dir.path(), name, b"{}", |_| Err(interrupted()))
+            .unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::Interrupted);
+        assert!(!temp_dir.path().join(name).exists());
+
+        // Staging files are cleaned up on failure, too
+        assert_eq!(fs::read_dir(temp_dir.path()).unwrap().count(), 0);
+
+        write_atomic(&summary, b"# Summary").unwrap();
+        assert_eq!(fs::read_to_string(&summary).unwrap(), "# Summary");
+    }
+
+    #[test]
+    fn test_link_unique_without_hard_links() {
+        let temp_dir = TempDir::new().unwrap();
+        let name = "a_20240309_140507Z.json";
+        fs::write(temp_dir.path().join(name), "taken").unwrap();
+
+        for kind in [io::ErrorKind::Unsupported, io::ErrorKind::PermissionDenied] {
+            let staging = write_staging(&temp_dir.path().join(name), b"{}").unwrap();
+            let path = link_unique_with(&staging, temp_dir.path(), name, |_, _| {
+                Err(io::Error::new(kind, "no hard links"))
+            })
+            .unwrap();
+
+            assert_ne!(path, temp_dir.path().join(name));
+            assert_eq!(fs::read_to_string(&path).unwrap(), "{}");
+            assert!(!staging.exists());
+        }
+        assert_eq!(fs::read_to_string(temp_dir.path().join(name)).unwrap(), "taken");
+        assert!(temp_dir.path().join("a_20240309_140507Z-1.json").exists());
+        assert!(temp_dir.path().join("a_20240309_140507Z-2.json").exists());
+    }
+
+    #[test]
+    fn test_shared_output_dir_skips_run_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let io = BenchmarkIO::with_paths(temp_dir.path(), temp_dir.path());
+
+        let results = vec![BenchmarkResult::new("a", serde_json::json!({"duration_ms": 1.0}))];
+        io.write_results(&results).unwrap();
+        io.write_summary(&results, "# Summary").unwrap();
+        io.write_status(&RunStatus::new(1, &results)).unwrap();
+
+        assert_eq!(io.read_results_strict().unwrap().len(), 1);
+        assert_eq!(io.clear_results().unwrap(), 1);
+        for file in [SUMMARY_FILE, SUMMARY_JSON_FILE, STATUS_FILE, MANIFEST_FILE] {
+            assert!(temp_dir.path().join(file).exists(), "{file} was cleared");
+        }
+    }
+
+    #[test]
+    fn test_clear_results_counts_removed_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let io = BenchmarkIO::with_paths(temp_dir.path(), temp_dir.path().join("raw"));
+        assert_eq!(io.clear_results().unwrap(), 0);
+
+        io.write_result(&BenchmarkResult::new("a", serde_json::json!({}))).unwrap();
+        io.write_result(&BenchmarkResult::new("b", serde_json::json!({}))).unwrap();
+        fs::write(temp_dir.path().join("raw/notes.txt"), "kept").unwrap();
+
+        assert_eq!(io.result_file_count().unwrap(), 2);
+        assert_eq!(io.clear_results().unwrap(), 2);
+        assert!(io.read_results().unwrap().is_empty());
+        assert!(temp_dir.path().join("raw/notes.txt").exists());
+    }
+
+    #[test]
+    fn test_prune_keeps_latest_per_target() {
+        let temp_dir = TempDir::new().unwrap();
+        let io = BenchmarkIO::with_paths(temp_dir.path(), temp_dir.path().join("raw"));
+
+        let at = |hour| chrono::TimeZone::with_ymd_and_hms(&Utc, 2024, 3, 9, hour, 0, 0).unwrap();
+        for hour in [1, 2, 3] {
+            io.write_result(&BenchmarkResult::with_timestamp("a", serde_json::json!({}), at(hour)))
+                .unwrap();
+        }
+        io.write_result(&BenchmarkResult::with_timestamp("b", serde_json::json!({}), at(1)))
+            .unwrap();
+        fs::write(io.raw_dir().join("notes.json"), "{}").unwrap();
+
+        assert_eq!(io.prune(1).unwrap(), 2);
+        let remaining = io.read_results().unwrap();
+        assert_eq!(remaining.len(), 2);
+        assert!(remaining.iter().any(|r| r.target_id == "a" && r.timestamp == at(3)));
+        assert!(io.raw_dir().join("notes.json").exists());
+        assert_eq!(io.prune(1).unwrap(), 0);
+    }
+
+    #[test]
+    fn test_prune_older_than() {
+        let temp_dir = TempDir::new().unwrap();
+        let io = BenchmarkIO::with_paths(temp_dir.path(), temp_dir.path().join("raw"));
+
+        let old = chrono::TimeZone::with_ymd_and_hms(&Utc, 2024, 3, 9, 0, 0, 0).unwrap();
+        io.write_result(&BenchmarkResult::with_timestamp("a", serde_json::json!({}), old))
+            .unwrap();
+        io.write_result(&BenchmarkResult::new("a", serde_json::json!({}))).unwrap();
+
+        assert_eq!(io.prune_older_than(Duration::from_secs(24 * 60 * 60)).unwrap(), 1);
+        assert_eq!(io.read_results().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn test_rollup_daily() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_dir = temp_dir.path().join("output");
+        let raw_dir = temp_dir.path().join("output/raw");
+        let io = BenchmarkIO::with_paths(&output_dir, &raw_dir);
+
+        io.write_result(&BenchmarkResult::new("a", serde_json::json!({"duration_ms": 2.0})))
+            .unwrap();
+
+        let rollups = io.rollup_daily().unwrap();
+        assert_eq!(rollups.len(), 1);
+        assert!(output_dir.join(DAILY_ROLLUP_FILE).exists());
+
+        let read_back = io.read_daily_rollups().unwrap();
+        assert_eq!(read_back[0].metrics["duration_ms"].mean, 2.0);
+    }
+
+    #[test]
+    fn test_manifest_tracks_written_files() {
+        let temp_dir = TempDir::new().unwrap();
+        let io = BenchmarkIO::with_paths(temp_dir.path(), temp_dir.path().join("raw"));
+        assert!(io.read_manifest().unwrap().is_empty());
+
+        let at = |hour| chrono::TimeZone::with_ymd_and_hms(&Utc, 2024, 3, 9, hour, 0, 0).unwrap();
+        let old = BenchmarkResult::with_timestamp("a", serde_json::json!({}), at(1));
+        io.write_result(&old).unwrap();
+        let new = io
+            .write_result(&BenchmarkResult::with_timestamp("a", serde_json::json!({}), at(2)))
+            .unwrap();
+
+        let entries = io.read_manifest().unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(entries[0].target_id, "a");
+        assert_eq!(entries[0].timestamp, at(1));
+        assert_eq!(Path::new(&entries[1].path), Path::new("raw").join(new.file_name().unwrap()));
+
+        io.prune(1).unwrap();
+        let entries = io.read_manifest().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0].timestamp, at(2));
+
+        io.clear_results().unwrap();
+        assert!(io.read_manifest().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_append_ndjson() {
+        let temp_dir = TempDir::new().unwrap();
+        let io = BenchmarkIO::with_paths(temp_dir.path(), temp_dir.path().join("raw"));
+        assert!(io.read_ndjson().unwrap().is_empty());
+
+        io.append_ndjson(&[BenchmarkResult::new("a", serde_json::json!({"duration_ms": 1.0}))])
+            .unwrap();
+        let path = io
+            .append_ndjson(&[
+                BenchmarkResult::new("b", serde_json::json!({})),
+                BenchmarkResult::new("c", serde_json::json!({})),
+            ])
+            .unwrap();
+
+        assert_eq!(fs::read_to_string(path).unwrap().lines().count(), 3);
+        let ids: Vec<String> = io.read_ndjson().unwrap().into_iter().map(|r| r.target_id).collect();
+        assert_eq!(ids, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_init_layout() {
+        let temp_dir = TempDir::new().unwrap();
+        let output_dir = temp_dir.path().join("output");
+        let raw_dir = temp_dir.path().join("output/raw");
+        let thresholds = temp_dir.path().join("thresholds.toml");
+
+        let io = BenchmarkIO::with_paths(&output_dir, &raw_dir);
+        io.init_layout(&thresholds, false).unwrap();
+
+        assert!(raw_dir.is_dir());
+        assert!(crate::Thresholds::load(&thresholds).unwrap().is_empty());
+
+        // Uncommenting a target's table enables exactly the limits shown
+        let content = fs::read_to_string(&thresholds).unwrap();
+        let table = "# [limits.\"encryption-1kb\"]\n";
+        let start = content.find(table).unwrap();
+        let end = start + content[start..].find("\n\n").unwrap();
+        let enabled = content[start..end].replace("# ", "");
+        fs::write(&thresholds, content.replacen(&content[start..end], &enabled, 1)).unwrap();
+
+        let loaded = crate::Thresholds::load(&thresholds).unwrap();
+        assert_eq!(loaded.limits.len(), 1);
+        let limits = &loaded.limits["encryption-1kb"];
+        assert_eq!(limits.min_ops_per_second, Some(1000.0));
+        assert_eq!(limits.max_latency_p99_ms, Some(5.0));
+
+        let err = io.init_layout(&thresholds, false).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert!(io.init_layout(&thresholds, true).is_ok());
+    }
+
     #[test]
     fn test_format_value() {
         assert_eq!(format_value(&serde_json::json!(1500000.0)), "1.50M");
@@ -0,0 +1,175 @@
+//! JUnit XML export of benchmark results.
+//!
+//! Renders one `<testcase>` per target so CI systems that already chart test
+//! reports (Jenkins, GitLab) can track benchmarks the same way. A target fails
+//! when it breaches a configured threshold and errors when its run failed; key
+//! metrics are attached to each test case as properties.
+
+use crate::thresholds::{Thresholds, Violation};
+use crate::BenchmarkResult;
+use std::fmt::Write;
+
+/// Name of the generated test suite.
+pub const SUITE_NAME: &str = "vault-benchmarks";
+
+/// Metrics embedded as test case properties, in output order.
+const PROPERTY_METRICS: &[&str] = &[
+    "duration_ms",
+    "ops_per_second",
+    "bytes_per_second",
+    "latency_p50_ms",
+    "latency_p95_ms",
+    "latency_p99_ms",
+    "iterations",
+    "data_size_bytes",
+];
+
+/// Renders `results` as a JUnit XML report, checking each against `thresholds`.
+///
+/// Test cases are listed in `target_id` order. A test case's `time` is the
+/// total timed duration of the target: average duration times iterations.
+#[must_use]
+pub fn to_xml(results: &[BenchmarkResult], thresholds: &Thresholds) -> String {
+    let mut sorted: Vec<&BenchmarkResult> = results.iter().collect();
+    sorted.sort_by(|a, b| a.target_id.cmp(&b.target_id));
+
+    let violations = thresholds.evaluate(results);
+    let errors = results.iter().filter(|r| r.is_failed()).count();
+    let failures = sorted
+        .iter()
+        .filter(|r| violations.iter().any(|v| v.target_id == r.target_id))
+        .count();
+    let total_time: f64 = sorted.iter().map(|r| case_time(r)).sum();
+
+    let counts = format!(
+        "name=\"{SUITE_NAME}\" tests=\"{}\" failures=\"{failures}\" errors=\"{errors}\" \
+         time=\"{total_time:.6}\"",
+        sorted.len()
+    );
+
+    let mut xml = String::new();
+    xml.push_str("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n");
+    let _ = writeln!(xml, "<testsuites {counts}>");
+    let _ = writeln!(xml, "  <testsuite {counts}>");
+
+    for result in sorted {
+        let breached: Vec<&Violation> = violations
+            .iter()
+            .filter(|v| v.target_id == result.target_id)
+            .collect();
+        write_case(&mut xml, result, &breached);
+    }
+
+    xml.push_str("  </testsuite>\n");
+    xml.push_str("</testsuites>\n");
+    xml
+}
+
+/// Writes one `<testcase>` element.
+fn write_case(xml: &mut String, result: &BenchmarkResult, violations: &[&Violation]) {
+    let _ = writeln!(
+        xml,
+        "    <testcase name=\"{}\" classname=\"{SUITE_NAME}\" time=\"{:.6}\">",
+        escape(&result.target_id),
+        case_time(result)
+    );
+
+    let properties: Vec<(&str, f64)> = PROPERTY_METRICS
+        .iter()
+        .filter_map(|name| result.metrics[*name].as_f64().map(|value| (*name, value)))
+        .collect();
+    if !properties.is_empty() {
+        xml.push_str("      <properties>\n");
+        for (name, value) in properties {
+            let _ = writeln!(xml, "        <property name=\"{name}\" value=\"{value}\"/>");
+        }
+        xml.push_str("      </properties>\n");
+    }
+
+    // Legacy results recorded failures as an `error` metric
+    let error = result.error.as_deref().or_else(|| result.metrics["error"].as_str());
+    if let Some(error) = error {
+        let _ = writeln!(
+            xml,
+            "      <error message=\"{}\" type=\"benchmark_error\"/>",
+            escape(error)
+        );
+    }
+
+    if !violations.is_empty() {
+        let details: Vec<String> = violations.iter().map(|v| escape(&v.to_string())).collect();
+        let _ = writeln!(
+            xml,
+            "      <failure message=\"{} threshold(s) breached\" type=\"threshold\">{}\
+             </failure>",
+            violations.len(),
+            details.join("\n")
+        );
+    }
+
+    xml.push_str("    </testcase>\n");
+}
+
+/// Returns the total timed duration of a result in seconds.
+fn case_time(result: &BenchmarkResult) -> f64 {
+    let duration_ms = result.metrics["duration_ms"].as_f64().unwrap_or_default();
+    let iterations = result.metrics["iterations"].as_f64().unwrap_or(1.0);
+    let seconds = duration_ms * iterations / 1000.0;
+    if seconds.is_finite() {
+        seconds
+    } else {
+        0.0
+    }
+}
+
+/// Escapes text for use in XML attributes and character data.
+fn escape(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::thresholds::TargetThresholds;
+    use serde_json::json;
+
+    #[test]
+    fn test_to_xml() {
+        let results = vec![
+            BenchmarkResult::new(
+                "storage-read-1mb",
+                json!({"ops_per_second": 50.0, "duration_ms": 20.0, "iterations": 100}),
+            ),
+            BenchmarkResult::new("encryption-1kb", json!({"ops_per_second": 9000.0})),
+            BenchmarkResult::failed("hashing-<x>", "setup failed"),
+        ];
+        let thresholds = Thresholds::new().with_target(
+            "storage-read-1mb",
+            TargetThresholds {
+                min_ops_per_second: Some(100.0),
+                ..Default::default()
+            },
+        );
+
+        let xml = to_xml(&results, &thresholds);
+
+        assert!(xml.starts_with("<?xml version=\"1.0\" encoding=\"UTF-8\"?>\n"));
+        assert!(xml.contains("tests=\"3\" failures=\"1\" errors=\"1\""));
+        assert!(xml.contains(
+            "<testcase name=\"storage-read-1mb\" classname=\"vault-benchmarks\" time=\"2.000000\">"
+        ));
+        assert!(xml.contains("<property name=\"ops_per_second\" value=\"50\"/>"));
+        assert!(xml.contains(
+            "<failure message=\"1 threshold(s) breached\" type=\"threshold\">\
+             storage-read-1mb: ops_per_second 50.00 is below the minimum of 100.00</failure>"
+        ));
+        assert!(xml.contains("<testcase name=\"hashing-&lt;x&gt;\""));
+        assert!(xml.contains("<error message=\"setup failed\" type=\"benchmark_error\"/>"));
+        assert!(xml.find("encryption-1kb") < xml.find("storage-read-1mb"));
+    }
+}
@@ -7,9 +7,18 @@
 //!
 //! - `BenchmarkResult`: Canonical result struct with `target_id`, `metrics`, and `timestamp`
 //! - `BenchTarget` trait: Interface for implementing benchmark targets
-//! - Adapters for Data Vault operations (encryption, hashing, anonymization, storage)
+//! - Adapters for Data Vault operations (encryption, key derivation, hashing,
+//!   anonymization, storage, compression, and the end-to-end ingest pipeline)
 //! - I/O utilities for reading/writing results to canonical output directories
 //! - Markdown report generation
+//! - Prometheus text exposition export
+//! - OTLP metrics export (with the `otlp` feature)
+//! - Peak memory sampling (with the `memory-sampler` feature)
+//! - SQLite result history (with the `sqlite` feature)
+//! - JUnit XML export for CI dashboards
+//! - CSV and HTML export for spreadsheets and reports
+//! - GitHub Actions annotations for threshold violations and regressions
+//! - Regression detection against a baseline run
 //!
 //! ## Canonical Structure
 //!
@@ -38,13 +47,71 @@
 
 pub mod result;
 pub mod markdown;
+pub mod prometheus;
+pub mod junit;
+pub mod csv;
+pub mod html;
+pub mod annotations;
 pub mod io;
 pub mod adapters;
+pub mod environment;
+pub mod lifecycle;
+pub mod options;
+pub mod regression;
+pub mod thresholds;
+pub mod stats;
+pub mod calibration;
+#[cfg(feature = "otlp")]
+pub mod otlp;
+#[cfg(feature = "sqlite")]
+pub mod sqlite;
+mod cycles;
+mod memory;
 
-pub use result::{BenchmarkResult, StandardMetrics};
-pub use markdown::generate_summary;
-pub use io::{BenchmarkIO, print_results, DEFAULT_OUTPUT_DIR, RAW_OUTPUT_DIR, SUMMARY_FILE};
-pub use adapters::{BenchTarget, all_targets, targets_by_prefix, target_by_id};
+pub use result::{
+    metric_f64, BenchmarkResult, DailyRollup, IntoMetricValue, MetricAggregate, RunStatus,
+    StandardMetrics, SuiteReport, INFINITY_SENTINEL, NAN_SENTINEL, NEG_INFINITY_SENTINEL,
+};
+pub use markdown::{
+    generate_comparison, generate_report_summary, generate_summary, generate_summary_with_charts,
+    generate_trend,
+};
+pub use prometheus::to_exposition;
+pub use csv::to_csv;
+pub use html::to_html;
+pub use io::{
+    BenchmarkIO, ManifestEntry, parse_result_filename, print_results, result_filename,
+    sanitize_target_id,
+    thresholds_template,
+    DAILY_ROLLUP_FILE, DEFAULT_OUTPUT_DIR, DEFAULT_REGRESSION_PERCENT, DEFAULT_THRESHOLDS_FILE, NDJSON_FILE, RAW_OUTPUT_DIR,
+    MANIFEST_FILE, RESULT_TIMESTAMP_FORMAT, STATUS_FILE, SUMMARY_FILE, SUMMARY_JSON_FILE,
+};
+pub use adapters::{
+    BenchTarget, DataPattern, RunContext, TargetInfo, TargetOverrides, TargetSpec, all_targets,
+    default_specs,
+    order_by_dependencies, parse_size, size_label, size_sweep_specs, targets_by_prefix,
+    target_by_id, targets_from_specs, DEFAULT_SEED, DEFAULT_TARGETS_FILE,
+    SIZE_SWEEP_FAMILIES,
+};
+pub use annotations::{
+    regression_annotation, running_in_github_actions, violation_annotation, AnnotationLevel,
+    GITHUB_ACTIONS_ENV,
+};
+pub use environment::{detect_commit, EnvironmentInfo, COMMIT_ENV};
+pub use lifecycle::{LifecycleLog, PhaseTiming};
+pub use options::RunOptions;
+pub use stats::DEFAULT_MAX_CV;
+pub use regression::{compare_against_baseline, metric_direction, MetricDirection, Regression};
+pub use thresholds::{Bound, TargetThresholds, Thresholds, Violation};
+#[cfg(feature = "sqlite")]
+pub use sqlite::SqliteStore;
+
+use adapters::dependency_waves;
+use memory::PeakMemorySampler;
+use std::sync::Arc;
+use tokio::sync::Semaphore;
+use tokio::task::JoinSet;
+use tracing::Instrument;
 
 /// Runs all registered benchmarks and returns results.
 ///
@@ -63,100 +130,459 @@ pub use adapters::{BenchTarget, all_targets, targets_by_prefix, target_by_id};
 /// }
 /// ```
 pub async fn run_all_benchmarks() -> Vec<BenchmarkResult> {
-    let targets = all_targets();
-    let mut results = Vec::with_capacity(targets.len());
-
-    for target in targets {
-        // Setup
-        if let Err(e) = target.setup().await {
-            eprintln!("Setup failed for {}: {}", target.id(), e);
-            continue;
-        }
+    run_all_benchmarks_report().await.results
+}
 
-        // Run benchmark
-        let result = target.run().await;
-        results.push(result);
+/// Runs all registered benchmarks, reporting the suite duration and
+/// pass/fail counts alongside the results.
+pub async fn run_all_benchmarks_report() -> SuiteReport {
+    let options = RunOptions::default();
+    run_with(specs_with(&options, |_| true), &options).await
+}
 
-        // Teardown
-        if let Err(e) = target.teardown().await {
-            eprintln!("Teardown failed for {}: {}", target.id(), e);
-        }
-    }
+/// Runs all registered benchmarks with the given options.
+pub async fn run_all_benchmarks_with(options: &RunOptions) -> Vec<BenchmarkResult> {
+    run_with(specs_with(options, |_| true), options).await.results
+}
 
-    results
+/// Runs all registered benchmarks, up to `max_concurrency` targets at once.
+///
+/// Each target still goes through setup, run, and teardown, and targets only
+/// start once their dependencies have finished. Results are returned in the
+/// same order as [`run_all_benchmarks`].
+///
+/// Concurrent targets compete for CPU and memory bandwidth, so throughput and
+/// latency numbers from a parallel run are skewed relative to a serial one.
+/// Use it for quick smoke runs; callers that need accurate numbers should
+/// stick to the serial [`run_all_benchmarks`].
+pub async fn run_all_benchmarks_parallel(max_concurrency: usize) -> Vec<BenchmarkResult> {
+    run_all_benchmarks_with(&RunOptions::new().with_parallelism(max_concurrency)).await
 }
 
 /// Runs benchmarks matching the given prefix and returns results.
 pub async fn run_benchmarks_by_prefix(prefix: &str) -> Vec<BenchmarkResult> {
-    let targets = targets_by_prefix(prefix);
-    let mut results = Vec::with_capacity(targets.len());
+    run_benchmarks_by_prefix_with(prefix, &RunOptions::default()).await
+}
+
+/// Runs benchmarks matching the given prefix with the given options.
+pub async fn run_benchmarks_by_prefix_with(prefix: &str, options: &RunOptions) -> Vec<BenchmarkResult> {
+    run_with(specs_with(options, |id| id.starts_with(prefix)), options).await.results
+}
+
+/// Runs the given specs with `options` applied, reporting the suite
+/// duration, pass/fail counts, and threshold breaches.
+///
+/// Use this to run a custom selection, such as specs loaded from a
+/// targets config or a size sweep. Targets are ordered by their
+/// dependencies; if they cannot be ordered, none run.
+pub async fn run_specs(mut specs: Vec<TargetSpec>, options: &RunOptions) -> SuiteReport {
+    options.apply(&mut specs);
+    run_with(specs, options).await
+}
+
+/// Returns the registered specs whose id matches `filter`, with `options` applied.
+fn specs_with(options: &RunOptions, filter: impl Fn(&str) -> bool) -> Vec<TargetSpec> {
+    let mut specs: Vec<TargetSpec> = default_specs().into_iter().filter(|s| filter(&s.id)).collect();
+    options.apply(&mut specs);
+    specs
+}
+
+/// Calibrates `specs` if `options` asks for a target duration, returning the
+/// chosen iteration counts.
+async fn calibrate_with(specs: &mut [TargetSpec], options: &RunOptions) -> Vec<(String, usize)> {
+    match options.target_duration {
+        Some(duration) => calibration::calibrate_specs(specs, duration).await,
+        None => Vec::new(),
+    }
+}
+
+/// Runs targets serially or concurrently as `options` requests, saving the
+/// results if asked to.
+async fn run_with(mut specs: Vec<TargetSpec>, options: &RunOptions) -> SuiteReport {
+    let started_at = chrono::Utc::now();
+    let start = std::time::Instant::now();
+
+    let calibrated = calibrate_with(&mut specs, options).await;
+    let targets = targets_from_specs(&specs);
+
+    let total_targets = targets.len();
+    let SuiteRun { mut results, phases } = if options.parallelism > 1 {
+        run_concurrently(targets, options).await
+    } else {
+        run_in_dependency_order(targets, options).await
+    };
+    if let Some(duration) = options.target_duration {
+        calibration::record_calibration(&mut results, &calibrated, duration);
+    }
+    for result in &mut results {
+        result.mark_noisy(options.max_cv);
+    }
+
+    let violations = options.thresholds.evaluate(&results);
+    let report = SuiteReport::new(results, started_at, start.elapsed())
+        .with_phases(phases)
+        .with_violations(violations);
+    if options.save {
+        let summary = generate_report_summary(&report);
+        let status = run_status(total_targets, &report.results, &report.violations, options);
+        report_save_error(save_results(&BenchmarkIO::new(), &report.results, &status, &summary));
+    }
+    report
+}
+
+/// Orders targets by their dependencies and runs them in sequence.
+///
+/// Runs nothing if the dependencies cannot be satisfied.
+async fn run_in_dependency_order(
+    targets: Vec<Box<dyn BenchTarget>>,
+    options: &RunOptions,
+) -> SuiteRun {
+    let targets = match order_by_dependencies(targets) {
+        Ok(targets) => targets,
+        Err(e) => {
+            eprintln!("Cannot order benchmark targets: {}", e);
+            return SuiteRun::default();
+        }
+    };
 
+    let stamp = SuiteStamp::detect();
+    let ctx = Arc::new(RunContext::new());
+    let mut run = SuiteRun::default();
     for target in targets {
-        if let Err(e) = target.setup().await {
-            eprintln!("Setup failed for {}: {}", target.id(), e);
-            continue;
+        let id = target.id().to_string();
+        let (result, log) =
+            run_isolated_phases(target, Arc::clone(&ctx), options.trace_lifecycle).await;
+        run.record(id, stamp.apply(result), log);
+    }
+
+    run
+}
+
+/// Runs targets in dependency waves, at most `max_concurrency` at a time.
+async fn run_concurrently(targets: Vec<Box<dyn BenchTarget>>, options: &RunOptions) -> SuiteRun {
+    let targets = match order_by_dependencies(targets) {
+        Ok(targets) => targets,
+        Err(e) => {
+            eprintln!("Cannot order benchmark targets: {}", e);
+            return SuiteRun::default();
         }
+    };
+
+    let semaphore = Arc::new(Semaphore::new(options.parallelism.max(1)));
+    let trace_lifecycle = options.trace_lifecycle;
+    let ctx = Arc::new(RunContext::new());
+    let mut finished = Vec::with_capacity(targets.len());
 
-        let result = target.run().await;
-        results.push(result);
+    for wave in dependency_waves(targets) {
+        let mut tasks = JoinSet::new();
+        for (index, target) in wave {
+            let semaphore = Arc::clone(&semaphore);
+            let ctx = Arc::clone(&ctx);
+            tasks.spawn(async move {
+                let _permit = semaphore.acquire_owned().await.expect("semaphore is never closed");
+                let id = target.id().to_string();
+                let (result, log) = run_isolated_phases(target, ctx, trace_lifecycle).await;
+                (index, id, result, log)
+            });
+        }
 
-        if let Err(e) = target.teardown().await {
-            eprintln!("Teardown failed for {}: {}", target.id(), e);
+        while let Some(joined) = tasks.join_next().await {
+            match joined {
+                Ok(outcome) => finished.push(outcome),
+                Err(e) => eprintln!("Benchmark task failed: {}", e),
+            }
         }
     }
 
-    results
+    let stamp = SuiteStamp::detect();
+    finished.sort_by_key(|(index, ..)| *index);
+    let mut run = SuiteRun::default();
+    for (_, id, result, log) in finished {
+        run.record(id, stamp.apply(result), log);
+    }
+    run
+}
+
+/// Results and phase timings collected while running a suite.
+#[derive(Default)]
+struct SuiteRun {
+    results: Vec<BenchmarkResult>,
+    phases: Vec<(String, LifecycleLog)>,
+}
+
+impl SuiteRun {
+    /// Records one target's outcome; the log is missing if it panicked.
+    fn record(&mut self, id: String, result: BenchmarkResult, log: Option<LifecycleLog>) {
+        self.results.push(result);
+        if let Some(log) = log {
+            self.phases.push((id, log));
+        }
+    }
 }
 
 /// Runs a single benchmark by ID and returns the result.
 pub async fn run_benchmark_by_id(id: &str) -> Option<BenchmarkResult> {
-    let target = target_by_id(id)?;
+    run_benchmark_by_id_with(id, &RunOptions::default()).await
+}
+
+/// Runs a single benchmark by ID with the given options.
+pub async fn run_benchmark_by_id_with(id: &str, options: &RunOptions) -> Option<BenchmarkResult> {
+    let mut specs = specs_with(options, |target_id| target_id == id);
+    let calibrated = calibrate_with(&mut specs, options).await;
+    let target = targets_from_specs(&specs).pop()?;
+    let ctx = Arc::new(RunContext::new());
+    let (result, _) = run_isolated_phases(target, ctx, options.trace_lifecycle).await;
+    let mut result = SuiteStamp::detect().apply(result);
+    if let Some(duration) = options.target_duration {
+        calibration::record_calibration(std::slice::from_mut(&mut result), &calibrated, duration);
+    }
+    result.mark_noisy(options.max_cv);
 
-    if let Err(e) = target.setup().await {
-        eprintln!("Setup failed for {}: {}", id, e);
-        return None;
+    if options.save {
+        let results = std::slice::from_ref(&result);
+        let status = run_status(1, results, &options.thresholds.evaluate(results), options);
+        let summary = generate_summary(results);
+        report_save_error(save_results(&BenchmarkIO::new(), results, &status, &summary));
     }
+    Some(result)
+}
 
-    let result = target.run().await;
+/// Metadata detected once per suite and stamped on every result.
+struct SuiteStamp {
+    environment: EnvironmentInfo,
+    commit: Option<String>,
+}
 
-    if let Err(e) = target.teardown().await {
-        eprintln!("Teardown failed for {}: {}", id, e);
+impl SuiteStamp {
+    fn detect() -> Self {
+        Self {
+            environment: EnvironmentInfo::detect(),
+            commit: detect_commit(),
+        }
     }
 
-    Some(result)
+    fn apply(&self, result: BenchmarkResult) -> BenchmarkResult {
+        let result = result.with_environment(self.environment.clone());
+        match &self.commit {
+            Some(commit) => result.with_commit(commit.as_str()),
+            None => result,
+        }
+    }
+}
+
+/// Runs a single target through setup, run, and teardown.
+///
+/// A failed setup yields a [`BenchmarkResult::failed`] result, and the run
+/// and teardown phases are skipped. Each phase runs in a `tracing` span under a
+/// `bench_target` span that records the phase durations. When
+/// `trace_lifecycle` is set, the phase timestamps are also attached to the
+/// result's metrics as a `lifecycle` object.
+pub async fn run_target(target: &dyn BenchTarget, trace_lifecycle: bool) -> BenchmarkResult {
+    run_target_with_context(target, &RunContext::new(), trace_lifecycle).await
+}
+
+/// Runs a single target like [`run_target`], sharing `ctx` with it.
+///
+/// Pass the same context to every target of a run so that targets can reuse
+/// fixtures published by their dependencies.
+pub async fn run_target_with_context(
+    target: &dyn BenchTarget,
+    ctx: &RunContext,
+    trace_lifecycle: bool,
+) -> BenchmarkResult {
+    let (result, log) = run_phases(target, ctx).await;
+    attach_lifecycle(result, &log, trace_lifecycle)
+}
+
+/// Runs the phases of a target, returning its result and phase timings.
+///
+/// The `bench_target` span carries the target id and records `setup_ms`,
+/// `run_ms`, and `teardown_ms` as each phase finishes.
+async fn run_phases(
+    target: &dyn BenchTarget,
+    ctx: &RunContext,
+) -> (BenchmarkResult, LifecycleLog) {
+    let target_span = tracing::info_span!(
+        "bench_target",
+        target = target.id(),
+        setup_ms = tracing::field::Empty,
+        run_ms = tracing::field::Empty,
+        teardown_ms = tracing::field::Empty,
+    );
+    let mut log = LifecycleLog::new();
+
+    log.setup_started();
+    let setup = target
+        .setup()
+        .instrument(tracing::info_span!(parent: &target_span, "setup"))
+        .await;
+    log.setup_finished(setup.as_ref().err().map(ToString::to_string));
+    record_phase(&target_span, "setup_ms", &log.setup);
+    tracing::debug!(parent: &target_span, lifecycle = ?log.setup, "setup finished");
+
+    if let Err(e) = setup {
+        eprintln!("Setup failed for {}: {}", target.id(), e);
+        return (BenchmarkResult::failed(target.id(), format!("Setup failed: {e}")), log);
+    }
+
+    log.run_started();
+    let sampler = PeakMemorySampler::start();
+    let mut result = target
+        .run_with_context(ctx)
+        .instrument(tracing::info_span!(parent: &target_span, "run"))
+        .await;
+    if let Some(sampler) = sampler {
+        record_peak_memory(&mut result, sampler.finish());
+    }
+    log.run_finished();
+    record_phase(&target_span, "run_ms", &log.run);
+    tracing::debug!(parent: &target_span, lifecycle = ?log.run, "run finished");
+
+    log.teardown_started();
+    let teardown = target
+        .teardown()
+        .instrument(tracing::info_span!(parent: &target_span, "teardown"))
+        .await;
+    log.teardown_finished(teardown.as_ref().err().map(ToString::to_string));
+    record_phase(&target_span, "teardown_ms", &log.teardown);
+    tracing::debug!(parent: &target_span, lifecycle = ?log.teardown, "teardown finished");
+
+    if let Err(e) = teardown {
+        eprintln!("Teardown failed for {}: {}", target.id(), e);
+    }
+
+    (result, log)
+}
+
+/// Sets `memory_bytes` on a successful result that did not report its own.
+fn record_peak_memory(result: &mut BenchmarkResult, peak_bytes: u64) {
+    if result.is_failed() {
+        return;
+    }
+    if let Some(obj) = result.metrics.as_object_mut() {
+        obj.entry("memory_bytes").or_insert(peak_bytes.into());
+    }
+}
+
+/// Records a finished phase's duration on the target span.
+fn record_phase(span: &tracing::Span, field: &str, phase: &PhaseTiming) {
+    if let Some(ms) = phase.duration_ms() {
+        span.record(field, ms);
+    }
+}
+
+/// Attaches `log` to the result's metrics as `lifecycle` if `trace_lifecycle` is set.
+fn attach_lifecycle(
+    mut result: BenchmarkResult,
+    log: &LifecycleLog,
+    trace_lifecycle: bool,
+) -> BenchmarkResult {
+    if trace_lifecycle {
+        if let Some(obj) = result.metrics.as_object_mut() {
+            obj.insert("lifecycle".to_string(), log.to_json_value());
+        }
+    }
+    result
+}
+
+/// Runs a target like [`run_target_with_context`] on its own task.
+///
+/// A panic inside the target fails only that target: it is logged and
+/// reported as a [`BenchmarkResult::failed`] result rather than unwinding
+/// through the caller.
+pub async fn run_target_isolated(
+    target: Box<dyn BenchTarget>,
+    ctx: Arc<RunContext>,
+    trace_lifecycle: bool,
+) -> BenchmarkResult {
+    run_isolated_phases(target, ctx, trace_lifecycle).await.0
+}
+
+/// Runs a target like [`run_target_isolated`], also returning its phase
+/// timings unless it panicked.
+async fn run_isolated_phases(
+    target: Box<dyn BenchTarget>,
+    ctx: Arc<RunContext>,
+    trace_lifecycle: bool,
+) -> (BenchmarkResult, Option<LifecycleLog>) {
+    let id = target.id().to_string();
+    let task = tokio::spawn(async move {
+        let (result, log) = run_phases(target.as_ref(), &ctx).await;
+        (attach_lifecycle(result, &log, trace_lifecycle), log)
+    });
+
+    match task.await {
+        Ok((result, log)) => (result, Some(log)),
+        Err(e) => {
+            let message = if e.is_panic() {
+                panic_message(e.into_panic())
+            } else {
+                e.to_string()
+            };
+            tracing::error!(target_id = %id, error = %message, "Benchmark target panicked");
+            (BenchmarkResult::failed(id, message), None)
+        }
+    }
+}
+
+/// Extracts the message from a panic payload.
+fn panic_message(payload: Box<dyn std::any::Any + Send>) -> String {
+    match payload.downcast::<String>() {
+        Ok(message) => *message,
+        Err(payload) => match payload.downcast::<&'static str>() {
+            Ok(message) => (*message).to_string(),
+            Err(_) => "panicked with a non-string payload".to_string(),
+        },
+    }
 }
 
 /// Runs all benchmarks and writes results to canonical output directories.
 pub async fn run_and_save_benchmarks() -> std::io::Result<Vec<BenchmarkResult>> {
-    let results = run_all_benchmarks().await;
+    let report = run_all_benchmarks_report().await;
+    let status = RunStatus::new(all_targets().len(), &report.results);
+    save_results(&BenchmarkIO::new(), &report.results, &status, &generate_report_summary(&report))?;
+    Ok(report.results)
+}
 
-    let io = BenchmarkIO::new();
-    io.write_results(&results)?;
+/// Builds the run status of `results`, counting threshold breaches as
+/// regressions when `options` sets any thresholds.
+fn run_status(
+    total_targets: usize,
+    results: &[BenchmarkResult],
+    violations: &[Violation],
+    options: &RunOptions,
+) -> RunStatus {
+    let status = RunStatus::new(total_targets, results);
+    if options.thresholds.is_empty() {
+        status
+    } else {
+        status.with_regressions(violations.len())
+    }
+}
 
-    let summary = generate_summary(&results);
-    io.write_summary(&results, &summary)?;
+/// Writes results, summary, and run status through `io`.
+fn save_results(
+    io: &BenchmarkIO,
+    results: &[BenchmarkResult],
+    status: &RunStatus,
+    summary: &str,
+) -> std::io::Result<()> {
+    io.write_results(results)?;
+    io.write_summary(results, summary)?;
+    io.write_status(status)?;
+    Ok(())
+}
 
-    Ok(results)
+/// Reports a failed save from an entrypoint that returns results regardless.
+fn report_save_error(saved: std::io::Result<()>) {
+    if let Err(e) = saved {
+        eprintln!("Failed to save benchmark results: {}", e);
+    }
 }
 
-/// Lists all available benchmark target IDs.
-pub fn list_benchmark_ids() -> Vec<&'static str> {
-    // We need to create the targets to get their IDs
-    // Since BenchTarget returns &str, we'll return a static list
-    vec![
-        "encryption-1kb",
-        "encryption-1mb",
-        "encryption-10mb",
-        "hashing-blake3-1mb",
-        "hashing-sha256-1mb",
-        "checksum-verification-1mb",
-        "anonymization-100-records",
-        "anonymization-1000-records",
-        "pii-detection-1000-records",
-        "storage-write-1mb",
-        "storage-read-1mb",
-        "content-addressing-1mb",
-    ]
+/// Lists all available benchmark target IDs, in registration order.
+pub fn list_benchmark_ids() -> Vec<String> {
+    default_specs().into_iter().map(|spec| spec.id).collect()
 }
 
 #[cfg(test)]
@@ -184,16 +610,319 @@ mod tests {
 
     #[tokio::test]
     async fn test_run_benchmark_by_id() {
-        let result = run_benchmark_by_id("encryption-1kb").await;
-        assert!(result.is_some());
-        assert_eq!(result.unwrap().target_id, "encryption-1kb");
+        let result = run_benchmark_by_id("encryption-1kb").await.unwrap();
+        assert_eq!(result.target_id, "encryption-1kb");
+        assert!(result.environment.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_run_with_iterations_override() {
+        let options = RunOptions::new().with_iterations(7);
+
+        let result = run_benchmark_by_id_with("hashing-blake3-1mb", &options).await.unwrap();
+        assert_eq!(result.metrics["iterations"].as_u64().unwrap(), 7);
+    }
+
+    #[tokio::test]
+    async fn test_run_with_warmup_and_parallelism() {
+        let options = RunOptions::new()
+            .with_iterations(5)
+            .with_warmup(2)
+            .with_parallelism(2);
+
+        let results = run_benchmarks_by_prefix_with("hashing-sha", &options).await;
+        assert_eq!(results.len(), 2);
+        for result in &results {
+            assert_eq!(result.metrics["iterations"].as_u64().unwrap(), 5);
+            assert_eq!(result.metrics["warmup_iterations"].as_u64().unwrap(), 2);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_with_reports_suite() {
+        let options = RunOptions::new().with_iterations(5);
+
+        let specs = specs_with(&options, |id| id.starts_with("hashing-sha"));
+
+        let report = run_with(specs, &options).await;
+        assert_eq!(report.results.len(), 2);
+        assert_eq!((report.succeeded, report.failed), (2, 0));
+        assert!(report.total_duration > std::time::Duration::ZERO);
+        assert!(report.started_at <= report.results[0].timestamp);
+        assert_eq!(report.phases.len(), 2);
+        assert!(report.phases[0].1.setup.duration_ms().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_run_specs_applies_options() {
+        let specs = size_sweep_specs(&default_specs(), "hashing-blake3", &[1024, 4096]).unwrap();
+        let options = RunOptions::new()
+            .with_iterations(3)
+            .with_parallelism(2)
+            .with_trace_lifecycle(true);
+
+        let report = run_specs(specs, &options).await;
+        assert_eq!((report.succeeded, report.failed), (2, 0));
+        for result in &report.results {
+            assert_eq!(result.metrics["iterations"], 3);
+            assert!(result.metrics["lifecycle"].is_object());
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_with_target_duration() {
+        let options = RunOptions::new().with_target_duration(std::time::Duration::from_millis(50));
+
+        let result = run_benchmark_by_id_with("hashing-blake3-1mb", &options).await.unwrap();
+        let calibrated = result.metrics["calibrated_iterations"].as_u64().unwrap();
+        assert!(calibrated >= calibration::MIN_CALIBRATED_ITERATIONS as u64);
+        assert_eq!(result.metrics["iterations"].as_u64().unwrap(), calibrated);
+        assert_eq!(result.metrics["target_duration_ms"], 50.0);
+    }
+
+    #[tokio::test]
+    async fn test_run_with_trim_outliers() {
+        let options = RunOptions::new().with_iterations(40).with_trim_outliers(true);
+
+        let result = run_benchmark_by_id_with("hashing-blake3-1mb", &options).await.unwrap();
+        assert_eq!(result.metrics["outliers_removed"], 4);
+        assert_eq!(result.metrics["duration_ms"], result.metrics["trimmed_mean_ms"]);
+        assert!(result.metrics["mean_ms"].is_number());
+    }
+
+    #[tokio::test]
+    async fn test_run_flags_noisy_results() {
+        let options = RunOptions::new().with_iterations(5).with_max_cv(0.0);
+
+        let result = run_benchmark_by_id_with("hashing-blake3-1mb", &options).await.unwrap();
+        assert!(result.metrics["cv"].as_f64().unwrap() > 0.0);
+        assert_eq!(result.metrics["noisy"], true);
+    }
+
+    #[tokio::test]
+    async fn test_run_with_seed() {
+        let id = "compression-zstd-json-1mb";
+        let run = |options: RunOptions| async move {
+            run_benchmark_by_id_with(id, &options.with_iterations(2)).await.unwrap()
+        };
+
+        let default = run(RunOptions::new()).await;
+        assert_eq!(default.metrics["seed"], DEFAULT_SEED);
+
+        let seeded = run(RunOptions::new().with_seed(7)).await;
+        let again = run(RunOptions::new().with_seed(7)).await;
+        assert_eq!(seeded.metrics["seed"], 7);
+        assert_eq!(seeded.metrics["compressed_bytes"], again.metrics["compressed_bytes"]);
+    }
+
+    #[tokio::test]
+    async fn test_run_with_data_pattern() {
+        let pattern = |id: &'static str, options: RunOptions| async move {
+            let result = run_benchmark_by_id_with(id, &options.with_iterations(2)).await.unwrap();
+            result.metrics["data_pattern"].clone()
+        };
+
+        assert_eq!(pattern("encryption-1kb", RunOptions::new()).await, "random");
+        assert_eq!(pattern("hashing-blake3-1mb", RunOptions::new()).await, "sequential");
+
+        let zeros = RunOptions::new().with_data_pattern(DataPattern::Zeros);
+        assert_eq!(pattern("encryption-1kb", zeros.clone()).await, "zeros");
+        assert_eq!(pattern("storage-write-1mb", zeros).await, "zeros");
+    }
+
+    #[tokio::test]
+    async fn test_run_concurrently_preserves_order() {
+        let options = RunOptions::new().with_iterations(5);
+        let targets = targets_from_specs(&specs_with(&options, |id| id.starts_with("hashing")));
+        let expected: Vec<String> = targets.iter().map(|t| t.id().to_string()).collect();
+
+        let results = run_concurrently(targets, &options.with_parallelism(2)).await.results;
+        let ids: Vec<String> = results.iter().map(|r| r.target_id.clone()).collect();
+        assert_eq!(ids, expected);
+    }
+
+    struct Panicking;
+
+    #[async_trait::async_trait]
+    impl BenchTarget for Panicking {
+        fn id(&self) -> &str {
+            "panicking"
+        }
+
+        async fn run(&self) -> BenchmarkResult {
+            panic!("Encryption failed");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_panicking_target_does_not_abort_suite() {
+        let options = RunOptions::new().with_iterations(5);
+        let specs = specs_with(&options, |id| id == "hashing-blake3-1mb");
+        let mut targets = targets_from_specs(&specs);
+        targets.insert(0, Box::new(Panicking));
+
+        let SuiteRun { results, phases } = run_in_dependency_order(targets, &options).await;
+        assert_eq!(results.len(), 2);
+        assert_eq!(phases.len(), 1, "a panicked target has no phase timings");
+
+        let failed = results.iter().find(|r| r.target_id == "panicking").unwrap();
+        assert_eq!(failed.error.as_deref(), Some("Encryption failed"));
+        assert_eq!(failed.metrics["success_rate"], 0.0);
+        assert!(results.iter().any(|r| r.target_id == "hashing-blake3-1mb"));
+    }
+
+    /// Publishes a value into the run context.
+    struct Publisher;
+
+    #[async_trait::async_trait]
+    impl BenchTarget for Publisher {
+        fn id(&self) -> &str {
+            "publisher"
+        }
+
+        async fn run(&self) -> BenchmarkResult {
+            self.run_with_context(&RunContext::new()).await
+        }
+
+        async fn run_with_context(&self, ctx: &RunContext) -> BenchmarkResult {
+            ctx.insert("publisher/value", 42u64);
+            BenchmarkResult::new(self.id(), serde_json::json!({}))
+        }
+    }
+
+    /// Reads the publisher's value back out of the run context.
+    struct Consumer;
+
+    #[async_trait::async_trait]
+    impl BenchTarget for Consumer {
+        fn id(&self) -> &str {
+            "consumer"
+        }
+
+        fn depends_on(&self) -> &[&str] {
+            &["publisher"]
+        }
+
+        async fn run(&self) -> BenchmarkResult {
+            self.run_with_context(&RunContext::new()).await
+        }
+
+        async fn run_with_context(&self, ctx: &RunContext) -> BenchmarkResult {
+            match ctx.get::<u64>("publisher/value") {
+                Some(value) => {
+                    BenchmarkResult::new(self.id(), serde_json::json!({ "shared": *value }))
+                }
+                None => BenchmarkResult::failed(self.id(), "publisher/value missing"),
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_dependent_targets_share_context() {
+        // Listed consumer first; ordering must move the publisher ahead
+        let targets = || -> Vec<Box<dyn BenchTarget>> {
+            vec![Box::new(Consumer), Box::new(Publisher)]
+        };
+        let options = RunOptions::new();
+
+        for results in [
+            run_in_dependency_order(targets(), &options).await.results,
+            run_concurrently(targets(), &options.clone().with_parallelism(2)).await.results,
+        ] {
+            let ids: Vec<&str> = results.iter().map(|r| r.target_id.as_str()).collect();
+            assert_eq!(ids, ["publisher", "consumer"]);
+            assert!(!results[1].is_failed(), "{:?}", results[1].error);
+            assert_eq!(results[1].metrics["shared"], 42);
+        }
+    }
+
+    #[tokio::test]
+    async fn test_saved_status_counts_threshold_breaches() {
+        let unreachable = TargetThresholds {
+            min_ops_per_second: Some(f64::MAX),
+            ..TargetThresholds::default()
+        };
+        let options = RunOptions::new()
+            .with_iterations(5)
+            .with_thresholds(Thresholds::new().with_target("hashing-blake3-1mb", unreachable));
+        let specs = specs_with(&options, |id| id == "hashing-blake3-1mb");
+        let report = run_with(specs, &options).await;
+        assert_eq!(report.failed, 0);
+        assert_eq!(report.violations.len(), 1);
+
+        let dir = tempfile::TempDir::new().unwrap();
+        let io = BenchmarkIO::with_paths(dir.path(), dir.path().join("raw"));
+        let status = run_status(1, &report.results, &report.violations, &options);
+        save_results(&io, &report.results, &status, "").unwrap();
+
+        let written = std::fs::read_to_string(dir.path().join(STATUS_FILE)).unwrap();
+        let written: RunStatus = serde_json::from_str(&written).unwrap();
+        assert_eq!((written.succeeded, written.failed), (1, 0));
+        assert_eq!(written.regressions, Some(1));
+        assert!(!written.passed);
+
+        let unchecked = run_status(1, &report.results, &report.violations, &RunOptions::new());
+        assert_eq!(unchecked.regressions, None);
+        assert!(unchecked.passed);
+    }
+
+    struct FailingSetup;
+
+    #[async_trait::async_trait]
+    impl BenchTarget for FailingSetup {
+        fn id(&self) -> &str {
+            "failing-setup"
+        }
+
+        async fn setup(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync>> {
+            Err("fixture unavailable".into())
+        }
+
+        async fn run(&self) -> BenchmarkResult {
+            unreachable!("run must not follow a failed setup")
+        }
+    }
+
+    #[tokio::test]
+    async fn test_setup_failure_yields_failed_result() {
+        let options = RunOptions::new().with_iterations(5);
+        let specs = specs_with(&options, |id| id == "hashing-blake3-1mb");
+        let mut targets = targets_from_specs(&specs);
+        targets.push(Box::new(FailingSetup));
+
+        let SuiteRun { results, phases } = run_in_dependency_order(targets, &options).await;
+        assert_eq!(results.len(), 2);
+        assert_eq!(phases.len(), 2);
+
+        let failed = results.iter().find(|r| r.target_id == "failing-setup").unwrap();
+        assert_eq!(failed.error.as_deref(), Some("Setup failed: fixture unavailable"));
+
+        let status = RunStatus::new(2, &results);
+        assert_eq!((status.succeeded, status.failed, status.skipped), (1, 1, 0));
+        assert!(!status.passed);
+    }
+
+    #[tokio::test]
+    async fn test_run_target_trace_lifecycle() {
+        let target = target_by_id("encryption-1kb").unwrap();
+
+        let traced = run_target(target.as_ref(), true).await;
+        let lifecycle = &traced.metrics["lifecycle"];
+        assert!(lifecycle["setup"]["start"].is_string());
+        assert!(lifecycle["run"]["end"].is_string());
+        assert!(lifecycle["teardown"]["end"].is_string());
+        let sampled = cfg!(all(feature = "memory-sampler", target_os = "linux"));
+        assert_eq!(traced.metrics.get("memory_bytes").is_some(), sampled);
+
+        let untraced = run_target(target.as_ref(), false).await;
+        assert!(untraced.metrics.get("lifecycle").is_none());
     }
 
     #[test]
     fn test_list_benchmark_ids() {
         let ids = list_benchmark_ids();
         assert!(!ids.is_empty());
-        assert!(ids.contains(&"encryption-1kb"));
-        assert!(ids.contains(&"hashing-blake3-1mb"));
+        assert!(ids.iter().any(|id| id == "encryption-1kb"));
+        assert!(ids.iter().any(|id| id == "hashing-blake3-1mb"));
     }
 }
@@ -0,0 +1,123 @@
+//! Target lifecycle event log.
+//!
+//! Records when each phase of a target (setup, run, teardown) started and
+//! ended. Used to diagnose intermittent setup failures, where the ordering
+//! and timing of phases is otherwise lost.
+
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// Start and end timestamps for a single lifecycle phase.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PhaseTiming {
+    /// When the phase started.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub start: Option<DateTime<Utc>>,
+    /// When the phase ended.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub end: Option<DateTime<Utc>>,
+    /// Error message if the phase failed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+}
+
+impl PhaseTiming {
+    /// Returns the phase duration in milliseconds, if it completed.
+    #[must_use]
+    pub fn duration_ms(&self) -> Option<f64> {
+        match (self.start, self.end) {
+            (Some(start), Some(end)) => {
+                Some((end - start).num_microseconds().unwrap_or(0) as f64 / 1000.0)
+            }
+            _ => None,
+        }
+    }
+
+    fn begin(&mut self) {
+        self.start = Some(Utc::now());
+    }
+
+    fn finish(&mut self, error: Option<String>) {
+        self.end = Some(Utc::now());
+        self.error = error;
+    }
+}
+
+/// Lifecycle event log for one benchmark target.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct LifecycleLog {
+    /// Setup phase timing.
+    pub setup: PhaseTiming,
+    /// Run phase timing.
+    pub run: PhaseTiming,
+    /// Teardown phase timing.
+    pub teardown: PhaseTiming,
+}
+
+impl LifecycleLog {
+    /// Creates an empty log.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Marks the start of the setup phase.
+    pub fn setup_started(&mut self) {
+        self.setup.begin();
+    }
+
+    /// Marks the end of the setup phase.
+    pub fn setup_finished(&mut self, error: Option<String>) {
+        self.setup.finish(error);
+    }
+
+    /// Marks the start of the run phase.
+    pub fn run_started(&mut self) {
+        self.run.begin();
+    }
+
+    /// Marks the end of the run phase.
+    pub fn run_finished(&mut self) {
+        self.run.finish(None);
+    }
+
+    /// Marks the start of the teardown phase.
+    pub fn teardown_started(&mut self) {
+        self.teardown.begin();
+    }
+
+    /// Marks the end of the teardown phase.
+    pub fn teardown_finished(&mut self, error: Option<String>) {
+        self.teardown.finish(error);
+    }
+
+    /// Converts the log to a JSON value suitable for the `lifecycle` metric.
+    #[must_use]
+    pub fn to_json_value(&self) -> serde_json::Value {
+        serde_json::to_value(self).unwrap_or_default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_lifecycle_log() {
+        let mut log = LifecycleLog::new();
+        log.setup_started();
+        log.setup_finished(None);
+        log.run_started();
+        log.run_finished();
+        log.teardown_started();
+        log.teardown_finished(Some("boom".to_string()));
+
+        assert!(log.setup.duration_ms().unwrap() >= 0.0);
+        assert!(log.run.start.unwrap() >= log.setup.end.unwrap());
+
+        let json = log.to_json_value();
+        assert!(json["setup"]["start"].is_string());
+        assert!(json["setup"].get("error").is_none());
+        assert_eq!(json["teardown"]["error"], "boom");
+    }
+}
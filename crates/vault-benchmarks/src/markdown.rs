@@ -2,11 +2,17 @@
 //!
 //! This module generates human-readable markdown summaries of benchmark results.
 
-use crate::BenchmarkResult;
+use crate::{BenchmarkResult, LifecycleLog, PhaseTiming, SuiteReport};
 use chrono::Utc;
 
 /// Generates a markdown summary from benchmark results.
+///
+/// Targets are listed in `target_id` order so that summaries of repeated runs
+/// diff cleanly.
 pub fn generate_summary(results: &[BenchmarkResult]) -> String {
+    let mut sorted: Vec<&BenchmarkResult> = results.iter().collect();
+    sorted.sort_by(|a, b| a.target_id.cmp(&b.target_id));
+
     let mut md = String::new();
 
     // Header
@@ -16,18 +22,28 @@ pub fn generate_summary(results: &[BenchmarkResult]) -> String {
         Utc::now().format("%Y-%m-%d %H:%M:%S UTC")
     ));
 
+    if let Some(env) = results.iter().find_map(|r| r.environment.as_ref()) {
+        md.push_str(&format!("Environment: {}\n\n", env.describe()));
+    }
+
     // Overview table
     md.push_str("## Overview\n\n");
-    md.push_str("| Target | Key Metric | Value | Timestamp |\n");
-    md.push_str("|--------|------------|-------|----------|\n");
-
-    for result in results {
-        let key_metric = extract_key_metric(&result.metrics);
+    md.push_str("| Target | Key Metric | Value | P50 (ms) | P95 (ms) | P99 (ms) | Timestamp |\n");
+    md.push_str("|--------|------------|-------|-----|-----|-----|-----------|\n");
+
+    for result in &sorted {
+        let key_metric = match &result.error {
+            Some(_) => ("**FAILED**".to_string(), "-".to_string()),
+            None => extract_key_metric(&result.metrics),
+        };
         md.push_str(&format!(
-            "| {} | {} | {} | {} |\n",
+            "| {} | {} | {} | {} | {} | {} | {} |\n",
             result.target_id,
             key_metric.0,
             key_metric.1,
+            format_optional_metric(&result.metrics, "latency_p50_ms"),
+            format_optional_metric(&result.metrics, "latency_p95_ms"),
+            format_optional_metric(&result.metrics, "latency_p99_ms"),
             result.timestamp.format("%H:%M:%S")
         ));
     }
@@ -37,12 +53,15 @@ pub fn generate_summary(results: &[BenchmarkResult]) -> String {
     // Detailed results
     md.push_str("## Detailed Results\n\n");
 
-    for result in results {
+    for result in &sorted {
         md.push_str(&format!("### {}\n\n", result.target_id));
         md.push_str(&format!(
             "**Executed:** {}\n\n",
             result.timestamp.format("%Y-%m-%d %H:%M:%S UTC")
         ));
+        if let Some(error) = &result.error {
+            md.push_str(&format!("**FAILED:** {}\n\n", error));
+        }
 
         if let Some(obj) = result.metrics.as_object() {
             md.push_str("| Metric | Value |\n");
@@ -84,19 +103,91 @@ pub fn generate_summary(results: &[BenchmarkResult]) -> String {
     md
 }
 
+/// Generates a markdown summary of a suite run.
+///
+/// Same as [`generate_summary`] of the report's results, with the total
+/// runtime and pass/fail counts above the overview and, when the report has
+/// phase timings, a setup/teardown overhead table before the detailed results.
+pub fn generate_report_summary(report: &SuiteReport) -> String {
+    let mut summary = generate_summary(&report.results);
+    if !report.phases.is_empty() {
+        let phases = generate_phase_overhead(&report.phases);
+        match summary.find("## Detailed Results") {
+            Some(at) => summary.insert_str(at, &phases),
+            None => summary.push_str(&phases),
+        }
+    }
+
+    let overview = format!(
+        "**Total Runtime:** {:.2} s | **Passed:** {} | **Failed:** {}\n\n",
+        report.total_duration.as_secs_f64(),
+        report.succeeded,
+        report.failed
+    );
+
+    match summary.find("## Overview") {
+        Some(at) => format!("{}{}{}", &summary[..at], overview, &summary[at..]),
+        None => overview + &summary,
+    }
+}
+
+/// Generates the phase overhead section: per-target setup, run, and teardown
+/// times, and the share of total phase time spent outside `run`.
+fn generate_phase_overhead(phases: &[(String, LifecycleLog)]) -> String {
+    let mut sorted: Vec<&(String, LifecycleLog)> = phases.iter().collect();
+    sorted.sort_by(|a, b| a.0.cmp(&b.0));
+
+    let mut md = String::new();
+    md.push_str("## Phase Overhead\n\n");
+    md.push_str("| Target | Setup (ms) | Run (ms) | Teardown (ms) |\n");
+    md.push_str("|--------|------------|----------|---------------|\n");
+
+    let (mut overhead_ms, mut total_ms) = (0.0, 0.0);
+    for (target_id, log) in sorted {
+        let cell = |phase: &PhaseTiming| match (phase.duration_ms(), &phase.error) {
+            (Some(ms), Some(_)) => format!("{:.2} (failed)", ms),
+            (Some(ms), None) => format!("{:.2}", ms),
+            (None, _) => "-".to_string(),
+        };
+        md.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            target_id,
+            cell(&log.setup),
+            cell(&log.run),
+            cell(&log.teardown)
+        ));
+
+        let setup_ms = log.setup.duration_ms().unwrap_or(0.0);
+        let teardown_ms = log.teardown.duration_ms().unwrap_or(0.0);
+        overhead_ms += setup_ms + teardown_ms;
+        total_ms += setup_ms + teardown_ms + log.run.duration_ms().unwrap_or(0.0);
+    }
+
+    md.push('\n');
+    if total_ms > 0.0 {
+        md.push_str(&format!(
+            "**Setup + Teardown:** {:.2} ms of {:.2} ms ({:.1}%)\n\n",
+            overhead_ms,
+            total_ms,
+            overhead_ms / total_ms * 100.0
+        ));
+    }
+    md
+}
+
+/// Priority order for key metrics.
+const KEY_METRIC_PRIORITY: [&str; 5] = [
+    "throughput_bps",
+    "ops_per_second",
+    "duration_ms",
+    "latency_p50_ms",
+    "data_size_bytes",
+];
+
 /// Extracts the most important metric from results.
 fn extract_key_metric(metrics: &serde_json::Value) -> (String, String) {
     if let Some(obj) = metrics.as_object() {
-        // Priority order for key metrics
-        let priority = [
-            "throughput_bps",
-            "ops_per_second",
-            "duration_ms",
-            "latency_p50_ms",
-            "data_size_bytes",
-        ];
-
-        for key in priority {
+        for key in KEY_METRIC_PRIORITY {
             if let Some(value) = obj.get(key) {
                 return (format_metric_name(key), format_metric_value(value));
             }
@@ -111,6 +202,13 @@ fn extract_key_metric(metrics: &serde_json::Value) -> (String, String) {
     ("N/A".to_string(), "N/A".to_string())
 }
 
+/// Returns the name and numeric value of the highest-priority key metric.
+fn key_metric_value(metrics: &serde_json::Value) -> Option<(&'static str, f64)> {
+    KEY_METRIC_PRIORITY
+        .iter()
+        .find_map(|key| metrics.get(*key).and_then(|v| v.as_f64()).map(|v| (*key, v)))
+}
+
 /// Formats a metric name for display.
 fn format_metric_name(name: &str) -> String {
     name.split('_')
@@ -152,6 +250,14 @@ fn format_metric_value(value: &serde_json::Value) -> String {
     }
 }
 
+/// Formats a metric for a table cell, or a dash if the target doesn't emit it.
+fn format_optional_metric(metrics: &serde_json::Value, key: &str) -> String {
+    match metrics.get(key) {
+        Some(value) if !value.is_null() => format_metric_value(value),
+        _ => "-".to_string(),
+    }
+}
+
 /// Formats bytes for human-readable display.
 fn format_bytes(bytes: u64) -> String {
     const KB: u64 = 1024;
@@ -222,62 +328,251 @@ fn calculate_stats(results: &[BenchmarkResult]) -> Option<BenchmarkStats> {
 }
 
 /// Generates a comparison table between two benchmark runs.
+///
+/// Results are matched by `target_id`. Each key metric present in either run
+/// gets a column showing `old → new` with the percent change; targets found
+/// in only one run are labelled "new" or "removed".
 pub fn generate_comparison(
-    baseline: &[BenchmarkResult],
+    previous: &[BenchmarkResult],
     current: &[BenchmarkResult],
 ) -> String {
-    let mut md = String::new();
+    let metrics: Vec<&str> = KEY_METRIC_PRIORITY
+        .iter()
+        .copied()
+        .filter(|key| {
+            previous
+                .iter()
+                .chain(current)
+                .any(|r| r.metrics.get(*key).and_then(|v| v.as_f64()).is_some())
+        })
+        .collect();
+
+    let mut rows: Vec<(&str, Option<&BenchmarkResult>, Option<&BenchmarkResult>)> = current
+        .iter()
+        .map(|c| {
+            let prev = previous.iter().find(|p| p.target_id == c.target_id);
+            (c.target_id.as_str(), prev, Some(c))
+        })
+        .collect();
+    for prev in previous {
+        if !current.iter().any(|c| c.target_id == prev.target_id) {
+            rows.push((prev.target_id.as_str(), Some(prev), None));
+        }
+    }
 
+    let mut md = String::new();
     md.push_str("# Benchmark Comparison\n\n");
-    md.push_str("| Target | Metric | Baseline | Current | Change |\n");
-    md.push_str("|--------|--------|----------|---------|--------|\n");
 
-    for current_result in current {
-        if let Some(baseline_result) = baseline
-            .iter()
-            .find(|b| b.target_id == current_result.target_id)
-        {
-            if let (Some(base_obj), Some(curr_obj)) = (
-                baseline_result.metrics.as_object(),
-                current_result.metrics.as_object(),
-            ) {
-                for (key, curr_val) in curr_obj {
-                    if let (Some(base_f), Some(curr_f)) =
-                        (base_obj.get(key).and_then(|v| v.as_f64()), curr_val.as_f64())
-                    {
-                        let change = if base_f != 0.0 {
-                            ((curr_f - base_f) / base_f) * 100.0
-                        } else {
-                            0.0
-                        };
-
-                        let change_str = if change > 0.0 {
-                            format!("+{:.1}%", change)
-                        } else {
-                            format!("{:.1}%", change)
-                        };
-
-                        md.push_str(&format!(
-                            "| {} | {} | {} | {} | {} |\n",
-                            current_result.target_id,
-                            format_metric_name(key),
-                            format_metric_value(&serde_json::json!(base_f)),
-                            format_metric_value(curr_val),
-                            change_str
-                        ));
-                    }
-                }
-            }
+    md.push_str("| Target | Status |");
+    for metric in &metrics {
+        md.push_str(&format!(" {} |", format_metric_name(metric)));
+    }
+    md.push_str("\n|--------|--------|");
+    for _ in &metrics {
+        md.push_str("---|");
+    }
+    md.push('\n');
+
+    for (target_id, prev, curr) in rows {
+        let status = match (prev, curr) {
+            (None, _) => "new",
+            (_, None) => "removed",
+            _ => "",
+        };
+        md.push_str(&format!("| {} | {} |", target_id, status));
+
+        for metric in &metrics {
+            let old = prev.and_then(|r| r.metrics.get(*metric)).and_then(|v| v.as_f64());
+            let new = curr.and_then(|r| r.metrics.get(*metric)).and_then(|v| v.as_f64());
+            md.push_str(&format!(" {} |", format_change(old, new)));
+        }
+        md.push('\n');
+    }
+
+    md
+}
+
+/// Formats one comparison cell as `old → new` with a percent-change arrow.
+fn format_change(old: Option<f64>, new: Option<f64>) -> String {
+    let fmt = |v: f64| format_metric_value(&serde_json::json!(v));
+
+    match (old, new) {
+        (Some(old), Some(new)) => {
+            let change = if old != 0.0 { (new - old) / old * 100.0 } else { 0.0 };
+            let arrow = if change > 0.0 {
+                format!("▲ +{:.1}%", change)
+            } else if change < 0.0 {
+                format!("▼ {:.1}%", change)
+            } else {
+                "0.0%".to_string()
+            };
+            format!("{} → {} {}", fmt(old), fmt(new), arrow)
         }
+        (None, Some(new)) => fmt(new),
+        (Some(old), None) => fmt(old),
+        (None, None) => "-".to_string(),
+    }
+}
+
+/// Generates a chronological trend report of one metric for a single target.
+///
+/// `history` may contain results for any number of targets; only those
+/// matching `target_id` that carry a numeric `metric` are included. The
+/// lowest and highest values in the series are highlighted.
+pub fn generate_trend(target_id: &str, metric: &str, history: &[BenchmarkResult]) -> String {
+    let mut points: Vec<(&BenchmarkResult, f64)> = history
+        .iter()
+        .filter(|r| r.target_id == target_id)
+        .filter_map(|r| r.metrics.get(metric).and_then(|v| v.as_f64()).map(|v| (r, v)))
+        .collect();
+    points.sort_by_key(|p| p.0.timestamp);
+
+    let mut md = String::new();
+    md.push_str(&format!(
+        "## Trend: {} ({})\n\n",
+        target_id,
+        format_metric_name(metric)
+    ));
+
+    if points.is_empty() {
+        md.push_str("No data available for this metric.\n");
+        return md;
+    }
+
+    let values: Vec<f64> = points.iter().map(|(_, v)| *v).collect();
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+
+    md.push_str(&format!("`{}`\n\n", sparkline(&values)));
+
+    md.push_str("| Run | Timestamp | Value | |\n");
+    md.push_str("|-----|-----------|-------|---|\n");
+
+    for (i, (result, value)) in points.iter().enumerate() {
+        let marker = if *value == max && max != min {
+            "**max**"
+        } else if *value == min && max != min {
+            "**min**"
+        } else {
+            ""
+        };
+
+        md.push_str(&format!(
+            "| {} | {} | {} | {} |\n",
+            i + 1,
+            result.timestamp.format("%Y-%m-%d %H:%M:%S"),
+            format_metric_value(&serde_json::json!(value)),
+            marker
+        ));
     }
 
+    md.push('\n');
     md
 }
 
+/// Generates a markdown summary with bar charts of each target's key metric.
+///
+/// Targets are grouped by key metric so that only comparable values share a
+/// chart; bars are scaled to the largest value in their group. The full
+/// summary from [`generate_summary`] follows the charts unchanged.
+pub fn generate_summary_with_charts(results: &[BenchmarkResult]) -> String {
+    const BAR_WIDTH: usize = 40;
+
+    let mut groups: Vec<(&str, Vec<(&str, f64)>)> = Vec::new();
+    for result in results {
+        if let Some((metric, value)) = key_metric_value(&result.metrics) {
+            match groups.iter_mut().find(|(m, _)| *m == metric) {
+                Some((_, rows)) => rows.push((&result.target_id, value)),
+                None => groups.push((metric, vec![(&result.target_id, value)])),
+            }
+        }
+    }
+
+    let mut charts = String::new();
+    charts.push_str("## Charts\n\n");
+
+    for (metric, rows) in &groups {
+        let max = rows.iter().map(|(_, v)| *v).fold(0.0, f64::max);
+        let label_width = rows.iter().map(|(id, _)| id.len()).max().unwrap_or(0);
+
+        charts.push_str(&format!("### {}\n\n```text\n", format_metric_name(metric)));
+        for (id, value) in rows {
+            let bar = horizontal_bar(*value, max, BAR_WIDTH);
+            charts.push_str(&format!(
+                "{:<label_width$}  {:<BAR_WIDTH$}  {}\n",
+                id,
+                bar,
+                format_metric_value(&serde_json::json!(value)),
+            ));
+        }
+        charts.push_str("```\n\n");
+    }
+
+    let summary = generate_summary(results);
+    match summary.find("## Detailed Results") {
+        Some(pos) => format!("{}{}{}", &summary[..pos], charts, &summary[pos..]),
+        None => format!("{}\n{}", summary, charts),
+    }
+}
+
+/// Renders a bar of up to `width` cells, using eighth blocks for the remainder.
+fn horizontal_bar(value: f64, max: f64, width: usize) -> String {
+    const PARTIAL: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+    if max <= 0.0 || value <= 0.0 {
+        return String::new();
+    }
+
+    let eighths = ((value / max).min(1.0) * (width * 8) as f64).round() as usize;
+    let mut bar = "█".repeat(eighths / 8);
+    if eighths % 8 > 0 {
+        bar.push(PARTIAL[eighths % 8]);
+    }
+    bar
+}
+
+/// Renders values as a sparkline using block characters.
+fn sparkline(values: &[f64]) -> String {
+    const TICKS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+
+    let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    let range = max - min;
+
+    values
+        .iter()
+        .map(|v| {
+            if range <= 0.0 {
+                TICKS[TICKS.len() / 2]
+            } else {
+                let idx = ((v - min) / range * (TICKS.len() - 1) as f64).round() as usize;
+                TICKS[idx.min(TICKS.len() - 1)]
+            }
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
+    #[test]
+    fn test_generate_summary_with_charts() {
+        let results = vec![
+            BenchmarkResult::new("fast", serde_json::json!({"throughput_bps": 2000.0})),
+            BenchmarkResult::new("slow", serde_json::json!({"throughput_bps": 1000.0})),
+            BenchmarkResult::new("ops", serde_json::json!({"ops_per_second": 50.0})),
+        ];
+
+        let md = generate_summary_with_charts(&results);
+
+        assert!(md.contains("### Throughput Bps"));
+        assert!(md.contains("### Ops Per Second"));
+        assert!(md.contains(&format!("fast  {}", "█".repeat(40))));
+        assert!(md.contains(&format!("slow  {}{}", "█".repeat(20), " ".repeat(20))));
+        assert!(md.find("## Charts").unwrap() < md.find("## Detailed Results").unwrap());
+    }
+
     #[test]
     fn test_generate_summary() {
         let results = vec![
@@ -306,6 +601,108 @@ mod tests {
         assert!(summary.contains("## Detailed Results"));
     }
 
+    #[test]
+    fn test_generate_summary_percentiles_sorted() {
+        let results = vec![
+            BenchmarkResult::new(
+                "b-target",
+                serde_json::json!({
+                    "ops_per_second": 10.0,
+                    "latency_p50_ms": 1.5,
+                    "latency_p95_ms": 2500.0,
+                    "latency_p99_ms": 0.005
+                }),
+            ),
+            BenchmarkResult::new("a-target", serde_json::json!({"ops_per_second": 20.0})),
+        ];
+
+        let summary = generate_summary(&results);
+
+        assert!(summary.contains("| P50 (ms) | P95 (ms) | P99 (ms) |"));
+        let row = "| b-target | Ops Per Second | 10.00 | 1.50 | 2.50 K | 0.0050 |";
+        assert!(summary.contains(row));
+        assert!(summary.contains("| a-target | Ops Per Second | 20.00 | - | - | - |"));
+        assert!(summary.find("| a-target").unwrap() < summary.find("| b-target").unwrap());
+        assert!(summary.find("### a-target").unwrap() < summary.find("### b-target").unwrap());
+    }
+
+    #[test]
+    fn test_generate_summary_marks_failures() {
+        let results = vec![BenchmarkResult::failed("broken", "Decryption failed")];
+
+        let summary = generate_summary(&results);
+
+        assert!(summary.contains("| broken | **FAILED** | - |"));
+        assert!(summary.contains("**FAILED:** Decryption failed"));
+    }
+
+    #[test]
+    fn test_generate_report_summary() {
+        let results = vec![
+            BenchmarkResult::new("ok", serde_json::json!({"ops_per_second": 100.0})),
+            BenchmarkResult::failed("broken", "Decryption failed"),
+        ];
+        let report = SuiteReport::new(results, Utc::now(), std::time::Duration::from_millis(1500));
+
+        let summary = generate_report_summary(&report);
+
+        let runtime = summary.find("**Total Runtime:** 1.50 s | **Passed:** 1 | **Failed:** 1");
+        assert!(runtime.unwrap() < summary.find("## Overview").unwrap());
+    }
+
+    #[test]
+    fn test_generate_report_summary_phase_overhead() {
+        let timing = |start_ms: i64, end_ms: i64, error: Option<&str>| {
+            let base = Utc::now();
+            PhaseTiming {
+                start: Some(base + chrono::Duration::milliseconds(start_ms)),
+                end: Some(base + chrono::Duration::milliseconds(end_ms)),
+                error: error.map(str::to_string),
+            }
+        };
+        let ran = LifecycleLog {
+            setup: timing(0, 30, None),
+            run: timing(30, 90, None),
+            teardown: timing(90, 100, None),
+        };
+        let skipped = LifecycleLog {
+            setup: timing(0, 20, Some("no fixture")),
+            ..LifecycleLog::default()
+        };
+        let results = vec![BenchmarkResult::new("ran", serde_json::json!({"ops_per_second": 1.0}))];
+        let report = SuiteReport::new(results, Utc::now(), std::time::Duration::from_millis(120))
+            .with_phases(vec![("ran".to_string(), ran), ("skipped".to_string(), skipped)]);
+
+        let summary = generate_report_summary(&report);
+
+        assert!(summary.contains("| ran | 30.00 | 60.00 | 10.00 |"));
+        assert!(summary.contains("| skipped | 20.00 (failed) | - | - |"));
+        assert!(summary.contains("**Setup + Teardown:** 60.00 ms of 120.00 ms (50.0%)"));
+        let phases = summary.find("## Phase Overhead").unwrap();
+        assert!(summary.find("## Overview").unwrap() < phases);
+        assert!(phases < summary.find("## Detailed Results").unwrap());
+    }
+
+    #[test]
+    fn test_generate_comparison() {
+        let previous = vec![
+            BenchmarkResult::new("kept", serde_json::json!({"ops_per_second": 100.0})),
+            BenchmarkResult::new("dropped", serde_json::json!({"ops_per_second": 10.0})),
+        ];
+        let current = vec![
+            BenchmarkResult::new("kept", serde_json::json!({"ops_per_second": 50.0})),
+            BenchmarkResult::new("added", serde_json::json!({"throughput_bps": 2000.0})),
+        ];
+
+        let md = generate_comparison(&previous, &current);
+
+        assert!(md.contains("| Target | Status | Throughput Bps | Ops Per Second |"));
+        assert!(md.contains("| kept |  | - | 100.00 → 50.00 ▼ -50.0% |"));
+        assert!(md.contains("| added | new | 2.00 K | - |"));
+        assert!(md.contains("| dropped | removed | - | 10.00 |"));
+        assert!(md.find("| added |").unwrap() < md.find("| dropped |").unwrap());
+    }
+
     #[test]
     fn test_format_metric_name() {
         assert_eq!(format_metric_name("duration_ms"), "Duration Ms");
@@ -318,4 +715,33 @@ mod tests {
         assert_eq!(format_bytes(2048), "2.00 KB");
         assert_eq!(format_bytes(5 * 1024 * 1024), "5.00 MB");
     }
+
+    #[test]
+    fn test_generate_trend() {
+        let base = Utc::now();
+        let history: Vec<BenchmarkResult> = [100.0, 300.0, 200.0]
+            .iter()
+            .enumerate()
+            .map(|(i, v)| {
+                BenchmarkResult::with_timestamp(
+                    "encryption-1kb",
+                    serde_json::json!({"ops_per_second": v}),
+                    base + chrono::Duration::seconds(i as i64),
+                )
+            })
+            .chain(std::iter::once(BenchmarkResult::new(
+                "other-target",
+                serde_json::json!({"ops_per_second": 1.0}),
+            )))
+            .collect();
+
+        let trend = generate_trend("encryption-1kb", "ops_per_second", &history);
+
+        assert!(trend.contains("## Trend: encryption-1kb (Ops Per Second)"));
+        assert!(trend.contains("`▁█▅`"));
+        assert!(trend.contains("| 2 |"));
+        assert!(!trend.contains("| 4 |"));
+        assert!(trend.contains("**max**"));
+        assert!(trend.contains("**min**"));
+    }
 }
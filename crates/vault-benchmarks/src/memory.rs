@@ -0,0 +1,102 @@
+//! Optional peak memory sampling for the `memory_bytes` metric.
+//!
+//! With the `memory-sampler` feature enabled on Linux, a background thread
+//! polls the process's resident set size from `/proc/self/status` while a
+//! target runs, and the highest reading is reported as `memory_bytes`.
+//! Everywhere else no sampler starts and the metric is omitted rather than
+//! estimated.
+//!
+//! The numbers are best-effort. RSS is process-wide, so it includes memory
+//! held before the target started and, in parallel runs, memory used by
+//! other targets. Short-lived spikes between two polls are missed.
+
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::Arc;
+use std::thread::JoinHandle;
+use std::time::Duration;
+
+/// How often the sampler thread reads the resident set size.
+const POLL_INTERVAL: Duration = Duration::from_millis(5);
+
+#[cfg(all(feature = "memory-sampler", target_os = "linux"))]
+mod imp {
+    /// Reads the current resident set size from `/proc/self/status`, which
+    /// reports it in KiB.
+    pub fn resident_bytes() -> Option<u64> {
+        let status = std::fs::read_to_string("/proc/self/status").ok()?;
+        let line = status.lines().find(|line| line.starts_with("VmRSS:"))?;
+        let kib: u64 = line.split_whitespace().nth(1)?.parse().ok()?;
+        Some(kib * 1024)
+    }
+}
+
+#[cfg(not(all(feature = "memory-sampler", target_os = "linux")))]
+mod imp {
+    /// Placeholder used when no sampler is compiled in.
+    pub fn resident_bytes() -> Option<u64> {
+        None
+    }
+}
+
+/// Tracks the peak resident set size on a background thread.
+pub(crate) struct PeakMemorySampler {
+    peak: Arc<AtomicU64>,
+    stop: Arc<AtomicBool>,
+    handle: JoinHandle<()>,
+}
+
+impl PeakMemorySampler {
+    /// Starts sampling, or returns `None` where memory cannot be read.
+    pub(crate) fn start() -> Option<Self> {
+        let initial = imp::resident_bytes()?;
+        let peak = Arc::new(AtomicU64::new(initial));
+        let stop = Arc::new(AtomicBool::new(false));
+
+        let handle = std::thread::spawn({
+            let peak = Arc::clone(&peak);
+            let stop = Arc::clone(&stop);
+            move || {
+                while !stop.load(Ordering::Acquire) {
+                    if let Some(bytes) = imp::resident_bytes() {
+                        peak.fetch_max(bytes, Ordering::Relaxed);
+                    }
+                    std::thread::park_timeout(POLL_INTERVAL);
+                }
+            }
+        });
+
+        Some(Self { peak, stop, handle })
+    }
+
+    /// Stops sampling and returns the peak resident set size in bytes.
+    pub(crate) fn finish(self) -> u64 {
+        self.stop.store(true, Ordering::Release);
+        self.handle.thread().unpark();
+        // The thread only reads /proc; a panic there just loses samples.
+        let _ = self.handle.join();
+
+        if let Some(bytes) = imp::resident_bytes() {
+            self.peak.fetch_max(bytes, Ordering::Relaxed);
+        }
+        self.peak.load(Ordering::Relaxed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_sampler_reports_only_where_supported() {
+        let Some(sampler) = PeakMemorySampler::start() else {
+            assert!(!cfg!(all(feature = "memory-sampler", target_os = "linux")));
+            return;
+        };
+
+        let buffer = vec![1u8; 32 * 1024 * 1024];
+        std::hint::black_box(&buffer);
+        let peak = sampler.finish();
+
+        assert!(peak >= buffer.len() as u64);
+    }
+}
@@ -0,0 +1,177 @@
+//! Run-wide options for the suite entrypoints.
+
+use crate::stats::DEFAULT_MAX_CV;
+use crate::{DataPattern, TargetSpec, Thresholds};
+use std::time::Duration;
+
+/// Options applied to every target of a run.
+///
+/// Unset fields leave each target's spec unchanged.
+#[derive(Debug, Clone)]
+pub struct RunOptions {
+    /// Overrides the timed iteration count of every target.
+    pub iterations: Option<usize>,
+    /// Overrides the untimed warmup iteration count of every target.
+    pub warmup: Option<usize>,
+    /// Maximum number of targets run at once; 0 or 1 runs them serially.
+    pub parallelism: usize,
+    /// Writes results, summary, and status to the canonical output directories.
+    pub save: bool,
+    /// Calibrates each target's iteration count to roughly fill this
+    /// duration, overriding `iterations`; see [`crate::calibration`].
+    pub target_duration: Option<Duration>,
+    /// Reports the mean with the top and bottom 5% of samples dropped,
+    /// keeping the untrimmed mean as `mean_ms`; see
+    /// [`StandardMetrics::from_samples_with`](crate::StandardMetrics::from_samples_with).
+    pub trim_outliers: bool,
+    /// Attaches each target's setup, run, and teardown timestamps to its
+    /// result as a `lifecycle` metric.
+    pub trace_lifecycle: bool,
+    /// Coefficient of variation above which a result is flagged `noisy`;
+    /// see [`BenchmarkResult::mark_noisy`](crate::BenchmarkResult::mark_noisy).
+    pub max_cv: f64,
+    /// Overrides the seed every target generates its test data from.
+    pub seed: Option<u64>,
+    /// Overrides the payload contents of encryption, hashing, and storage
+    /// targets.
+    pub data_pattern: Option<DataPattern>,
+    /// Limits the results are checked against; breaches are reported as
+    /// [`SuiteReport::violations`](crate::SuiteReport::violations) and
+    /// counted as regressions in the saved run status.
+    pub thresholds: Thresholds,
+}
+
+impl Default for RunOptions {
+    fn default() -> Self {
+        Self {
+            iterations: None,
+            warmup: None,
+            parallelism: 1,
+            save: false,
+            target_duration: None,
+            trim_outliers: false,
+            trace_lifecycle: false,
+            max_cv: DEFAULT_MAX_CV,
+            seed: None,
+            data_pattern: None,
+            thresholds: Thresholds::new(),
+        }
+    }
+}
+
+impl RunOptions {
+    /// Creates options that leave every target at its defaults.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Overrides the timed iteration count of every target.
+    #[must_use]
+    pub fn with_iterations(mut self, iterations: usize) -> Self {
+        self.iterations = Some(iterations);
+        self
+    }
+
+    /// Overrides the untimed warmup iteration count of every target.
+    #[must_use]
+    pub fn with_warmup(mut self, warmup: usize) -> Self {
+        self.warmup = Some(warmup);
+        self
+    }
+
+    /// Runs up to `parallelism` targets at once.
+    ///
+    /// Concurrent targets skew each other's numbers; see
+    /// [`run_all_benchmarks_parallel`](crate::run_all_benchmarks_parallel).
+    #[must_use]
+    pub fn with_parallelism(mut self, parallelism: usize) -> Self {
+        self.parallelism = parallelism;
+        self
+    }
+
+    /// Saves results to the canonical output directories after the run.
+    #[must_use]
+    pub fn with_save(mut self, save: bool) -> Self {
+        self.save = save;
+        self
+    }
+
+    /// Calibrates each target's iteration count to roughly fill `duration`.
+    #[must_use]
+    pub fn with_target_duration(mut self, duration: Duration) -> Self {
+        self.target_duration = Some(duration);
+        self
+    }
+
+    /// Trims outliers from each target's mean.
+    #[must_use]
+    pub fn with_trim_outliers(mut self, trim_outliers: bool) -> Self {
+        self.trim_outliers = trim_outliers;
+        self
+    }
+
+    /// Attaches each target's phase timestamps to its result.
+    #[must_use]
+    pub fn with_trace_lifecycle(mut self, trace_lifecycle: bool) -> Self {
+        self.trace_lifecycle = trace_lifecycle;
+        self
+    }
+
+    /// Sets the coefficient of variation above which a result is flagged `noisy`.
+    #[must_use]
+    pub fn with_max_cv(mut self, max_cv: f64) -> Self {
+        self.max_cv = max_cv;
+        self
+    }
+
+    /// Overrides the seed every target generates its test data from.
+    ///
+    /// Runs with the same seed see byte-identical inputs, which keeps a
+    /// regression bisectable.
+    #[must_use]
+    pub fn with_seed(mut self, seed: u64) -> Self {
+        self.seed = Some(seed);
+        self
+    }
+
+    /// Overrides the payload contents of encryption, hashing, and storage
+    /// targets.
+    #[must_use]
+    pub fn with_data_pattern(mut self, pattern: DataPattern) -> Self {
+        self.data_pattern = Some(pattern);
+        self
+    }
+
+    /// Checks the results against `thresholds` after the run.
+    #[must_use]
+    pub fn with_thresholds(mut self, thresholds: Thresholds) -> Self {
+        self.thresholds = thresholds;
+        self
+    }
+
+    /// Applies the options to `specs` in place.
+    ///
+    /// Calibration needs to run the targets, so `target_duration` is applied
+    /// by the suite entrypoints rather than here.
+    pub fn apply(&self, specs: &mut [TargetSpec]) {
+        for spec in specs {
+            if let Some(iterations) = self.iterations {
+                spec.iterations = iterations;
+            }
+            if let Some(warmup) = self.warmup {
+                spec.warmup = Some(warmup);
+            }
+            if let Some(seed) = self.seed {
+                spec.seed = seed;
+            }
+            if let Some(pattern) = self.data_pattern {
+                spec.data_pattern = Some(pattern);
+            }
+            if self.trim_outliers {
+                spec.trim_outliers = true;
+            }
+        }
+    }
+}
+
@@ -0,0 +1,187 @@
+//! OTLP export of benchmark results.
+//!
+//! Sends results to the OpenTelemetry collector named by the Infra
+//! [`TracingConfig`], so benchmark runs land in the same observability
+//! backend as production telemetry. Every numeric metric becomes a gauge
+//! with the same name as in the Prometheus export, with one data point per
+//! result tagged with `target_id` and `service_name`.
+//!
+//! Metrics are sent as OTLP/HTTP with JSON encoding, so the endpoint must be
+//! a collector's HTTP receiver (conventionally port 4318), not its gRPC one.
+
+use crate::prometheus::{metric_name, sample_value};
+use crate::BenchmarkResult;
+use serde_json::{json, Value};
+use std::collections::BTreeMap;
+use std::io;
+use std::time::Duration;
+use vault_integration::TracingConfig;
+
+/// Path of the OTLP/HTTP metrics endpoint.
+pub const METRICS_PATH: &str = "/v1/metrics";
+
+/// Instrumentation scope reported with exported metrics.
+const SCOPE_NAME: &str = "vault-benchmarks";
+
+/// Time allowed for the export request.
+const EXPORT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Builds an OTLP `ExportMetricsServiceRequest` in its JSON encoding.
+///
+/// Failed results and non-numeric metrics are skipped. Non-finite values use
+/// the proto3 JSON spellings `"NaN"`, `"Infinity"`, and `"-Infinity"`.
+#[must_use]
+pub fn metrics_request(results: &[BenchmarkResult], service_name: &str) -> Value {
+    let mut gauges: BTreeMap<String, Vec<Value>> = BTreeMap::new();
+    for result in results.iter().filter(|r| !r.is_failed()) {
+        let Some(metrics) = result.metrics.as_object() else {
+            continue;
+        };
+        let time_unix_nano = result.timestamp.timestamp_nanos_opt().unwrap_or_default();
+        for (name, value) in metrics {
+            let Some(value) = sample_value(value) else {
+                continue;
+            };
+            gauges.entry(metric_name(name)).or_default().push(json!({
+                "timeUnixNano": time_unix_nano.to_string(),
+                "asDouble": double_value(value),
+                "attributes": [
+                    string_attribute("target_id", &result.target_id),
+                    string_attribute("service_name", service_name),
+                ],
+            }));
+        }
+    }
+
+    let metrics: Vec<Value> = gauges
+        .into_iter()
+        .map(|(name, data_points)| {
+            json!({ "name": name, "gauge": { "dataPoints": data_points } })
+        })
+        .collect();
+
+    json!({
+        "resourceMetrics": [{
+            "resource": { "attributes": [string_attribute("service.name", service_name)] },
+            "scopeMetrics": [{
+                "scope": { "name": SCOPE_NAME, "version": env!("CARGO_PKG_VERSION") },
+                "metrics": metrics,
+            }],
+        }],
+    })
+}
+
+/// Exports results to the OTLP endpoint in `config`.
+///
+/// Does nothing and returns `Ok(0)` when tracing is disabled or no endpoint
+/// is set; otherwise returns the number of data points sent. `/v1/metrics`
+/// is appended to the endpoint unless it already ends with it.
+pub async fn export_results(
+    results: &[BenchmarkResult],
+    config: &TracingConfig,
+) -> io::Result<usize> {
+    let endpoint = match &config.otlp_endpoint {
+        Some(endpoint) if config.enabled => endpoint,
+        _ => return Ok(0),
+    };
+
+    let request = metrics_request(results, &config.service_name);
+    let data_points = request["resourceMetrics"][0]["scopeMetrics"][0]["metrics"]
+        .as_array()
+        .map_or(0, |metrics| {
+            metrics
+                .iter()
+                .filter_map(|m| m["gauge"]["dataPoints"].as_array())
+                .map(Vec::len)
+                .sum()
+        });
+    if data_points == 0 {
+        return Ok(0);
+    }
+
+    let client = reqwest::Client::builder()
+        .timeout(EXPORT_TIMEOUT)
+        .build()
+        .map_err(io::Error::other)?;
+    let response = client
+        .post(metrics_url(endpoint))
+        .json(&request)
+        .send()
+        .await
+        .map_err(io::Error::other)?;
+
+    let status = response.status();
+    if !status.is_success() {
+        let body = response.text().await.unwrap_or_default();
+        return Err(io::Error::other(format!("OTLP export failed with {status}: {body}")));
+    }
+    Ok(data_points)
+}
+
+/// Returns the metrics URL for a collector endpoint.
+fn metrics_url(endpoint: &str) -> String {
+    let endpoint = endpoint.trim_end_matches('/');
+    if endpoint.ends_with(METRICS_PATH) {
+        endpoint.to_string()
+    } else {
+        format!("{endpoint}{METRICS_PATH}")
+    }
+}
+
+/// Encodes an OTLP string attribute.
+fn string_attribute(key: &str, value: &str) -> Value {
+    json!({ "key": key, "value": { "stringValue": value } })
+}
+
+/// Encodes a double per the proto3 JSON mapping.
+fn double_value(value: f64) -> Value {
+    if value.is_nan() {
+        json!("NaN")
+    } else if value == f64::INFINITY {
+        json!("Infinity")
+    } else if value == f64::NEG_INFINITY {
+        json!("-Infinity")
+    } else {
+        json!(value)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StandardMetrics;
+
+    #[tokio::test]
+    async fn test_metrics_request() {
+        let metrics = StandardMetrics::new()
+            .with_ops_per_second(1500.0)
+            .with_custom("speedup", f64::INFINITY)
+            .with_custom("algorithm", "BLAKE3");
+        let results = vec![
+            BenchmarkResult::new("hashing-blake3-1mb", metrics.to_json_value()),
+            BenchmarkResult::failed("storage-read-1mb", "setup failed"),
+        ];
+
+        let request = metrics_request(&results, "vault-ci");
+
+        let resource = &request["resourceMetrics"][0];
+        assert_eq!(resource["resource"]["attributes"][0]["value"]["stringValue"], "vault-ci");
+        let metrics = resource["scopeMetrics"][0]["metrics"].as_array().unwrap();
+        let names: Vec<&str> = metrics.iter().map(|m| m["name"].as_str().unwrap()).collect();
+        assert_eq!(names, ["vault_benchmark_ops_per_second", "vault_benchmark_speedup"]);
+
+        let point = &metrics[0]["gauge"]["dataPoints"][0];
+        assert_eq!(point["asDouble"], 1500.0);
+        assert_eq!(point["attributes"][0]["value"]["stringValue"], "hashing-blake3-1mb");
+        assert_eq!(point["attributes"][1]["value"]["stringValue"], "vault-ci");
+        assert_eq!(metrics[1]["gauge"]["dataPoints"][0]["asDouble"], "Infinity");
+
+        assert_eq!(metrics_url("http://collector:4318/"), "http://collector:4318/v1/metrics");
+        let disabled = TracingConfig {
+            otlp_endpoint: Some("http://localhost:1".to_string()),
+            enabled: false,
+            ..Default::default()
+        };
+        assert_eq!(export_results(&results, &disabled).await.unwrap(), 0);
+    }
+}
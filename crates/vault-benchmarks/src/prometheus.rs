@@ -0,0 +1,193 @@
+//! Prometheus text exposition of benchmark results.
+//!
+//! Renders results in the Prometheus text format so CI can drop them into a
+//! node_exporter textfile collector directory. Every numeric metric becomes a
+//! gauge named `vault_benchmark_<metric>` labeled with the `target_id`.
+
+use crate::result::{INFINITY_SENTINEL, NAN_SENTINEL, NEG_INFINITY_SENTINEL};
+use crate::BenchmarkResult;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+
+/// Prefix shared by all exported metric names.
+pub const METRIC_PREFIX: &str = "vault_benchmark_";
+
+/// Standard metrics in export order, with their help text.
+const STANDARD_METRICS: &[(&str, &str)] = &[
+    ("duration_ms", "Average duration per iteration in milliseconds."),
+    ("ops_per_second", "Throughput in operations per second."),
+    ("bytes_per_second", "Throughput in bytes per second."),
+    ("latency_p50_ms", "Median latency in milliseconds."),
+    ("latency_p95_ms", "95th percentile latency in milliseconds."),
+    ("latency_p99_ms", "99th percentile latency in milliseconds."),
+    ("min_ms", "Fastest sample in milliseconds."),
+    ("max_ms", "Slowest sample in milliseconds."),
+    ("stddev_ms", "Sample standard deviation in milliseconds."),
+    ("cv", "Coefficient of variation of the samples (stddev / mean)."),
+    ("mean_ms", "Untrimmed mean duration per iteration in milliseconds."),
+    ("trimmed_mean_ms", "Mean duration per iteration after outlier trimming."),
+    ("outliers_removed", "Samples left out of the trimmed mean."),
+    ("memory_bytes", "Memory usage in bytes."),
+    ("iterations", "Number of timed iterations."),
+    ("data_size_bytes", "Payload size in bytes."),
+    ("success_rate", "Fraction of successful operations (0.0 to 1.0)."),
+];
+
+/// Renders results in the Prometheus text exposition format.
+///
+/// Standard metrics come first, followed by custom numeric metrics in name
+/// order. Non-numeric metrics (strings, booleans, nested objects) are
+/// skipped; non-finite sentinels are exported as `+Inf`, `-Inf`, or `NaN`.
+///
+/// A series may only appear once per exposition, so when several results
+/// share a target only the most recent one is exported.
+#[must_use]
+pub fn to_exposition(results: &[BenchmarkResult]) -> String {
+    let mut families: BTreeMap<&str, Vec<(&str, f64)>> = BTreeMap::new();
+    for result in latest_per_target(results) {
+        let Some(metrics) = result.metrics.as_object() else {
+            continue;
+        };
+        for (name, value) in metrics {
+            if let Some(value) = sample_value(value) {
+                families
+                    .entry(name.as_str())
+                    .or_default()
+                    .push((result.target_id.as_str(), value));
+            }
+        }
+    }
+
+    let mut out = String::new();
+    for (name, help) in STANDARD_METRICS {
+        if let Some(samples) = families.remove(name) {
+            write_family(&mut out, name, help, &samples);
+        }
+    }
+    for (name, samples) in &families {
+        let help = format!("Custom benchmark metric {}.", name);
+        write_family(&mut out, name, &help, samples);
+    }
+
+    out
+}
+
+/// Returns the most recent result of each target, in order of first
+/// appearance. Ties go to the later result.
+fn latest_per_target(results: &[BenchmarkResult]) -> Vec<&BenchmarkResult> {
+    let mut latest: Vec<&BenchmarkResult> = Vec::new();
+    let mut index: HashMap<&str, usize> = HashMap::new();
+    for result in results {
+        match index.get(result.target_id.as_str()) {
+            Some(&i) if result.timestamp >= latest[i].timestamp => latest[i] = result,
+            Some(_) => {}
+            None => {
+                index.insert(&result.target_id, latest.len());
+                latest.push(result);
+            }
+        }
+    }
+    latest
+}
+
+/// Writes one gauge family with its `# HELP` and `# TYPE` headers.
+fn write_family(out: &mut String, metric: &str, help: &str, samples: &[(&str, f64)]) {
+    let name = metric_name(metric);
+    let _ = writeln!(out, "# HELP {} {}", name, help);
+    let _ = writeln!(out, "# TYPE {} gauge", name);
+    for (target_id, value) in samples {
+        let _ = writeln!(
+            out,
+            "{}{{target_id=\"{}\"}} {}",
+            name,
+            escape_label(target_id),
+            format_value(*value)
+        );
+    }
+}
+
+/// Returns the numeric value of a metric, if it has one.
+pub(crate) fn sample_value(value: &serde_json::Value) -> Option<f64> {
+    match value {
+        serde_json::Value::Number(n) => n.as_f64(),
+        serde_json::Value::String(s) if s == INFINITY_SENTINEL => Some(f64::INFINITY),
+        serde_json::Value::String(s) if s == NEG_INFINITY_SENTINEL => Some(f64::NEG_INFINITY),
+        serde_json::Value::String(s) if s == NAN_SENTINEL => Some(f64::NAN),
+        _ => None,
+    }
+}
+
+/// Builds a valid Prometheus metric name from a metric key.
+pub(crate) fn metric_name(metric: &str) -> String {
+    let sanitized: String = metric
+        .chars()
+        .map(|c| if c.is_ascii_alphanumeric() || c == '_' { c } else { '_' })
+        .collect();
+    format!("{}{}", METRIC_PREFIX, sanitized)
+}
+
+/// Escapes a label value per the exposition format.
+fn escape_label(value: &str) -> String {
+    value
+        .replace('\\', "\\\\")
+        .replace('"', "\\\"")
+        .replace('\n', "\\n")
+}
+
+/// Formats a sample value, spelling non-finite values the Prometheus way.
+fn format_value(value: f64) -> String {
+    if value.is_nan() {
+        "NaN".to_string()
+    } else if value == f64::INFINITY {
+        "+Inf".to_string()
+    } else if value == f64::NEG_INFINITY {
+        "-Inf".to_string()
+    } else {
+        value.to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StandardMetrics;
+
+    #[test]
+    fn test_to_exposition() {
+        let metrics = StandardMetrics::new()
+            .with_ops_per_second(12345.0)
+            .with_custom("throughput_bps", 2.5)
+            .with_custom("speedup", f64::INFINITY)
+            .with_custom("algorithm", "BLAKE3");
+        let results = vec![
+            BenchmarkResult::new("encryption-1kb", metrics.to_json_value()),
+            BenchmarkResult::new("hashing-\"x\"", serde_json::json!({"ops_per_second": 7})),
+        ];
+
+        let text = to_exposition(&results);
+        assert!(text.starts_with(
+            "# HELP vault_benchmark_ops_per_second Throughput in operations per second.\n\
+             # TYPE vault_benchmark_ops_per_second gauge\n\
+             vault_benchmark_ops_per_second{target_id=\"encryption-1kb\"} 12345\n\
+             vault_benchmark_ops_per_second{target_id=\"hashing-\\\"x\\\"\"} 7\n"
+        ));
+        assert!(text.contains("# TYPE vault_benchmark_throughput_bps gauge\n"));
+        assert!(text.contains("vault_benchmark_throughput_bps{target_id=\"encryption-1kb\"} 2.5\n"));
+        assert!(text.contains("vault_benchmark_speedup{target_id=\"encryption-1kb\"} +Inf\n"));
+        assert!(!text.contains("algorithm"));
+    }
+
+    #[test]
+    fn test_to_exposition_keeps_latest_result_per_target() {
+        let ops = |n: u32| serde_json::json!({ "ops_per_second": n });
+        let older = BenchmarkResult::new("encryption-1kb", ops(1));
+        let mut newer = BenchmarkResult::new("encryption-1kb", ops(2));
+        newer.timestamp = older.timestamp + chrono::Duration::seconds(1);
+        let other = BenchmarkResult::new("hashing-blake3-1mb", ops(3));
+
+        let text = to_exposition(&[newer, other, older]);
+        assert_eq!(text.matches("target_id=\"encryption-1kb\"").count(), 1);
+        assert!(text.contains("_ops_per_second{target_id=\"encryption-1kb\"} 2\n"));
+        assert!(text.contains("_ops_per_second{target_id=\"hashing-blake3-1mb\"} 3\n"));
+    }
+}
@@ -0,0 +1,140 @@
+//! Regression detection against a stored baseline.
+//!
+//! Compares a set of results with a baseline run, matching by `target_id`,
+//! and flags performance metrics that got worse by more than a threshold.
+//! Only metrics with a known direction are compared; see [`metric_direction`].
+
+use crate::BenchmarkResult;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// Which way a metric moves when performance improves.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MetricDirection {
+    /// Larger values are better (throughput, success rate).
+    HigherIsBetter,
+    /// Smaller values are better (durations, latencies, memory).
+    LowerIsBetter,
+}
+
+/// A metric that degraded beyond the threshold relative to the baseline.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Regression {
+    /// Target the metric belongs to.
+    pub target_id: String,
+    /// Metric name.
+    pub metric: String,
+    /// Value in the baseline run.
+    pub baseline: f64,
+    /// Value in the current run.
+    pub current: f64,
+    /// Signed change from baseline to current, in percent.
+    pub percent_change: f64,
+}
+
+/// Returns the direction of a metric, or `None` if it is not a performance
+/// metric (counts, sizes, labels).
+///
+/// Besides the standard metrics, custom metrics are classified by suffix:
+/// `_per_second` and `_bps` are throughputs, `_ms` are durations.
+#[must_use]
+pub fn metric_direction(metric: &str) -> Option<MetricDirection> {
+    match metric {
+        "success_rate" => Some(MetricDirection::HigherIsBetter),
+        "memory_bytes" => Some(MetricDirection::LowerIsBetter),
+        m if m.ends_with("_per_second") || m.ends_with("_bps") => Some(MetricDirection::HigherIsBetter),
+        m if m.ends_with("_ms") => Some(MetricDirection::LowerIsBetter),
+        _ => None,
+    }
+}
+
+/// Compares `current` results with `baseline` and returns the regressions.
+///
+/// Results are matched by `target_id`; when the baseline holds several runs
+/// of a target, the most recent one is used. A metric regresses when it moved
+/// in the worse direction by more than `threshold_pct` percent. Targets or
+/// metrics missing from either side are skipped.
+#[must_use]
+pub fn compare_against_baseline(
+    current: &[BenchmarkResult],
+    baseline: &[BenchmarkResult],
+    threshold_pct: f64,
+) -> Vec<Regression> {
+    let mut latest: HashMap<&str, &BenchmarkResult> = HashMap::new();
+    for result in baseline {
+        latest
+            .entry(result.target_id.as_str())
+            .and_modify(|existing| {
+                if result.timestamp > existing.timestamp {
+                    *existing = result;
+                }
+            })
+            .or_insert(result);
+    }
+
+    let mut regressions = Vec::new();
+    for result in current {
+        let Some(base) = latest.get(result.target_id.as_str()) else {
+            continue;
+        };
+        let Some(metrics) = result.metrics.as_object() else {
+            continue;
+        };
+
+        for metric in metrics.keys() {
+            let Some(direction) = metric_direction(metric) else {
+                continue;
+            };
+            let Some(change) = result.percent_change(base, metric) else {
+                continue;
+            };
+
+            let degraded = match direction {
+                MetricDirection::HigherIsBetter => -change,
+                MetricDirection::LowerIsBetter => change,
+            };
+            if degraded > threshold_pct {
+                regressions.push(Regression {
+                    target_id: result.target_id.clone(),
+                    metric: metric.clone(),
+                    baseline: base.metrics[metric].as_f64().unwrap_or_default(),
+                    current: result.metrics[metric].as_f64().unwrap_or_default(),
+                    percent_change: change,
+                });
+            }
+        }
+    }
+
+    regressions
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_compare_against_baseline() {
+        let baseline = vec![BenchmarkResult::new(
+            "hashing-blake3-1mb",
+            json!({"ops_per_second": 1000.0, "latency_p99_ms": 2.0, "iterations": 100}),
+        )];
+        let current = vec![
+            BenchmarkResult::new(
+                "hashing-blake3-1mb",
+                json!({"ops_per_second": 850.0, "latency_p99_ms": 2.1, "iterations": 50}),
+            ),
+            BenchmarkResult::new("storage-read-1mb", json!({"ops_per_second": 1.0})),
+        ];
+
+        let regressions = compare_against_baseline(&current, &baseline, 10.0);
+        assert_eq!(regressions.len(), 1);
+        assert_eq!(regressions[0].metric, "ops_per_second");
+        assert_eq!(regressions[0].baseline, 1000.0);
+        assert_eq!(regressions[0].current, 850.0);
+        assert!((regressions[0].percent_change + 15.0).abs() < 1e-9);
+
+        assert!(compare_against_baseline(&current, &baseline, 20.0).is_empty());
+    }
+}
@@ -3,8 +3,17 @@
 //! This module defines the standardized `BenchmarkResult` struct used across
 //! all benchmark-target repositories.
 
-use chrono::{DateTime, Utc};
+use chrono::{DateTime, NaiveDate, NaiveTime, Utc};
 use serde::{Deserialize, Serialize};
+use crate::environment::EnvironmentInfo;
+use crate::lifecycle::LifecycleLog;
+use crate::thresholds::Violation;
+use crate::stats::{
+    coefficient_of_variation, dispersion, latency_percentiles, percentiles_exact, trimmed_mean,
+    MIN_CONFIDENT_SAMPLES, MIN_TAIL_SAMPLES, OUTLIER_TRIM_FRACTION,
+};
+use std::collections::BTreeMap;
+use std::time::Duration;
 
 /// Canonical benchmark result structure.
 ///
@@ -12,6 +21,10 @@ use serde::{Deserialize, Serialize};
 /// - `target_id`: Unique identifier for the benchmark target
 /// - `metrics`: JSON object containing benchmark measurements
 /// - `timestamp`: UTC timestamp when the benchmark was executed
+///
+/// An optional `result_digest` may be attached for tamper evidence, and
+/// optional `environment` and `commit` fields describe the host and the code
+/// measured; all are omitted from the serialized form when absent.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct BenchmarkResult {
     /// Unique identifier for the benchmark target.
@@ -20,6 +33,18 @@ pub struct BenchmarkResult {
     pub metrics: serde_json::Value,
     /// Timestamp when the benchmark was executed.
     pub timestamp: DateTime<Utc>,
+    /// Host the benchmark ran on.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub environment: Option<EnvironmentInfo>,
+    /// Git commit the benchmark was built from.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub commit: Option<String>,
+    /// BLAKE3 digest of the canonical result JSON (see [`BenchmarkResult::compute_digest`]).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub result_digest: Option<String>,
+    /// Why the benchmark failed, if it ran but did not succeed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
 }
 
 impl BenchmarkResult {
@@ -30,6 +55,10 @@ impl BenchmarkResult {
             target_id: target_id.into(),
             metrics,
             timestamp: Utc::now(),
+            environment: None,
+            commit: None,
+            result_digest: None,
+            error: None,
         }
     }
 
@@ -44,7 +73,75 @@ impl BenchmarkResult {
             target_id: target_id.into(),
             metrics,
             timestamp,
+            environment: None,
+            commit: None,
+            result_digest: None,
+            error: None,
+        }
+    }
+
+    /// Creates a result for a benchmark that ran but failed.
+    ///
+    /// The metrics carry only a `success_rate` of 0.
+    #[must_use]
+    pub fn failed(target_id: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            error: Some(message.into()),
+            ..Self::new(target_id, serde_json::json!({ "success_rate": 0.0 }))
+        }
+    }
+
+    /// Returns true if the benchmark failed.
+    ///
+    /// Results written before the `error` field existed recorded failures as
+    /// an `error` metric, which also counts.
+    #[must_use]
+    pub fn is_failed(&self) -> bool {
+        self.error.is_some() || self.metrics.get("error").is_some()
+    }
+
+    /// Flags the result as `noisy` if its `cv` metric exceeds `max_cv`.
+    ///
+    /// Returns true, and logs a warning, when the result is noisy. Results
+    /// without a `cv` metric are never flagged.
+    pub fn mark_noisy(&mut self, max_cv: f64) -> bool {
+        let Some(cv) = self.metrics.get("cv").and_then(serde_json::Value::as_f64) else {
+            return false;
+        };
+        if cv <= max_cv {
+            return false;
+        }
+
+        tracing::warn!(
+            target_id = %self.target_id,
+            cv,
+            max_cv,
+            "benchmark run is too noisy to trust"
+        );
+        if let Some(obj) = self.metrics.as_object_mut() {
+            obj.insert(NOISY.to_string(), true.into());
         }
+        true
+    }
+
+    /// Returns true if [`mark_noisy`](Self::mark_noisy) flagged this result.
+    #[must_use]
+    pub fn is_noisy(&self) -> bool {
+        self.metrics.get(NOISY).and_then(serde_json::Value::as_bool) == Some(true)
+    }
+
+    /// Attaches host environment metadata.
+    #[must_use]
+    pub fn with_environment(mut self, environment: EnvironmentInfo) -> Self {
+        self.environment = Some(environment);
+        self
+    }
+
+    /// Records the git commit the benchmark was built from.
+    #[must_use]
+    pub fn with_commit(mut self, commit: impl Into<String>) -> Self {
+        self.commit = Some(commit.into());
+        self
     }
 
     /// Returns the target ID.
@@ -74,9 +171,366 @@ impl BenchmarkResult {
     pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
         serde_json::from_str(json)
     }
+
+    /// Returns the change of `metric` relative to `baseline`, in percent.
+    ///
+    /// Positive means the value grew. Returns `None` if either result lacks a
+    /// numeric value for the metric or the baseline value is zero.
+    #[must_use]
+    pub fn percent_change(&self, baseline: &BenchmarkResult, metric: &str) -> Option<f64> {
+        let current = self.metrics.get(metric)?.as_f64()?;
+        let base = baseline.metrics.get(metric)?.as_f64()?;
+        if base == 0.0 {
+            return None;
+        }
+
+        Some((current - base) / base.abs() * 100.0)
+    }
+
+    /// Computes the BLAKE3 digest of this result, as lowercase hex.
+    ///
+    /// The digest covers the canonical JSON form: the result serialized
+    /// without `result_digest`, with object keys sorted lexicographically at
+    /// every depth and no whitespace between tokens. Floats use serde_json's
+    /// shortest round-trip formatting, so re-reading a written result yields
+    /// the same digest.
+    #[must_use]
+    pub fn compute_digest(&self) -> String {
+        let unsealed = Self {
+            result_digest: None,
+            ..self.clone()
+        };
+        let value = serde_json::to_value(&unsealed).unwrap_or_default();
+        let canonical = canonicalize(value).to_string();
+
+        vault_crypto::blake3_hex(canonical.as_bytes())
+    }
+
+    /// Computes and stores the digest in `result_digest`.
+    #[must_use]
+    pub fn with_digest(mut self) -> Self {
+        self.result_digest = Some(self.compute_digest());
+        self
+    }
+
+    /// Returns true if `result_digest` is present and matches the content.
+    #[must_use]
+    pub fn verify_digest(&self) -> bool {
+        self.result_digest
+            .as_deref()
+            .is_some_and(|digest| digest == self.compute_digest())
+    }
+}
+
+/// Rebuilds a JSON value with object keys in sorted order at every depth.
+fn canonicalize(value: serde_json::Value) -> serde_json::Value {
+    match value {
+        serde_json::Value::Object(map) => {
+            let mut entries: Vec<(String, serde_json::Value)> = map.into_iter().collect();
+            entries.sort_by(|a, b| a.0.cmp(&b.0));
+            serde_json::Value::Object(
+                entries
+                    .into_iter()
+                    .map(|(k, v)| (k, canonicalize(v)))
+                    .collect(),
+            )
+        }
+        serde_json::Value::Array(items) => {
+            serde_json::Value::Array(items.into_iter().map(canonicalize).collect())
+        }
+        other => other,
+    }
+}
+
+/// Compact run verdict written alongside the detailed results.
+///
+/// Unlike `summary.json`, which carries every metric, this is the single file
+/// orchestration polls to decide whether a run passed.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunStatus {
+    /// Unique identifier for this run.
+    pub run_id: String,
+    /// Timestamp when the status was produced.
+    pub timestamp: DateTime<Utc>,
+    /// Number of targets selected for the run.
+    pub total_targets: usize,
+    /// Number of targets that produced a successful result.
+    pub succeeded: usize,
+    /// Number of targets that produced a failed result.
+    pub failed: usize,
+    /// Number of targets that never produced a result (e.g. their
+    /// dependencies could not be ordered).
+    pub skipped: usize,
+    /// Number of regressions found, if a baseline was compared.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub regressions: Option<usize>,
+    /// Overall verdict: no failures, no regressions, and no tripped gate.
+    pub passed: bool,
+}
+
+impl RunStatus {
+    /// Builds a run status from the targets selected and the results produced.
+    ///
+    /// A result counts as failed when [`BenchmarkResult::is_failed`] says so.
+    #[must_use]
+    pub fn new(total_targets: usize, results: &[BenchmarkResult]) -> Self {
+        let failed = results.iter().filter(|r| r.is_failed()).count();
+        let succeeded = results.len() - failed;
+
+        Self {
+            run_id: uuid::Uuid::new_v4().to_string(),
+            timestamp: Utc::now(),
+            total_targets,
+            succeeded,
+            failed,
+            skipped: total_targets.saturating_sub(results.len()),
+            regressions: None,
+            passed: failed == 0,
+        }
+    }
+
+    /// Records the number of regressions or threshold breaches found.
+    #[must_use]
+    pub fn with_regressions(mut self, regressions: usize) -> Self {
+        self.regressions = Some(regressions);
+        self.passed &= regressions == 0;
+        self
+    }
+
+    /// Records the outcome of an extra gate, such as failing on noisy
+    /// results; a tripped gate fails the run.
+    #[must_use]
+    pub fn with_gate(mut self, passed: bool) -> Self {
+        self.passed &= passed;
+        self
+    }
+}
+
+/// Results of a whole suite run, with its wall-clock duration.
+#[derive(Debug, Clone)]
+pub struct SuiteReport {
+    /// Results of every target that produced one.
+    pub results: Vec<BenchmarkResult>,
+    /// Wall-clock time of the whole run, including calibration.
+    pub total_duration: Duration,
+    /// Number of results that succeeded.
+    pub succeeded: usize,
+    /// Number of results that failed.
+    pub failed: usize,
+    /// When the run started.
+    pub started_at: DateTime<Utc>,
+    /// Phase timings of every target that ran, in run order, keyed by
+    /// target id. Includes targets whose setup failed.
+    pub phases: Vec<(String, LifecycleLog)>,
+    /// Threshold breaches found in the results.
+    pub violations: Vec<Violation>,
+}
+
+impl SuiteReport {
+    /// Builds a report, counting failures with [`BenchmarkResult::is_failed`].
+    #[must_use]
+    pub fn new(
+        results: Vec<BenchmarkResult>,
+        started_at: DateTime<Utc>,
+        total_duration: Duration,
+    ) -> Self {
+        let failed = results.iter().filter(|r| r.is_failed()).count();
+
+        Self {
+            succeeded: results.len() - failed,
+            failed,
+            results,
+            total_duration,
+            started_at,
+            phases: Vec::new(),
+            violations: Vec::new(),
+        }
+    }
+
+    /// Attaches the per-target phase timings.
+    #[must_use]
+    pub fn with_phases(mut self, phases: Vec<(String, LifecycleLog)>) -> Self {
+        self.phases = phases;
+        self
+    }
+
+    /// Attaches the threshold breaches found in the results.
+    #[must_use]
+    pub fn with_violations(mut self, violations: Vec<Violation>) -> Self {
+        self.violations = violations;
+        self
+    }
+}
+
+/// Mean, minimum, and maximum of one metric over a day.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct MetricAggregate {
+    /// Arithmetic mean.
+    pub mean: f64,
+    /// Smallest value.
+    pub min: f64,
+    /// Largest value.
+    pub max: f64,
+}
+
+/// One target's results aggregated over a UTC day.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyRollup {
+    /// Target the runs belong to.
+    pub target_id: String,
+    /// UTC calendar day.
+    pub date: NaiveDate,
+    /// Number of runs aggregated.
+    pub run_count: usize,
+    /// Aggregates of every numeric top-level metric, keyed by metric name.
+    pub metrics: BTreeMap<String, MetricAggregate>,
+}
+
+impl DailyRollup {
+    /// Groups results by (target_id, UTC day) and aggregates their numeric metrics.
+    ///
+    /// Rollups are returned sorted by target id, then date.
+    #[must_use]
+    pub fn aggregate(results: &[BenchmarkResult]) -> Vec<Self> {
+        let mut groups: BTreeMap<(&str, NaiveDate), Vec<&BenchmarkResult>> = BTreeMap::new();
+        for result in results {
+            groups
+                .entry((result.target_id.as_str(), result.timestamp.date_naive()))
+                .or_default()
+                .push(result);
+        }
+
+        groups
+            .into_iter()
+            .map(|((target_id, date), runs)| {
+                let mut samples: BTreeMap<String, Vec<f64>> = BTreeMap::new();
+                for run in &runs {
+                    if let Some(obj) = run.metrics.as_object() {
+                        for (key, value) in obj {
+                            if let Some(v) = value.as_f64() {
+                                samples.entry(key.clone()).or_default().push(v);
+                            }
+                        }
+                    }
+                }
+
+                let metrics = samples
+                    .into_iter()
+                    .map(|(key, values)| {
+                        let aggregate = MetricAggregate {
+                            mean: values.iter().sum::<f64>() / values.len() as f64,
+                            min: values.iter().copied().fold(f64::INFINITY, f64::min),
+                            max: values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+                        };
+                        (key, aggregate)
+                    })
+                    .collect();
+
+                Self {
+                    target_id: target_id.to_string(),
+                    date,
+                    run_count: runs.len(),
+                    metrics,
+                }
+            })
+            .collect()
+    }
+
+    /// Converts the rollup to a result carrying each metric's daily mean,
+    /// timestamped at midnight UTC, so it can feed the trend report.
+    #[must_use]
+    pub fn to_mean_result(&self) -> BenchmarkResult {
+        let metrics: serde_json::Map<String, serde_json::Value> = self
+            .metrics
+            .iter()
+            .map(|(key, agg)| (key.clone(), serde_json::json!(agg.mean)))
+            .collect();
+
+        BenchmarkResult::with_timestamp(
+            &self.target_id,
+            serde_json::Value::Object(metrics),
+            self.date.and_time(NaiveTime::MIN).and_utc(),
+        )
+    }
+}
+
+/// Sentinel written in place of `f64::INFINITY`.
+pub const INFINITY_SENTINEL: &str = "Infinity";
+
+/// Sentinel written in place of `f64::NEG_INFINITY`.
+pub const NEG_INFINITY_SENTINEL: &str = "-Infinity";
+
+/// Sentinel written in place of `f64::NAN`.
+pub const NAN_SENTINEL: &str = "NaN";
+
+/// Custom metric flagging results with too few samples for tail latencies.
+const LOW_SAMPLE_WARNING: &str = "low_sample_warning";
+
+/// Metric flagging results whose `cv` exceeds the noise threshold.
+const NOISY: &str = "noisy";
+
+/// Custom metric grading how far the summary statistics can be trusted.
+const CONFIDENCE: &str = "confidence";
+
+/// Custom metric carrying the raw sample count of low-confidence results.
+const SAMPLE_COUNT: &str = "sample_count";
+
+/// Converts a float metric to JSON, replacing non-finite values with a sentinel.
+///
+/// `serde_json` encodes NaN and infinities as `null`, which is
+/// indistinguishable from a missing value. Non-finite floats are instead
+/// written as the strings [`INFINITY_SENTINEL`], [`NEG_INFINITY_SENTINEL`],
+/// or [`NAN_SENTINEL`], so consumers can tell a division by zero apart from
+/// a metric that was never recorded.
+#[must_use]
+pub fn metric_f64(value: f64) -> serde_json::Value {
+    if value.is_nan() {
+        NAN_SENTINEL.into()
+    } else if value == f64::INFINITY {
+        INFINITY_SENTINEL.into()
+    } else if value == f64::NEG_INFINITY {
+        NEG_INFINITY_SENTINEL.into()
+    } else {
+        value.into()
+    }
+}
+
+/// Values accepted by [`StandardMetrics::with_custom`].
+///
+/// Floats go through [`metric_f64`], so non-finite values become sentinels.
+pub trait IntoMetricValue {
+    /// Converts the value to its JSON metric representation.
+    fn into_metric_value(self) -> serde_json::Value;
+}
+
+impl IntoMetricValue for f64 {
+    fn into_metric_value(self) -> serde_json::Value {
+        metric_f64(self)
+    }
 }
 
+impl IntoMetricValue for f32 {
+    fn into_metric_value(self) -> serde_json::Value {
+        metric_f64(f64::from(self))
+    }
+}
+
+macro_rules! impl_into_metric_value {
+    ($($ty:ty),*) => {
+        $(impl IntoMetricValue for $ty {
+            fn into_metric_value(self) -> serde_json::Value {
+                self.into()
+            }
+        })*
+    };
+}
+
+impl_into_metric_value!(u64, u32, usize, i64, i32, bool, String, &str, serde_json::Value);
+
 /// Standard metrics commonly used in benchmarks.
+///
+/// Non-finite float metrics are serialized as sentinel strings rather than
+/// `null`; see [`metric_f64`].
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 pub struct StandardMetrics {
     /// Duration in milliseconds.
@@ -95,7 +549,36 @@ pub struct StandardMetrics {
     pub latency_p95_ms: Option<f64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub latency_p99_ms: Option<f64>,
-    /// Memory usage in bytes.
+    /// False when too few samples were taken for p95/p99 to be precise.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percentiles_exact: Option<bool>,
+    /// Fastest sample in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub min_ms: Option<f64>,
+    /// Slowest sample in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_ms: Option<f64>,
+    /// Sample standard deviation in milliseconds.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub stddev_ms: Option<f64>,
+    /// Coefficient of variation of the samples (stddev / mean).
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub cv: Option<f64>,
+    /// Untrimmed mean sample in milliseconds, set when outlier trimming
+    /// replaced `duration_ms` with the trimmed mean.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub mean_ms: Option<f64>,
+    /// Mean sample in milliseconds after outlier trimming.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub trimmed_mean_ms: Option<f64>,
+    /// Number of samples outlier trimming left out of `trimmed_mean_ms`.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub outliers_removed: Option<u64>,
+    /// Peak resident memory in bytes while the target ran.
+    ///
+    /// Filled in by the runner with the `memory-sampler` feature on Linux and
+    /// left unset elsewhere. Best-effort: it is process-wide RSS, so it also
+    /// counts memory held outside the target.
     #[serde(skip_serializing_if = "Option::is_none")]
     pub memory_bytes: Option<u64>,
     /// Number of iterations.
@@ -119,6 +602,56 @@ impl StandardMetrics {
         Self::default()
     }
 
+    /// Computes the sample-derived metrics from per-iteration latencies.
+    ///
+    /// `samples` are in milliseconds and `data_size` is the number of bytes
+    /// processed per iteration. Sets the mean `duration_ms`, ops and bytes
+    /// per second, percentiles, dispersion, `cv`, and sample count; non-finite
+    /// samples are ignored. Adapters that complete several operations per
+    /// iteration override `ops_per_second` afterwards.
+    #[must_use]
+    pub fn from_samples(samples: &[f64], data_size: u64, iterations: u64) -> Self {
+        Self::from_samples_with(samples, data_size, iterations, false)
+    }
+
+    /// Like [`from_samples`](Self::from_samples), optionally trimming outliers.
+    ///
+    /// With `trim_outliers`, the mean and the throughputs derived from it use
+    /// the [`trimmed_mean`] instead, and `mean_ms`, `trimmed_mean_ms`, and
+    /// `outliers_removed` are set. Percentiles and dispersion always cover
+    /// every sample.
+    #[must_use]
+    pub fn from_samples_with(
+        samples: &[f64],
+        data_size: u64,
+        iterations: u64,
+        trim_outliers: bool,
+    ) -> Self {
+        let mut sorted: Vec<f64> = samples.iter().copied().filter(|s| s.is_finite()).collect();
+        let mut metrics = Self::new().with_data_size(data_size).with_iterations(iterations);
+        if sorted.is_empty() {
+            return metrics.with_sample_count(0);
+        }
+
+        let mut mean_ms = sorted.iter().sum::<f64>() / sorted.len() as f64;
+        let (p50, p95, p99) = latency_percentiles(&mut sorted);
+        let (min_ms, max_ms, stddev_ms) = dispersion(&sorted);
+        metrics.cv = coefficient_of_variation(sorted.len(), mean_ms, stddev_ms);
+        if trim_outliers {
+            let (trimmed_ms, removed) = trimmed_mean(&sorted, OUTLIER_TRIM_FRACTION);
+            metrics = metrics.with_trimmed_mean(mean_ms, trimmed_ms, removed);
+            mean_ms = trimmed_ms;
+        }
+
+        metrics
+            .with_duration_ms(mean_ms)
+            .with_ops_per_second(1000.0 / mean_ms)
+            .with_bytes_per_second((data_size as f64 / mean_ms) * 1000.0)
+            .with_latencies(p50, p95, p99)
+            .with_dispersion(min_ms, max_ms, stddev_ms)
+            .with_sample_count(sorted.len())
+    }
+
     /// Sets the duration.
     #[must_use]
     pub fn with_duration_ms(mut self, duration_ms: f64) -> Self {
@@ -149,6 +682,69 @@ impl StandardMetrics {
         self
     }
 
+    /// Sets the sample spread: fastest, slowest, and standard deviation.
+    #[must_use]
+    pub fn with_dispersion(mut self, min_ms: f64, max_ms: f64, stddev_ms: f64) -> Self {
+        self.min_ms = Some(min_ms);
+        self.max_ms = Some(max_ms);
+        self.stddev_ms = Some(stddev_ms);
+        self
+    }
+
+    /// Sets the untrimmed and trimmed means and how many samples trimming removed.
+    #[must_use]
+    pub fn with_trimmed_mean(
+        mut self,
+        mean_ms: f64,
+        trimmed_mean_ms: f64,
+        removed: usize,
+    ) -> Self {
+        self.mean_ms = Some(mean_ms);
+        self.trimmed_mean_ms = Some(trimmed_mean_ms);
+        self.outliers_removed = Some(removed as u64);
+        self
+    }
+
+    /// Records the number of samples behind the latency percentiles.
+    ///
+    /// Sets `percentiles_exact`. With fewer than [`MIN_CONFIDENT_SAMPLES`]
+    /// samples it sets `confidence: "low"` and records `sample_count`, and
+    /// with fewer than [`MIN_TAIL_SAMPLES`] it also drops p95/p99, which
+    /// serialize as `null`, and sets the `low_sample_warning` custom metric.
+    /// Call after [`with_latencies`](Self::with_latencies).
+    #[must_use]
+    pub fn with_sample_count(mut self, samples: usize) -> Self {
+        self.percentiles_exact = Some(percentiles_exact(samples));
+        if samples < MIN_CONFIDENT_SAMPLES {
+            tracing::warn!(
+                samples,
+                "fewer than {MIN_CONFIDENT_SAMPLES} samples; statistics are low confidence"
+            );
+            self.custom.insert(SAMPLE_COUNT.to_string(), (samples as u64).into());
+            self.custom.insert(CONFIDENCE.to_string(), "low".into());
+        }
+        if samples < MIN_TAIL_SAMPLES {
+            self.latency_p95_ms = None;
+            self.latency_p99_ms = None;
+            self.custom.insert(LOW_SAMPLE_WARNING.to_string(), true.into());
+        }
+        self
+    }
+
+    /// Records whether the sample count was large enough for exact percentiles.
+    #[must_use]
+    pub fn with_percentiles_exact(mut self, exact: bool) -> Self {
+        self.percentiles_exact = Some(exact);
+        self
+    }
+
+    /// Sets the success rate (0.0 to 1.0).
+    #[must_use]
+    pub fn with_success_rate(mut self, rate: f64) -> Self {
+        self.success_rate = Some(rate);
+        self
+    }
+
     /// Sets the data size.
     #[must_use]
     pub fn with_data_size(mut self, bytes: u64) -> Self {
@@ -165,20 +761,52 @@ impl StandardMetrics {
 
     /// Adds a custom metric.
     #[must_use]
-    pub fn with_custom(mut self, key: impl Into<String>, value: impl Into<serde_json::Value>) -> Self {
-        self.custom.insert(key.into(), value.into());
+    pub fn with_custom(mut self, key: impl Into<String>, value: impl IntoMetricValue) -> Self {
+        self.custom.insert(key.into(), value.into_metric_value());
         self
     }
 
-    /// Converts to JSON value.
+    /// Converts to JSON value, writing non-finite floats as sentinels.
     pub fn to_json_value(&self) -> serde_json::Value {
-        serde_json::to_value(self).unwrap_or(serde_json::Value::Null)
+        let mut value = serde_json::to_value(self).unwrap_or(serde_json::Value::Null);
+
+        if let Some(map) = value.as_object_mut() {
+            let floats = [
+                ("duration_ms", self.duration_ms),
+                ("ops_per_second", self.ops_per_second),
+                ("bytes_per_second", self.bytes_per_second),
+                ("latency_p50_ms", self.latency_p50_ms),
+                ("latency_p95_ms", self.latency_p95_ms),
+                ("latency_p99_ms", self.latency_p99_ms),
+                ("min_ms", self.min_ms),
+                ("max_ms", self.max_ms),
+                ("stddev_ms", self.stddev_ms),
+                ("cv", self.cv),
+                ("mean_ms", self.mean_ms),
+                ("trimmed_mean_ms", self.trimmed_mean_ms),
+                ("success_rate", self.success_rate),
+            ];
+            for (key, v) in floats {
+                if let Some(v) = v.filter(|v| !v.is_finite()) {
+                    map.insert(key.to_string(), metric_f64(v));
+                }
+            }
+
+            // Tail percentiles withheld for too few samples are explicit nulls
+            if self.custom.contains_key(LOW_SAMPLE_WARNING) {
+                map.insert("latency_p95_ms".to_string(), serde_json::Value::Null);
+                map.insert("latency_p99_ms".to_string(), serde_json::Value::Null);
+            }
+        }
+
+        value
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::stats::DEFAULT_MAX_CV;
 
     #[test]
     fn test_benchmark_result_creation() {
@@ -209,6 +837,111 @@ mod tests {
         assert_eq!(json["custom_field"], "value");
     }
 
+    #[test]
+    fn test_standard_metrics_from_samples() {
+        let metrics = StandardMetrics::from_samples(&[4.0, 1.0, 3.0, 2.0, f64::NAN], 1000, 4);
+
+        assert_eq!(metrics.duration_ms, Some(2.5));
+        assert_eq!(metrics.ops_per_second, Some(400.0));
+        assert_eq!(metrics.bytes_per_second, Some(400_000.0));
+        assert_eq!(metrics.latency_p50_ms, Some(2.5));
+        assert_eq!((metrics.min_ms, metrics.max_ms), (Some(1.0), Some(4.0)));
+        assert_eq!(metrics.iterations, Some(4));
+        assert_eq!(metrics.percentiles_exact, Some(false));
+
+        let empty = StandardMetrics::from_samples(&[], 1000, 0);
+        assert!(empty.duration_ms.is_none());
+        assert_eq!(empty.to_json_value()["latency_p99_ms"], serde_json::Value::Null);
+    }
+
+    #[test]
+    fn test_mark_noisy() {
+        let steady = StandardMetrics::from_samples(&[10.0, 10.5, 9.5, 10.0], 1000, 4);
+        let jittery = StandardMetrics::from_samples(&[10.0, 30.0, 5.0, 10.0], 1000, 4);
+        assert!(steady.cv.unwrap() < DEFAULT_MAX_CV);
+
+        let mut result = BenchmarkResult::new("steady", steady.to_json_value());
+        assert!(!result.mark_noisy(DEFAULT_MAX_CV));
+        assert!(result.metrics.get("noisy").is_none());
+
+        let mut result = BenchmarkResult::new("jittery", jittery.to_json_value());
+        assert!(result.mark_noisy(DEFAULT_MAX_CV));
+        assert_eq!(result.metrics["noisy"], true);
+        assert!(!BenchmarkResult::failed("broken", "boom").mark_noisy(0.0));
+    }
+
+    #[test]
+    fn test_from_samples_trims_outliers_when_enabled() {
+        let mut samples = vec![2.0; 19];
+        samples.push(100.0);
+
+        let raw = StandardMetrics::from_samples(&samples, 1000, 20);
+        assert_eq!(raw.duration_ms, Some(6.9));
+        assert!(raw.trimmed_mean_ms.is_none());
+
+        let trimmed = StandardMetrics::from_samples_with(&samples, 1000, 20, true);
+        assert_eq!(trimmed.duration_ms, Some(2.0));
+        assert_eq!(trimmed.ops_per_second, Some(500.0));
+        assert_eq!(trimmed.mean_ms, Some(6.9));
+        assert_eq!(trimmed.trimmed_mean_ms, Some(2.0));
+        assert_eq!(trimmed.outliers_removed, Some(2));
+        assert_eq!(trimmed.max_ms, Some(100.0));
+    }
+
+    #[test]
+    fn test_non_finite_metrics_use_sentinels() {
+        let metrics = StandardMetrics::new()
+            .with_ops_per_second(f64::INFINITY)
+            .with_custom("throughput_bps", f64::NAN)
+            .with_custom("speedup", f64::NEG_INFINITY);
+
+        let result = BenchmarkResult::new("sentinel-test", metrics.to_json_value());
+        let parsed: serde_json::Value = serde_json::from_str(&result.to_json().unwrap()).unwrap();
+
+        assert_eq!(parsed["metrics"]["ops_per_second"], INFINITY_SENTINEL);
+        assert_eq!(parsed["metrics"]["throughput_bps"], NAN_SENTINEL);
+        assert_eq!(parsed["metrics"]["speedup"], NEG_INFINITY_SENTINEL);
+        // An unrecorded metric stays absent rather than colliding with a sentinel
+        assert!(parsed["metrics"].get("bytes_per_second").is_none());
+    }
+
+    #[test]
+    fn test_low_sample_count_withholds_tail_latencies() {
+        let few = StandardMetrics::new()
+            .with_latencies(1.0, 2.0, 3.0)
+            .with_sample_count(1)
+            .to_json_value();
+        assert_eq!(few["latency_p50_ms"], 1.0);
+        assert!(few["latency_p95_ms"].is_null());
+        assert!(few.as_object().unwrap().contains_key("latency_p99_ms"));
+        assert_eq!(few["low_sample_warning"], true);
+
+        let enough = StandardMetrics::new()
+            .with_latencies(1.0, 2.0, 3.0)
+            .with_sample_count(MIN_TAIL_SAMPLES)
+            .to_json_value();
+        assert_eq!(enough["latency_p99_ms"], 3.0);
+        assert!(enough.get("low_sample_warning").is_none());
+    }
+
+    #[test]
+    fn test_low_confidence_flag() {
+        let low = StandardMetrics::new()
+            .with_latencies(1.0, 2.0, 3.0)
+            .with_sample_count(MIN_CONFIDENT_SAMPLES - 1)
+            .to_json_value();
+        assert_eq!(low["confidence"], "low");
+        assert_eq!(low["sample_count"].as_u64().unwrap(), MIN_CONFIDENT_SAMPLES as u64 - 1);
+        assert_eq!(low["latency_p99_ms"], 3.0);
+
+        let confident = StandardMetrics::new()
+            .with_latencies(1.0, 2.0, 3.0)
+            .with_sample_count(MIN_CONFIDENT_SAMPLES)
+            .to_json_value();
+        assert!(confident.get("confidence").is_none());
+        assert!(confident.get("sample_count").is_none());
+    }
+
     #[test]
     fn test_json_roundtrip() {
         let result = BenchmarkResult::new(
@@ -222,4 +955,83 @@ mod tests {
         assert_eq!(parsed.target_id(), result.target_id());
         assert_eq!(parsed.metrics()["value"], 42);
     }
+
+    #[test]
+    fn test_result_digest() {
+        let result = BenchmarkResult::new(
+            "digest-test",
+            serde_json::json!({"ops_per_second": 1234.5, "nested": {"b": 1, "a": 2}}),
+        )
+        .with_digest();
+        assert!(result.verify_digest());
+
+        // The digest survives a write/read cycle
+        let parsed = BenchmarkResult::from_json(&result.to_json().unwrap()).unwrap();
+        assert!(parsed.verify_digest());
+
+        let mut tampered = parsed;
+        tampered.metrics["ops_per_second"] = serde_json::json!(9999.0);
+        assert!(!tampered.verify_digest());
+
+        assert!(!BenchmarkResult::new("unsealed", serde_json::json!({})).verify_digest());
+    }
+
+    #[test]
+    fn test_daily_rollup() {
+        let at = |d, h| chrono::TimeZone::with_ymd_and_hms(&Utc, 2024, 5, d, h, 0, 0).unwrap();
+        let results = vec![
+            BenchmarkResult::with_timestamp("a", serde_json::json!({"ops_per_second": 10.0, "algorithm": "x"}), at(1, 1)),
+            BenchmarkResult::with_timestamp("a", serde_json::json!({"ops_per_second": 30.0}), at(1, 23)),
+            BenchmarkResult::with_timestamp("a", serde_json::json!({"ops_per_second": 5.0}), at(2, 0)),
+        ];
+
+        let rollups = DailyRollup::aggregate(&results);
+
+        assert_eq!(rollups.len(), 2);
+        assert_eq!(rollups[0].run_count, 2);
+        assert_eq!(
+            rollups[0].metrics["ops_per_second"],
+            MetricAggregate { mean: 20.0, min: 10.0, max: 30.0 }
+        );
+        assert!(!rollups[0].metrics.contains_key("algorithm"));
+        assert_eq!(rollups[1].to_mean_result().timestamp, at(2, 0));
+    }
+
+    #[test]
+    fn test_run_status() {
+        let results = vec![
+            BenchmarkResult::new("ok", serde_json::json!({"duration_ms": 1.0})),
+            BenchmarkResult::new("broken", serde_json::json!({"error": "boom"})),
+        ];
+
+        let status = RunStatus::new(3, &results);
+        assert_eq!(status.succeeded, 1);
+        assert_eq!(status.failed, 1);
+        assert_eq!(status.skipped, 1);
+        assert!(!status.passed);
+
+        let clean = RunStatus::new(1, &results[..1]).with_regressions(2);
+        assert_eq!(clean.regressions, Some(2));
+        assert!(!clean.passed);
+
+        // Regressions never clear an earlier failure
+        assert!(!RunStatus::new(2, &results).with_regressions(0).passed);
+        assert!(RunStatus::new(1, &results[..1]).with_regressions(0).with_gate(true).passed);
+        assert!(!RunStatus::new(1, &results[..1]).with_gate(false).passed);
+    }
+
+    #[test]
+    fn test_failed_result() {
+        let ok = BenchmarkResult::new("ok", serde_json::json!({"duration_ms": 1.0}));
+        assert!(!ok.is_failed());
+        assert!(!ok.to_json().unwrap().contains("\"error\""));
+
+        let failed = BenchmarkResult::failed("broken", "Write failed: disk full");
+        assert!(failed.is_failed());
+        assert_eq!(failed.metrics["success_rate"], 0.0);
+
+        let parsed = BenchmarkResult::from_json(&failed.to_json().unwrap()).unwrap();
+        assert_eq!(parsed.error.as_deref(), Some("Write failed: disk full"));
+        assert_eq!(RunStatus::new(2, &[ok, parsed]).failed, 1);
+    }
 }
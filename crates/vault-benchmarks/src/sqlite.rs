@@ -0,0 +1,171 @@
+//! SQLite history of benchmark results.
+//!
+//! The JSON files written per run are convenient CI artifacts but awkward to
+//! query across runs. [`SqliteStore`] appends results to a single table so
+//! questions like "encryption-1mb throughput over the last 90 days" become
+//! one [`query`](SqliteStore::query) call.
+//!
+//! The table keeps the target id, the timestamp, the metrics as JSON, and the
+//! error message of failed results. Environment, commit, and digest are not
+//! stored.
+
+use crate::BenchmarkResult;
+use chrono::{DateTime, SecondsFormat, Utc};
+use sqlx::sqlite::{SqliteConnectOptions, SqlitePool, SqlitePoolOptions};
+use std::io;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Statements creating the results table and its index.
+///
+/// Timestamps are fixed-width RFC 3339 strings in UTC, so ordering and range
+/// comparisons on the text column match chronological order.
+const SCHEMA: [&str; 2] = [
+    "CREATE TABLE IF NOT EXISTS benchmark_results (
+        target_id TEXT NOT NULL,
+        timestamp TEXT NOT NULL,
+        metrics_json TEXT NOT NULL,
+        error TEXT
+    )",
+    "CREATE INDEX IF NOT EXISTS benchmark_results_target_time
+        ON benchmark_results (target_id, timestamp)",
+];
+
+/// Append-only store of benchmark results in a SQLite database.
+#[derive(Debug, Clone)]
+pub struct SqliteStore {
+    pool: SqlitePool,
+}
+
+impl SqliteStore {
+    /// Opens the database at `path`, creating the file and table if missing.
+    pub async fn open(path: impl AsRef<Path>) -> io::Result<Self> {
+        let options = SqliteConnectOptions::new()
+            .filename(path)
+            .create_if_missing(true);
+        Self::connect(SqlitePoolOptions::new(), options).await
+    }
+
+    /// Opens a private in-memory database, discarded when the store is dropped.
+    pub async fn in_memory() -> io::Result<Self> {
+        // Every connection to `:memory:` is a separate database, so keep one
+        let pool_options = SqlitePoolOptions::new().max_connections(1).idle_timeout(None);
+        let options = SqliteConnectOptions::from_str("sqlite::memory:").map_err(io::Error::other)?;
+        Self::connect(pool_options, options).await
+    }
+
+    async fn connect(
+        pool_options: SqlitePoolOptions,
+        options: SqliteConnectOptions,
+    ) -> io::Result<Self> {
+        let pool = pool_options.connect_with(options).await.map_err(io::Error::other)?;
+        for statement in SCHEMA {
+            sqlx::query(statement).execute(&pool).await.map_err(io::Error::other)?;
+        }
+        Ok(Self { pool })
+    }
+
+    /// Appends one result.
+    pub async fn insert(&self, result: &BenchmarkResult) -> io::Result<()> {
+        self.insert_all(std::slice::from_ref(result)).await
+    }
+
+    /// Appends every result in a single transaction.
+    pub async fn insert_all(&self, results: &[BenchmarkResult]) -> io::Result<()> {
+        let mut tx = self.pool.begin().await.map_err(io::Error::other)?;
+        for result in results {
+            sqlx::query(
+                "INSERT INTO benchmark_results (target_id, timestamp, metrics_json, error)
+                 VALUES (?, ?, ?, ?)",
+            )
+            .bind(&result.target_id)
+            .bind(timestamp_text(result.timestamp))
+            .bind(result.metrics.to_string())
+            .bind(result.error.as_deref())
+            .execute(&mut *tx)
+            .await
+            .map_err(io::Error::other)?;
+        }
+        tx.commit().await.map_err(io::Error::other)
+    }
+
+    /// Returns the results of `target_id` recorded at or after `since`,
+    /// oldest first.
+    pub async fn query(
+        &self,
+        target_id: &str,
+        since: DateTime<Utc>,
+    ) -> io::Result<Vec<BenchmarkResult>> {
+        let rows: Vec<(String, String, String, Option<String>)> = sqlx::query_as(
+            "SELECT target_id, timestamp, metrics_json, error FROM benchmark_results
+             WHERE target_id = ? AND timestamp >= ?
+             ORDER BY timestamp",
+        )
+        .bind(target_id)
+        .bind(timestamp_text(since))
+        .fetch_all(&self.pool)
+        .await
+        .map_err(io::Error::other)?;
+
+        rows.into_iter()
+            .map(|(target_id, timestamp, metrics_json, error)| {
+                let timestamp = DateTime::parse_from_rfc3339(&timestamp)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?
+                    .with_timezone(&Utc);
+                let metrics = serde_json::from_str(&metrics_json)
+                    .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+                Ok(BenchmarkResult {
+                    error,
+                    ..BenchmarkResult::with_timestamp(target_id, metrics, timestamp)
+                })
+            })
+            .collect()
+    }
+}
+
+/// Formats a timestamp as stored in the `timestamp` column.
+fn timestamp_text(timestamp: DateTime<Utc>) -> String {
+    timestamp.to_rfc3339_opts(SecondsFormat::Micros, true)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use chrono::Duration;
+
+    #[tokio::test]
+    async fn test_insert_and_query() {
+        let store = SqliteStore::in_memory().await.unwrap();
+        let now = Utc::now();
+        let at = |days_ago: i64, ops: f64| {
+            BenchmarkResult::with_timestamp(
+                "encryption-1mb",
+                serde_json::json!({ "ops_per_second": ops }),
+                now - Duration::days(days_ago),
+            )
+        };
+
+        store.insert(&at(120, 10.0)).await.unwrap();
+        store
+            .insert_all(&[
+                at(2, 30.0),
+                at(30, 20.0),
+                BenchmarkResult::failed("storage-read-1mb", "setup failed"),
+            ])
+            .await
+            .unwrap();
+
+        let recent = store.query("encryption-1mb", now - Duration::days(90)).await.unwrap();
+        let ops: Vec<&serde_json::Value> =
+            recent.iter().map(|r| &r.metrics["ops_per_second"]).collect();
+        assert_eq!(ops, [20.0, 30.0]);
+        assert_eq!(
+            recent[1].timestamp.timestamp_micros(),
+            (now - Duration::days(2)).timestamp_micros()
+        );
+
+        let failed = store.query("storage-read-1mb", now - Duration::days(1)).await.unwrap();
+        assert!(failed[0].is_failed());
+        assert_eq!(failed[0].error.as_deref(), Some("setup failed"));
+    }
+}
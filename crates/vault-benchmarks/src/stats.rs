@@ -0,0 +1,201 @@
+//! Shared statistics helpers for benchmark adapters.
+
+/// Minimum sample count for which p99 is distinguishable from the maximum.
+///
+/// With fewer samples the 99th percentile falls between the two largest
+/// samples, so the reported tail latency is effectively the max.
+pub const MIN_EXACT_PERCENTILE_SAMPLES: usize = 100;
+
+/// Minimum sample count for which p95/p99 are reported at all.
+///
+/// Below this the tail percentiles would just repeat the maximum, so they
+/// are emitted as `null` and the result carries `low_sample_warning: true`.
+pub const MIN_TAIL_SAMPLES: usize = 4;
+
+/// Minimum sample count for which summary statistics are trusted.
+///
+/// Below this, percentiles and stddev are dominated by noise; results are
+/// tagged `confidence: "low"` and carry the raw `sample_count`.
+pub const MIN_CONFIDENT_SAMPLES: usize = 20;
+
+/// Default coefficient of variation above which a result is flagged noisy.
+pub const DEFAULT_MAX_CV: f64 = 0.10;
+
+/// Returns the coefficient of variation, `stddev / mean`.
+///
+/// `None` for fewer than two samples or a non-positive mean, where the ratio
+/// says nothing about run-to-run noise.
+#[must_use]
+pub fn coefficient_of_variation(sample_count: usize, mean: f64, stddev: f64) -> Option<f64> {
+    (sample_count >= 2 && mean > 0.0).then(|| stddev / mean)
+}
+
+/// Fraction of samples dropped from each end by [`trimmed_mean`] when
+/// outlier trimming is enabled.
+pub const OUTLIER_TRIM_FRACTION: f64 = 0.05;
+
+/// Returns the mean of an ascending-sorted slice after dropping `fraction`
+/// of the samples from each end, with the number of samples dropped.
+///
+/// The count dropped per end is rounded down, so short runs (fewer than 20
+/// samples at 5%) keep every sample. Returns 0.0 for an empty slice.
+#[must_use]
+pub fn trimmed_mean(sorted: &[f64], fraction: f64) -> (f64, usize) {
+    let per_end = (sorted.len() as f64 * fraction.clamp(0.0, 0.5)) as usize;
+    let kept = &sorted[per_end..sorted.len() - per_end];
+    if kept.is_empty() {
+        return (0.0, sorted.len());
+    }
+    (kept.iter().sum::<f64>() / kept.len() as f64, 2 * per_end)
+}
+
+/// Returns the `q`-quantile (0.0 to 1.0) of an ascending-sorted slice.
+///
+/// Interpolates linearly between the two closest ranks, placing the
+/// quantile at position `q * (n - 1)`. Returns 0.0 for an empty slice.
+#[must_use]
+pub fn percentile(sorted: &[f64], q: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+
+    let position = q.clamp(0.0, 1.0) * (sorted.len() - 1) as f64;
+    let lower = position.floor() as usize;
+    let upper = position.ceil() as usize;
+    let fraction = position - lower as f64;
+
+    sorted[lower] + (sorted[upper] - sorted[lower]) * fraction
+}
+
+/// Removes NaN and infinite samples, returning how many were dropped.
+///
+/// A degenerate clock reading or a division by zero upstream should cost one
+/// sample, not poison the average or abort the run.
+pub fn drop_non_finite(samples: &mut Vec<f64>) -> usize {
+    let before = samples.len();
+    samples.retain(|s| s.is_finite());
+    before - samples.len()
+}
+
+/// Sorts samples in place and returns the (p50, p95, p99) latencies.
+///
+/// Sorting uses [`f64::total_cmp`], so a stray NaN cannot panic; callers
+/// should still [`drop_non_finite`] first to keep it out of the results.
+pub fn latency_percentiles(samples: &mut [f64]) -> (f64, f64, f64) {
+    samples.sort_by(|a, b| a.total_cmp(b));
+
+    (
+        percentile(samples, 0.50),
+        percentile(samples, 0.95),
+        percentile(samples, 0.99),
+    )
+}
+
+/// Returns the (min, max, sample standard deviation) of the samples.
+///
+/// All three are 0.0 for an empty slice; the standard deviation is 0.0 for
+/// fewer than two samples.
+#[must_use]
+pub fn dispersion(samples: &[f64]) -> (f64, f64, f64) {
+    if samples.is_empty() {
+        return (0.0, 0.0, 0.0);
+    }
+
+    let min = samples.iter().copied().fold(f64::INFINITY, f64::min);
+    let max = samples.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+    if samples.len() < 2 {
+        return (min, max, 0.0);
+    }
+
+    let n = samples.len() as f64;
+    let mean = samples.iter().sum::<f64>() / n;
+    let variance = samples.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (min, max, variance.sqrt())
+}
+
+/// Returns true if `sample_count` samples are enough for precise p95/p99.
+#[must_use]
+pub fn percentiles_exact(sample_count: usize) -> bool {
+    sample_count >= MIN_EXACT_PERCENTILE_SAMPLES
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_close(actual: f64, expected: f64) {
+        assert!((actual - expected).abs() < 1e-9, "{actual} != {expected}");
+    }
+
+    #[test]
+    fn test_percentile_interpolates() {
+        assert_eq!(percentile(&[], 0.5), 0.0);
+
+        let one = [7.0];
+        assert_eq!(latency_percentiles(&mut one.clone()), (7.0, 7.0, 7.0));
+
+        let mut two = [3.0, 1.0];
+        let (p50, p95, p99) = latency_percentiles(&mut two);
+        assert_close(p50, 2.0);
+        assert_close(p95, 2.9);
+        assert_close(p99, 2.98);
+
+        let mut ten: Vec<f64> = (1..=10).rev().map(f64::from).collect();
+        let (p50, p95, p99) = latency_percentiles(&mut ten);
+        assert_close(p50, 5.5);
+        assert_close(p95, 9.55);
+        assert_close(p99, 9.91);
+
+        let mut thousand: Vec<f64> = (1..=1000).map(f64::from).collect();
+        let (p50, p95, p99) = latency_percentiles(&mut thousand);
+        assert_close(p50, 500.5);
+        assert_close(p95, 950.05);
+        assert_close(p99, 990.01);
+
+        assert!(!percentiles_exact(10));
+        assert!(percentiles_exact(100));
+    }
+
+    #[test]
+    fn test_coefficient_of_variation() {
+        assert_eq!(coefficient_of_variation(1, 2.0, 0.0), None);
+        assert_eq!(coefficient_of_variation(5, 0.0, 1.0), None);
+        assert_close(coefficient_of_variation(5, 4.0, 1.0).unwrap(), 0.25);
+    }
+
+    #[test]
+    fn test_trimmed_mean() {
+        assert_eq!(trimmed_mean(&[], OUTLIER_TRIM_FRACTION), (0.0, 0));
+        assert_eq!(trimmed_mean(&[1.0, 2.0, 3.0], OUTLIER_TRIM_FRACTION), (2.0, 0));
+
+        // One 1000 ms hiccup among 1 ms samples no longer dominates the mean
+        let mut samples = vec![1.0; 39];
+        samples.push(1000.0);
+        let (mean, removed) = trimmed_mean(&samples, OUTLIER_TRIM_FRACTION);
+        assert_close(mean, 1.0);
+        assert_eq!(removed, 4);
+    }
+
+    #[test]
+    fn test_dispersion() {
+        assert_eq!(dispersion(&[]), (0.0, 0.0, 0.0));
+        assert_eq!(dispersion(&[3.0]), (3.0, 3.0, 0.0));
+
+        let (min, max, stddev) = dispersion(&[2.0, 4.0, 4.0, 4.0, 5.0, 5.0, 7.0, 9.0]);
+        assert_eq!((min, max), (2.0, 9.0));
+        assert_close(stddev, (32.0f64 / 7.0).sqrt());
+    }
+
+    #[test]
+    fn test_non_finite_samples_are_dropped() {
+        let mut samples = vec![2.0, f64::NAN, 1.0, f64::INFINITY, 3.0];
+
+        // Sorting alone must not panic on NaN
+        let _ = latency_percentiles(&mut samples.clone());
+
+        assert_eq!(drop_non_finite(&mut samples), 2);
+        assert_eq!(samples.len(), 3);
+        let (p50, _, _) = latency_percentiles(&mut samples);
+        assert_close(p50, 2.0);
+    }
+}
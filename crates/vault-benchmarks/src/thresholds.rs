@@ -0,0 +1,242 @@
+//! Absolute performance limits per target.
+//!
+//! Where [`crate::regression`] compares a run with a stored baseline,
+//! thresholds are fixed bounds declared up front: a minimum throughput or a
+//! maximum latency a target has to meet on every run.
+//!
+//! Limits live in the thresholds file under one `[limits."<target-id>"]`
+//! table per target:
+//!
+//! ```toml
+//! [limits."storage-read-1mb"]
+//! min_ops_per_second = 500.0
+//! max_latency_p99_ms = 4.0
+//! ```
+
+use crate::BenchmarkResult;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+/// Which side of a limit a metric must stay on.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Bound {
+    /// The metric must not fall below the limit.
+    Min,
+    /// The metric must not exceed the limit.
+    Max,
+}
+
+/// Limits for a single target. Unset limits are not checked.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct TargetThresholds {
+    /// Minimum `ops_per_second`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_ops_per_second: Option<f64>,
+    /// Minimum `bytes_per_second`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub min_bytes_per_second: Option<f64>,
+    /// Maximum average `duration_ms`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_duration_ms: Option<f64>,
+    /// Maximum `latency_p95_ms`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_latency_p95_ms: Option<f64>,
+    /// Maximum `latency_p99_ms`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_latency_p99_ms: Option<f64>,
+}
+
+impl TargetThresholds {
+    /// Returns each limit with the metric it applies to and its bound.
+    fn limits(&self) -> [(&'static str, Bound, Option<f64>); 5] {
+        [
+            ("ops_per_second", Bound::Min, self.min_ops_per_second),
+            ("bytes_per_second", Bound::Min, self.min_bytes_per_second),
+            ("duration_ms", Bound::Max, self.max_duration_ms),
+            ("latency_p95_ms", Bound::Max, self.max_latency_p95_ms),
+            ("latency_p99_ms", Bound::Max, self.max_latency_p99_ms),
+        ]
+    }
+}
+
+/// A result metric that fell outside its configured limit.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Violation {
+    /// Target the metric belongs to.
+    pub target_id: String,
+    /// Metric name.
+    pub metric: String,
+    /// Which side of the limit the metric had to stay on.
+    pub bound: Bound,
+    /// Configured limit.
+    pub limit: f64,
+    /// Value in the run.
+    pub actual: f64,
+}
+
+impl Violation {
+    /// Signed change from the limit to the actual value, in percent.
+    #[must_use]
+    pub fn percent_change(&self) -> f64 {
+        (self.actual - self.limit) / self.limit.abs() * 100.0
+    }
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let side = match self.bound {
+            Bound::Min => "below the minimum",
+            Bound::Max => "above the maximum",
+        };
+        write!(
+            f,
+            "{}: {} {:.2} is {} of {:.2}",
+            self.target_id, self.metric, self.actual, side, self.limit
+        )
+    }
+}
+
+/// Per-target limits keyed by target id.
+///
+/// Targets without an entry are not checked.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct Thresholds {
+    /// Limits keyed by target id.
+    #[serde(default)]
+    pub limits: BTreeMap<String, TargetThresholds>,
+}
+
+impl Thresholds {
+    /// Creates an empty set of thresholds that every result passes.
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Parses thresholds from TOML text.
+    ///
+    /// Keys other than `limits` are ignored, so the file can be shared with
+    /// the baseline regression settings.
+    pub fn from_toml(content: &str) -> io::Result<Self> {
+        toml::from_str(content).map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))
+    }
+
+    /// Loads thresholds from a TOML file.
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::from_toml(&std::fs::read_to_string(path)?)
+    }
+
+    /// Sets the limits for one target.
+    #[must_use]
+    pub fn with_target(mut self, target_id: impl Into<String>, limits: TargetThresholds) -> Self {
+        self.limits.insert(target_id.into(), limits);
+        self
+    }
+
+    /// Returns true if no target has limits.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.limits.is_empty()
+    }
+
+    /// Checks every result against its target's limits.
+    ///
+    /// Failed results and metrics missing from a result are skipped, so a
+    /// limit only fails when a measured value is actually outside it.
+    #[must_use]
+    pub fn evaluate(&self, results: &[BenchmarkResult]) -> Vec<Violation> {
+        let mut violations = Vec::new();
+
+        for result in results.iter().filter(|r| !r.is_failed()) {
+            let Some(limits) = self.limits.get(&result.target_id) else {
+                continue;
+            };
+
+            for (metric, bound, limit) in limits.limits() {
+                let (Some(limit), Some(actual)) = (limit, result.metrics[metric].as_f64()) else {
+                    continue;
+                };
+                let breached = match bound {
+                    Bound::Min => actual < limit,
+                    Bound::Max => actual > limit,
+                };
+                if breached {
+                    violations.push(Violation {
+                        target_id: result.target_id.clone(),
+                        metric: metric.to_string(),
+                        bound,
+                        limit,
+                        actual,
+                    });
+                }
+            }
+        }
+
+        violations
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    #[test]
+    fn test_evaluate_thresholds() {
+        let thresholds = Thresholds::new().with_target(
+            "hashing-blake3-1mb",
+            TargetThresholds {
+                min_ops_per_second: Some(1000.0),
+                max_latency_p99_ms: Some(5.0),
+                max_duration_ms: Some(1.0),
+                ..Default::default()
+            },
+        );
+        let results = vec![
+            BenchmarkResult::new(
+                "hashing-blake3-1mb",
+                json!({"ops_per_second": 800.0, "latency_p99_ms": 4.0}),
+            ),
+            BenchmarkResult::new("storage-read-1mb", json!({"ops_per_second": 1.0})),
+        ];
+
+        let violations = thresholds.evaluate(&results);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].metric, "ops_per_second");
+        assert_eq!(violations[0].bound, Bound::Min);
+        assert_eq!(
+            violations[0].to_string(),
+            "hashing-blake3-1mb: ops_per_second 800.00 is below the minimum of 1000.00"
+        );
+        assert!((violations[0].percent_change() + 20.0).abs() < 1e-9);
+
+        assert!(Thresholds::new().evaluate(&results).is_empty());
+    }
+
+    #[test]
+    fn test_thresholds_from_toml() {
+        let thresholds = Thresholds::from_toml(
+            r#"
+            default_regression_percent = 10.0
+
+            [limits."storage-read-1mb"]
+            min_ops_per_second = 500.0
+            max_latency_p99_ms = 4.0
+            "#,
+        )
+        .unwrap();
+
+        let limits = &thresholds.limits["storage-read-1mb"];
+        assert_eq!(limits.min_ops_per_second, Some(500.0));
+        assert_eq!(limits.max_latency_p99_ms, Some(4.0));
+        assert_eq!(limits.max_duration_ms, None);
+
+        let typo = Thresholds::from_toml("[limits.x]\nmin_ops_per_sec = 1.0\n").unwrap_err();
+        assert_eq!(typo.kind(), io::ErrorKind::InvalidData);
+    }
+}
@@ -4,7 +4,9 @@
 
 use clap::{Args, Subcommand};
 
-use crate::output::{CliError, OutputFormat};
+use crate::output::{
+    export_unsupported, junit_unsupported, prometheus_unsupported, CliError, OutputFormat,
+};
 
 /// Benchmark commands.
 #[derive(Args)]
@@ -20,8 +22,18 @@ pub enum BenchmarkSubcommand {
     Run(RunBenchmarkCommand),
     /// List available benchmarks
     List(ListBenchmarksCommand),
+    /// Show details and the latest result of one benchmark
+    Info(InfoBenchmarkCommand),
     /// Show benchmark results
     Results(ResultsCommand),
+    /// Scaffold the output directories and a thresholds file
+    Init(InitBenchmarkCommand),
+    /// Compare results against a baseline and fail on regressions
+    Compare(CompareBenchmarkCommand),
+    /// Delete saved raw results
+    Clean(CleanBenchmarkCommand),
+    /// Convert saved raw results to another format without re-running
+    Export(ExportBenchmarkCommand),
 }
 
 /// Run benchmark command.
@@ -43,9 +55,93 @@ pub struct RunBenchmarkCommand {
     #[arg(long)]
     pub output_dir: Option<String>,
 
+    /// SQLite database to append results to, in addition to the JSON files
+    #[cfg(feature = "sqlite")]
+    #[arg(long, value_name = "PATH")]
+    pub db: Option<String>,
+
     /// Number of iterations for each benchmark
     #[arg(long)]
     pub iterations: Option<usize>,
+
+    /// Seed for generated test data; runs with the same seed use byte-identical
+    /// inputs (default: a fixed seed)
+    #[arg(long)]
+    pub seed: Option<u64>,
+
+    /// Payload contents for encryption, hashing, and storage targets:
+    /// sequential, zeros, random, or text (default: random for encryption,
+    /// sequential otherwise)
+    #[arg(long)]
+    pub data_pattern: Option<vault_benchmarks::DataPattern>,
+
+    /// Record setup/run/teardown timestamps as tracing spans and in each result
+    #[arg(long)]
+    pub trace_lifecycle: bool,
+
+    /// Report each target's mean with the top and bottom 5% of samples dropped
+    #[arg(long)]
+    pub trim_outliers: bool,
+
+    /// Maximum number of targets run at once; concurrent targets skew each
+    /// other's numbers, so use this for quick smoke runs only
+    #[arg(long, default_value_t = 1)]
+    pub parallelism: usize,
+
+    /// Calibrate each target's iteration count to roughly fill this many
+    /// seconds, overriding --iterations
+    #[arg(long, value_name = "SECONDS", value_parser = parse_seconds)]
+    pub target_duration: Option<std::time::Duration>,
+
+    /// Seal each result with a BLAKE3 `result_digest` for tamper evidence
+    #[arg(long)]
+    pub digest: bool,
+
+    /// Print the resolved target list in run order and exit without running
+    #[arg(long)]
+    pub list_only: bool,
+
+    /// Include bar charts of each target's key metric in summary.md
+    #[arg(long)]
+    pub charts: bool,
+
+    /// TOML file overriding per-target size, iterations, and warmup
+    /// (default: benchmarks/targets.toml, if present)
+    #[arg(long)]
+    pub targets_config: Option<String>,
+
+    /// Re-read each saved result file and fail if it does not round-trip
+    #[arg(long)]
+    pub verify_writes: bool,
+
+    /// Run the `--prefix` target family once per payload size (e.g. "1kb,1mb,10mb").
+    /// Supported families: encryption, hashing-blake3, hashing-sha256,
+    /// hashing-sha512, checksum-verification, storage-write, storage-read, content-addressing
+    #[arg(long, value_delimiter = ',', requires = "prefix", conflicts_with = "target")]
+    pub sizes: Option<Vec<String>>,
+
+    /// TOML file of per-target throughput and latency limits
+    /// (default: benchmarks/thresholds.toml, if present)
+    #[arg(long)]
+    pub thresholds: Option<String>,
+
+    /// Exit with an error when any result breaches its thresholds
+    #[arg(long)]
+    pub fail_on_regression: bool,
+
+    /// Coefficient of variation (stddev / mean) above which a result is flagged noisy
+    #[arg(long, default_value_t = vault_benchmarks::DEFAULT_MAX_CV)]
+    pub max_cv: f64,
+
+    /// Exit with an error when any result is flagged noisy
+    #[arg(long)]
+    pub fail_on_noisy: bool,
+
+    /// Print GitHub Actions annotations for threshold violations to stdout
+    /// (implied when GITHUB_ACTIONS=true, unless the output format is
+    /// machine-readable)
+    #[arg(long)]
+    pub github_annotations: bool,
 }
 
 /// List benchmarks command.
@@ -56,6 +152,17 @@ pub struct ListBenchmarksCommand {
     pub prefix: Option<String>,
 }
 
+/// Benchmark info command.
+#[derive(Args)]
+pub struct InfoBenchmarkCommand {
+    /// Benchmark target id (e.g., "encryption-10mb")
+    pub id: String,
+
+    /// Path to results directory (default: benchmarks/output)
+    #[arg(long)]
+    pub path: Option<String>,
+}
+
 /// Show results command.
 #[derive(Args)]
 pub struct ResultsCommand {
@@ -70,65 +177,336 @@ pub struct ResultsCommand {
     /// Show detailed metrics
     #[arg(long, short)]
     pub detailed: bool,
+
+    /// Show a trend of this metric across runs (e.g., "ops_per_second")
+    #[arg(long, value_name = "METRIC")]
+    pub trend: Option<String>,
+
+    /// Restrict the trend to a single target
+    #[arg(long, short, requires = "trend")]
+    pub target: Option<String>,
+
+    /// Number of most recent runs to include in the trend
+    #[arg(long, default_value = "30", requires = "trend")]
+    pub last: usize,
+
+    /// Plot the trend from daily rollups (built if missing) instead of raw runs
+    #[arg(long, requires = "trend")]
+    pub daily: bool,
+
+    /// Rebuild the daily rollup file from raw results and exit
+    #[arg(long, conflicts_with = "trend")]
+    pub rollup: bool,
+}
+
+/// Compare benchmarks command.
+#[derive(Args)]
+pub struct CompareBenchmarkCommand {
+    /// Results directory holding the baseline run
+    #[arg(long)]
+    pub baseline: String,
+
+    /// Results directory holding the current run (default: benchmarks/output)
+    #[arg(long)]
+    pub current: Option<String>,
+
+    /// Percentage by which a metric may degrade before it counts as a regression
+    #[arg(long, default_value_t = vault_benchmarks::DEFAULT_REGRESSION_PERCENT)]
+    pub threshold: f64,
+
+    /// Print GitHub Actions annotations for regressions to stdout
+    /// (implied when GITHUB_ACTIONS=true, unless the output format is
+    /// machine-readable)
+    #[arg(long)]
+    pub github_annotations: bool,
 }
 
+/// Init benchmarks command.
+#[derive(Args)]
+pub struct InitBenchmarkCommand {
+    /// Output directory to create (default: benchmarks/output)
+    #[arg(long)]
+    pub output_dir: Option<String>,
+
+    /// Path of the thresholds file to generate
+    #[arg(long, default_value = "benchmarks/thresholds.toml")]
+    pub thresholds: String,
+
+    /// Overwrite an existing thresholds file
+    #[arg(long)]
+    pub force: bool,
+}
+
+/// Clean benchmarks command.
+#[derive(Args)]
+pub struct CleanBenchmarkCommand {
+    /// Path to results directory (default: benchmarks/output)
+    #[arg(long)]
+    pub path: Option<String>,
+
+    /// Confirm deleting more than a handful of result files
+    #[arg(long, short)]
+    pub yes: bool,
+}
+
+/// Export benchmarks command.
+#[derive(Args)]
+pub struct ExportBenchmarkCommand {
+    /// Directory of raw result files to read (default: benchmarks/output/raw)
+    #[arg(long)]
+    pub path: Option<String>,
+
+    /// File to write the export to (default: stdout)
+    #[arg(long, short)]
+    pub out: Option<String>,
+}
+
+/// Serializes `value` as pretty JSON, compact JSON, or (for any other
+/// format) YAML, ending with a newline.
+fn to_document<T: serde::Serialize + ?Sized>(
+    value: &T,
+    format: OutputFormat,
+) -> Result<String, CliError> {
+    let document = match format {
+        OutputFormat::Json => serde_json::to_string_pretty(value).map(|json| json + "\n"),
+        OutputFormat::JsonCompact => serde_json::to_string(value).map(|json| json + "\n"),
+        _ => return serde_yaml::to_string(value).map_err(|e| CliError::output(e.to_string())),
+    };
+    document.map_err(|e| CliError::output(e.to_string()))
+}
+
+/// Number of result files `clean` deletes without `--yes`.
+const CLEAN_CONFIRM_THRESHOLD: usize = 10;
+
 impl BenchmarkCommands {
     /// Runs the benchmark command.
     pub async fn run(self, format: OutputFormat) -> Result<(), CliError> {
         match self.command {
             BenchmarkSubcommand::Run(cmd) => cmd.run(format).await,
             BenchmarkSubcommand::List(cmd) => cmd.run(format).await,
+            BenchmarkSubcommand::Info(cmd) => cmd.run(format).await,
             BenchmarkSubcommand::Results(cmd) => cmd.run(format).await,
+            BenchmarkSubcommand::Init(cmd) => cmd.run(format).await,
+            BenchmarkSubcommand::Compare(cmd) => cmd.run(format).await,
+            BenchmarkSubcommand::Clean(cmd) => cmd.run(format).await,
+            BenchmarkSubcommand::Export(cmd) => cmd.run(format).await,
         }
     }
 }
 
 impl RunBenchmarkCommand {
-    /// Runs benchmarks.
-    pub async fn run(self, format: OutputFormat) -> Result<(), CliError> {
-        use vault_benchmarks::{
-            run_all_benchmarks, run_benchmark_by_id, run_benchmarks_by_prefix,
-            BenchmarkIO, generate_summary, print_results,
+    /// Builds the run-wide options from the command-line flags.
+    fn run_options(&self) -> vault_benchmarks::RunOptions {
+        vault_benchmarks::RunOptions {
+            iterations: self.iterations,
+            parallelism: self.parallelism,
+            target_duration: self.target_duration,
+            trim_outliers: self.trim_outliers,
+            trace_lifecycle: self.trace_lifecycle,
+            max_cv: self.max_cv,
+            seed: self.seed,
+            data_pattern: self.data_pattern,
+            ..Default::default()
+        }
+    }
+
+    /// Loads the target spec table, applying any configured overrides.
+    ///
+    /// Command-line options take precedence over the targets config file.
+    fn load_specs(&self) -> Result<Vec<vault_benchmarks::TargetSpec>, CliError> {
+        use std::path::Path;
+        use vault_benchmarks::{default_specs, TargetOverrides, DEFAULT_TARGETS_FILE};
+
+        let mut specs = default_specs();
+
+        let path = match &self.targets_config {
+            Some(path) => Some(path.as_str()),
+            None => Path::new(DEFAULT_TARGETS_FILE)
+                .exists()
+                .then_some(DEFAULT_TARGETS_FILE),
         };
 
-        println!("Running benchmarks...\n");
+        if let Some(path) = path {
+            TargetOverrides::load(path)
+                .and_then(|overrides| overrides.apply(&mut specs))
+                .map_err(|e| CliError::validation(format!("Invalid targets config '{}': {}", path, e)))?;
+        }
+
+        self.run_options().apply(&mut specs);
 
-        let results = if let Some(target) = &self.target {
+        Ok(specs)
+    }
+
+    /// Loads the per-target limits, if a thresholds file is configured.
+    ///
+    /// Without `--thresholds`, the default file is used only when it exists,
+    /// so a missing file means no gating.
+    fn load_thresholds(&self) -> Result<vault_benchmarks::Thresholds, CliError> {
+        use std::path::Path;
+        use vault_benchmarks::{Thresholds, DEFAULT_THRESHOLDS_FILE};
+
+        let path = match &self.thresholds {
+            Some(path) => path.as_str(),
+            None if Path::new(DEFAULT_THRESHOLDS_FILE).exists() => DEFAULT_THRESHOLDS_FILE,
+            None => return Ok(Thresholds::new()),
+        };
+
+        Thresholds::load(path)
+            .map_err(|e| CliError::validation(format!("Invalid thresholds file '{}': {}", path, e)))
+    }
+
+    /// Resolves the selection flags to the ordered list of target specs to run.
+    fn select_specs(&self) -> Result<Vec<vault_benchmarks::TargetSpec>, CliError> {
+        use vault_benchmarks::{parse_size, size_sweep_specs};
+
+        let specs = self.load_specs()?;
+
+        let selected: Vec<_> = if let (Some(sizes), Some(family)) = (&self.sizes, &self.prefix) {
+            // Sweep one target family across the requested sizes
+            let sizes = sizes
+                .iter()
+                .map(|s| {
+                    parse_size(s).ok_or_else(|| CliError::validation(format!("Invalid size '{}'", s)))
+                })
+                .collect::<Result<Vec<_>, _>>()?;
+            size_sweep_specs(&specs, family, &sizes)
+                .map_err(|e| CliError::validation(e.to_string()))?
+        } else if let Some(target) = &self.target {
             // Run specific benchmark
-            match run_benchmark_by_id(target).await {
-                Some(result) => vec![result],
-                None => {
-                    return Err(CliError::validation(format!(
-                        "Benchmark target '{}' not found",
-                        target
-                    )));
-                }
+            let selected: Vec<_> = specs.into_iter().filter(|s| &s.id == target).collect();
+            if selected.is_empty() {
+                return Err(CliError::validation(format!(
+                    "Benchmark target '{}' not found",
+                    target
+                )));
             }
+            selected
         } else if let Some(prefix) = &self.prefix {
             // Run benchmarks by prefix
-            let results = run_benchmarks_by_prefix(prefix).await;
-            if results.is_empty() {
+            let selected: Vec<_> = specs.into_iter().filter(|s| s.id.starts_with(prefix.as_str())).collect();
+            if selected.is_empty() {
                 return Err(CliError::validation(format!(
                     "No benchmarks found with prefix '{}'",
                     prefix
                 )));
             }
-            results
+            selected
         } else {
             // Run all benchmarks
-            run_all_benchmarks().await
+            specs
         };
 
+        Ok(selected)
+    }
+
+    /// Runs benchmarks.
+    pub async fn run(self, format: OutputFormat) -> Result<(), CliError> {
+        use vault_benchmarks::{
+            generate_summary, generate_summary_with_charts, order_by_dependencies, print_results,
+            run_specs, targets_from_specs, BenchmarkIO, RunStatus,
+        };
+
+        let specs = self.select_specs()?;
+        let thresholds = self.load_thresholds()?;
+        let targets = order_by_dependencies(targets_from_specs(&specs))
+            .map_err(|e| CliError::validation(e.to_string()))?;
+
+        if self.list_only {
+            return print_target_list(&targets, format);
+        }
+
+        // Keep stdout a valid document when another program parses it
+        let quiet = format.is_machine_readable();
+        if !quiet {
+            println!("Running benchmarks...\n");
+        }
+
+        let total_targets = targets.len();
+        let options = self.run_options().with_thresholds(thresholds);
+        let report = run_specs(specs.clone(), &options).await;
+        let violations = report.violations;
+        let mut results = report.results;
+
+        if let (Some(family), true) = (&self.prefix, self.sizes.is_some()) {
+            for result in &mut results {
+                // Tag sweep results so the scaling dataset can be regrouped later
+                let size = specs.iter().find(|s| s.id == result.target_id).map(|s| s.size);
+                result.metrics["sweep_family"] = serde_json::json!(family);
+                result.metrics["sweep_size_bytes"] = serde_json::json!(size);
+            }
+        }
+
+        // The runner flags noisy results before they are sealed below
+        let noisy: Vec<String> = results
+            .iter()
+            .filter(|r| r.is_noisy())
+            .map(|r| r.target_id.clone())
+            .collect();
+
+        if self.digest {
+            results = results.into_iter().map(|r| r.with_digest()).collect();
+        }
+
         // Display results
         match format {
-            OutputFormat::Json => {
-                let json = serde_json::to_string_pretty(&results)
-                    .map_err(|e| CliError::serialization(e.to_string()))?;
-                println!("{}", json);
+            OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Yaml => {
+                print!("{}", to_document(&results, format)?);
             }
             OutputFormat::Table | OutputFormat::Plain => {
                 print_results(&results);
             }
+            OutputFormat::Prometheus => {
+                print!("{}", vault_benchmarks::to_exposition(&results));
+            }
+            OutputFormat::Junit => {
+                print!("{}", vault_benchmarks::junit::to_xml(&results, &options.thresholds));
+            }
+            OutputFormat::Csv => {
+                print!("{}", vault_benchmarks::to_csv(&results));
+            }
+            OutputFormat::Html => {
+                print!("{}", vault_benchmarks::to_html(&results));
+            }
+        }
+
+        if !violations.is_empty() {
+            let mut report = String::from("\nThreshold violations:\n");
+            for violation in &violations {
+                report.push_str(&format!("  {}\n", violation));
+            }
+            // Keep machine-readable stdout intact by reporting on stderr
+            if quiet {
+                eprint!("{}", report);
+            } else {
+                print!("{}", report);
+            }
+        }
+
+        if !noisy.is_empty() {
+            let report = format!(
+                "\nNoisy results (cv > {}): {}\n",
+                self.max_cv,
+                noisy.join(", ")
+            );
+            if quiet {
+                eprint!("{}", report);
+            } else {
+                print!("{}", report);
+            }
+        }
+
+        if annotations_enabled(self.github_annotations, format) {
+            use vault_benchmarks::{violation_annotation, AnnotationLevel};
+
+            // Actions only picks workflow commands up from stdout
+            let level = if self.fail_on_regression {
+                AnnotationLevel::Error
+            } else {
+                AnnotationLevel::Warning
+            };
+            for violation in &violations {
+                println!("{}", violation_annotation(violation, level));
+            }
         }
 
         // Save results if requested
@@ -137,31 +515,137 @@ impl RunBenchmarkCommand {
                 BenchmarkIO::with_paths(dir, format!("{}/raw", dir))
             } else {
                 BenchmarkIO::new()
-            };
+            }
+            .with_verify_after_write(self.verify_writes);
 
             io.write_results(&results)
                 .map_err(|e| CliError::io(e.to_string()))?;
 
-            let summary = generate_summary(&results);
+            let summary = if self.charts {
+                generate_summary_with_charts(&results)
+            } else {
+                generate_summary(&results)
+            };
             io.write_summary(&results, &summary)
                 .map_err(|e| CliError::io(e.to_string()))?;
 
-            println!(
-                "\nResults saved to: {}/",
-                io.output_dir().display()
-            );
+            let mut status = RunStatus::new(total_targets, &results)
+                .with_gate(!(self.fail_on_noisy && !noisy.is_empty()));
+            if !options.thresholds.is_empty() {
+                status = status.with_regressions(violations.len());
+            }
+            io.write_status(&status)
+                .map_err(|e| CliError::io(e.to_string()))?;
+
+            if !quiet {
+                println!(
+                    "\nResults saved to: {}/",
+                    io.output_dir().display()
+                );
+            }
+        }
+
+        #[cfg(feature = "sqlite")]
+        if let Some(db) = &self.db {
+            let store = vault_benchmarks::SqliteStore::open(db)
+                .await
+                .map_err(|e| CliError::io(format!("Cannot open results database '{}': {}", db, e)))?;
+            store
+                .insert_all(&results)
+                .await
+                .map_err(|e| CliError::io(e.to_string()))?;
+
+            if !quiet {
+                println!("Results appended to: {}", db);
+            }
+        }
+
+        if !quiet {
+            println!("\nCompleted {} benchmark(s)", results.len());
+        }
+
+        if self.fail_on_regression && !violations.is_empty() {
+            return Err(CliError::validation(format!(
+                "{} threshold(s) breached",
+                violations.len()
+            )));
         }
 
-        println!("\nCompleted {} benchmark(s)", results.len());
+        if self.fail_on_noisy && !noisy.is_empty() {
+            return Err(CliError::validation(format!(
+                "{} result(s) too noisy to trust (cv > {})",
+                noisy.len(),
+                self.max_cv
+            )));
+        }
 
         Ok(())
     }
 }
 
+/// Returns true if GitHub Actions annotations should be printed.
+///
+/// Annotations share stdout with the results, so under Actions they are only
+/// implied for human-readable formats; `explicit` forces them on.
+fn annotations_enabled(explicit: bool, format: OutputFormat) -> bool {
+    explicit || (!format.is_machine_readable() && vault_benchmarks::running_in_github_actions())
+}
+
+/// Parses a positive number of seconds, e.g. "2.5".
+fn parse_seconds(value: &str) -> Result<std::time::Duration, String> {
+    let seconds: f64 = value.parse().map_err(|e: std::num::ParseFloatError| e.to_string())?;
+    if seconds <= 0.0 {
+        return Err("must be greater than zero".to_string());
+    }
+    std::time::Duration::try_from_secs_f64(seconds).map_err(|e| e.to_string())
+}
+
+/// Returns the most recent result of each target.
+fn latest_per_target(results: &[vault_benchmarks::BenchmarkResult]) -> Vec<vault_benchmarks::BenchmarkResult> {
+    let mut latest_by_target: std::collections::HashMap<&str, _> = std::collections::HashMap::new();
+    for result in results {
+        latest_by_target
+            .entry(result.target_id.as_str())
+            .and_modify(|existing: &mut &vault_benchmarks::BenchmarkResult| {
+                if result.timestamp > existing.timestamp {
+                    *existing = result;
+                }
+            })
+            .or_insert(result);
+    }
+    latest_by_target.values().cloned().cloned().collect()
+}
+
+/// Prints the targets a run would execute, in order.
+fn print_target_list(
+    targets: &[Box<dyn vault_benchmarks::BenchTarget>],
+    format: OutputFormat,
+) -> Result<(), CliError> {
+    match format {
+        OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Yaml => {
+            let ids: Vec<&str> = targets.iter().map(|t| t.id()).collect();
+            let json = serde_json::to_string_pretty(&ids)
+                .map_err(|e| CliError::output(e.to_string()))?;
+            println!("{}", json);
+        }
+        OutputFormat::Table | OutputFormat::Plain => {
+            println!("Resolved {} target(s):\n", targets.len());
+            for (i, target) in targets.iter().enumerate() {
+                println!("{:>3}. {}", i + 1, target.id());
+            }
+        }
+        OutputFormat::Prometheus => return Err(prometheus_unsupported()),
+        OutputFormat::Junit => return Err(junit_unsupported()),
+        OutputFormat::Csv | OutputFormat::Html => return Err(export_unsupported(format)),
+    }
+
+    Ok(())
+}
+
 impl ListBenchmarksCommand {
     /// Lists available benchmarks.
     pub async fn run(self, format: OutputFormat) -> Result<(), CliError> {
-        use vault_benchmarks::{all_targets, targets_by_prefix};
+        use vault_benchmarks::{all_targets, targets_by_prefix, TargetInfo};
 
         let targets: Vec<_> = if let Some(prefix) = &self.prefix {
             targets_by_prefix(prefix)
@@ -170,11 +654,10 @@ impl ListBenchmarksCommand {
         };
 
         match format {
-            OutputFormat::Json => {
-                let ids: Vec<&str> = targets.iter().map(|t| t.id()).collect();
-                let json = serde_json::to_string_pretty(&ids)
-                    .map_err(|e| CliError::serialization(e.to_string()))?;
-                println!("{}", json);
+            OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Yaml => {
+                let infos: Vec<TargetInfo> =
+                    targets.iter().map(|t| TargetInfo::from_target(t.as_ref())).collect();
+                print!("{}", to_document(&infos, format)?);
             }
             OutputFormat::Table | OutputFormat::Plain => {
                 println!("Available Benchmarks:\n");
@@ -187,6 +670,226 @@ impl ListBenchmarksCommand {
 
                 println!("\nTotal: {} benchmark(s)", targets.len());
             }
+            OutputFormat::Prometheus => return Err(prometheus_unsupported()),
+            OutputFormat::Junit => return Err(junit_unsupported()),
+            OutputFormat::Csv | OutputFormat::Html => return Err(export_unsupported(format)),
+        }
+
+        Ok(())
+    }
+}
+
+impl InfoBenchmarkCommand {
+    /// Shows a single benchmark target and its most recent result.
+    pub async fn run(self, format: OutputFormat) -> Result<(), CliError> {
+        use vault_benchmarks::{default_specs, print_results, BenchmarkIO};
+
+        let spec = default_specs()
+            .into_iter()
+            .find(|s| s.id == self.id)
+            .ok_or_else(|| {
+                CliError::validation(format!("Benchmark target '{}' not found", self.id))
+            })?;
+        let target = spec.build();
+
+        let io = if let Some(path) = &self.path {
+            BenchmarkIO::with_paths(path, format!("{}/raw", path))
+        } else {
+            BenchmarkIO::new()
+        };
+        // Results are sorted by timestamp, so the last match is the latest
+        let latest = io
+            .read_results()
+            .map_err(|e| CliError::io(e.to_string()))?
+            .into_iter()
+            .rev()
+            .find(|r| r.target_id == self.id);
+
+        match format {
+            OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Yaml => {
+                let info = serde_json::json!({
+                    "id": target.id(),
+                    "name": target.name(),
+                    "description": target.description(),
+                    "iterations": spec.iterations,
+                    "latest": latest,
+                });
+                let json = serde_json::to_string_pretty(&info)
+                    .map_err(|e| CliError::output(e.to_string()))?;
+                println!("{}", json);
+            }
+            OutputFormat::Table | OutputFormat::Plain => {
+                println!("{:<13} {}", "ID:", target.id());
+                println!("{:<13} {}", "Name:", target.name());
+                println!("{:<13} {}", "Description:", target.description());
+                println!("{:<13} {}", "Iterations:", spec.iterations);
+                println!();
+                match &latest {
+                    Some(result) => print_results(std::slice::from_ref(result)),
+                    None => println!("No results recorded yet."),
+                }
+            }
+            OutputFormat::Prometheus => return Err(prometheus_unsupported()),
+            OutputFormat::Junit => return Err(junit_unsupported()),
+            OutputFormat::Csv | OutputFormat::Html => return Err(export_unsupported(format)),
+        }
+
+        Ok(())
+    }
+}
+
+impl InitBenchmarkCommand {
+    /// Creates the benchmark directory layout and thresholds template.
+    pub async fn run(self, format: OutputFormat) -> Result<(), CliError> {
+        use std::path::Path;
+        use vault_benchmarks::BenchmarkIO;
+
+        if format == OutputFormat::Prometheus {
+            return Err(prometheus_unsupported());
+        }
+        if format == OutputFormat::Junit {
+            return Err(junit_unsupported());
+        }
+        if matches!(format, OutputFormat::Csv | OutputFormat::Html) {
+            return Err(export_unsupported(format));
+        }
+
+        let io = if let Some(dir) = &self.output_dir {
+            BenchmarkIO::with_paths(dir, format!("{}/raw", dir))
+        } else {
+            BenchmarkIO::new()
+        };
+
+        let thresholds = io
+            .init_layout(Path::new(&self.thresholds), self.force)
+            .map_err(|e| match e.kind() {
+                std::io::ErrorKind::AlreadyExists => CliError::validation(format!(
+                    "{}; pass --force to overwrite it",
+                    e
+                )),
+                _ => CliError::io(e.to_string()),
+            })?;
+
+        match format {
+            OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Yaml => {
+                let json = serde_json::json!({
+                    "output_dir": io.output_dir().display().to_string(),
+                    "raw_dir": io.raw_dir().display().to_string(),
+                    "thresholds": thresholds.display().to_string(),
+                });
+                println!("{}", json);
+            }
+            OutputFormat::Table
+            | OutputFormat::Plain
+            | OutputFormat::Prometheus
+            | OutputFormat::Junit
+            | OutputFormat::Csv
+            | OutputFormat::Html => {
+                println!("Created {}", io.output_dir().display());
+                println!("Created {}", io.raw_dir().display());
+                println!("Wrote {}", thresholds.display());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl CleanBenchmarkCommand {
+    /// Removes the saved raw result files.
+    pub async fn run(self, format: OutputFormat) -> Result<(), CliError> {
+        use vault_benchmarks::BenchmarkIO;
+
+        if format == OutputFormat::Prometheus {
+            return Err(prometheus_unsupported());
+        }
+        if format == OutputFormat::Junit {
+            return Err(junit_unsupported());
+        }
+        if matches!(format, OutputFormat::Csv | OutputFormat::Html) {
+            return Err(export_unsupported(format));
+        }
+
+        let io = if let Some(path) = &self.path {
+            BenchmarkIO::with_paths(path, format!("{}/raw", path))
+        } else {
+            BenchmarkIO::new()
+        };
+
+        let pending = io.result_file_count()
+            .map_err(|e| CliError::io(e.to_string()))?;
+        if pending > CLEAN_CONFIRM_THRESHOLD && !self.yes {
+            return Err(CliError::validation(format!(
+                "{} result file(s) in {} would be deleted; pass --yes to confirm",
+                pending,
+                io.raw_dir().display()
+            )));
+        }
+
+        let removed = io.clear_results()
+            .map_err(|e| CliError::io(e.to_string()))?;
+
+        match format {
+            OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Yaml => {
+                let json = serde_json::json!({
+                    "raw_dir": io.raw_dir().display().to_string(),
+                    "removed": removed,
+                });
+                println!("{}", json);
+            }
+            OutputFormat::Table
+            | OutputFormat::Plain
+            | OutputFormat::Prometheus
+            | OutputFormat::Junit
+            | OutputFormat::Csv
+            | OutputFormat::Html => {
+                println!("Removed {} result file(s) from {}", removed, io.raw_dir().display());
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ExportBenchmarkCommand {
+    /// Renders the saved results in `format` and writes them out.
+    ///
+    /// Table and plain output produce the markdown summary; JUnit output
+    /// has no thresholds, so every result passes unless it failed to run.
+    pub async fn run(self, format: OutputFormat) -> Result<(), CliError> {
+        use vault_benchmarks::{
+            generate_summary, junit, to_csv, to_exposition, to_html, BenchmarkIO, Thresholds,
+        };
+
+        let raw_dir = self.path.as_deref().unwrap_or(vault_benchmarks::RAW_OUTPUT_DIR);
+        let io = BenchmarkIO::with_paths(raw_dir, raw_dir);
+        let results = io.read_results()
+            .map_err(|e| CliError::io(format!("Failed to read '{}': {}", raw_dir, e)))?;
+        if results.is_empty() {
+            return Err(CliError::validation(format!(
+                "No benchmark results found in '{}'",
+                raw_dir
+            )));
+        }
+
+        let rendered = match format {
+            OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Yaml => {
+                to_document(&results, format)?
+            }
+            OutputFormat::Prometheus => to_exposition(&results),
+            OutputFormat::Junit => junit::to_xml(&results, &Thresholds::new()),
+            OutputFormat::Csv => to_csv(&results),
+            OutputFormat::Html => to_html(&results),
+            OutputFormat::Table | OutputFormat::Plain => generate_summary(&results),
+        };
+
+        match &self.out {
+            Some(out) => {
+                std::fs::write(out, rendered)
+                    .map_err(|e| CliError::io(format!("Failed to write '{}': {}", out, e)))?;
+                println!("Exported {} result(s) to {}", results.len(), out);
+            }
+            None => print!("{}", rendered),
         }
 
         Ok(())
@@ -196,7 +899,7 @@ impl ListBenchmarksCommand {
 impl ResultsCommand {
     /// Shows benchmark results.
     pub async fn run(self, format: OutputFormat) -> Result<(), CliError> {
-        use vault_benchmarks::{BenchmarkIO, print_results};
+        use vault_benchmarks::{BenchmarkIO, DailyRollup, print_results, DAILY_ROLLUP_FILE};
 
         let io = if let Some(path) = &self.path {
             BenchmarkIO::with_paths(path, format!("{}/raw", path))
@@ -204,6 +907,28 @@ impl ResultsCommand {
             BenchmarkIO::new()
         };
 
+        if self.rollup {
+            let rollups = io.rollup_daily()
+                .map_err(|e| CliError::io(e.to_string()))?;
+            println!(
+                "Wrote {} daily rollup(s) to {}",
+                rollups.len(),
+                io.output_dir().join(DAILY_ROLLUP_FILE).display()
+            );
+            return Ok(());
+        }
+
+        if let (true, Some(metric)) = (self.daily, &self.trend) {
+            let mut rollups = io.read_daily_rollups()
+                .map_err(|e| CliError::io(e.to_string()))?;
+            if rollups.is_empty() {
+                rollups = io.rollup_daily()
+                    .map_err(|e| CliError::io(e.to_string()))?;
+            }
+            let daily: Vec<_> = rollups.iter().map(DailyRollup::to_mean_result).collect();
+            return self.show_trend(metric, &daily, format);
+        }
+
         let results = io.read_results()
             .map_err(|e| CliError::io(e.to_string()))?;
 
@@ -212,33 +937,185 @@ impl ResultsCommand {
             return Ok(());
         }
 
+        if let Some(metric) = &self.trend {
+            return self.show_trend(metric, &results, format);
+        }
+
         let display_results = if self.latest {
-            // Group by target_id and get latest
-            let mut latest_by_target: std::collections::HashMap<&str, _> = std::collections::HashMap::new();
-            for result in &results {
-                latest_by_target
-                    .entry(result.target_id.as_str())
-                    .and_modify(|existing: &mut &vault_benchmarks::BenchmarkResult| {
-                        if result.timestamp > existing.timestamp {
-                            *existing = result;
-                        }
-                    })
-                    .or_insert(result);
-            }
-            latest_by_target.values().cloned().cloned().collect()
+            latest_per_target(&results)
         } else {
             results
         };
 
         match format {
-            OutputFormat::Json => {
-                let json = serde_json::to_string_pretty(&display_results)
-                    .map_err(|e| CliError::serialization(e.to_string()))?;
-                println!("{}", json);
+            OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Yaml => {
+                print!("{}", to_document(&display_results, format)?);
             }
             OutputFormat::Table | OutputFormat::Plain => {
                 print_results(&display_results);
             }
+            OutputFormat::Prometheus => {
+                print!("{}", vault_benchmarks::to_exposition(&display_results));
+            }
+            OutputFormat::Csv => {
+                print!("{}", vault_benchmarks::to_csv(&display_results));
+            }
+            OutputFormat::Html => {
+                print!("{}", vault_benchmarks::to_html(&display_results));
+            }
+            OutputFormat::Junit => return Err(junit_unsupported()),
+        }
+
+        Ok(())
+    }
+
+    /// Shows a metric trend for each selected target.
+    fn show_trend(
+        &self,
+        metric: &str,
+        results: &[vault_benchmarks::BenchmarkResult],
+        format: OutputFormat,
+    ) -> Result<(), CliError> {
+        use vault_benchmarks::generate_trend;
+
+        let mut target_ids: Vec<&str> = match &self.target {
+            Some(target) => vec![target.as_str()],
+            None => results.iter().map(|r| r.target_id.as_str()).collect(),
+        };
+        target_ids.sort_unstable();
+        target_ids.dedup();
+
+        // Results are sorted by timestamp, so the tail holds the most recent runs
+        let recent = |target_id: &str| -> Vec<vault_benchmarks::BenchmarkResult> {
+            let history: Vec<_> = results
+                .iter()
+                .filter(|r| r.target_id == target_id && r.metrics.get(metric).is_some())
+                .cloned()
+                .collect();
+            let skip = history.len().saturating_sub(self.last);
+            history.into_iter().skip(skip).collect()
+        };
+
+        match format {
+            OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Yaml => {
+                let series: serde_json::Map<String, serde_json::Value> = target_ids
+                    .iter()
+                    .map(|id| {
+                        let points: Vec<_> = recent(id)
+                            .iter()
+                            .map(|r| serde_json::json!({
+                                "timestamp": r.timestamp,
+                                "value": r.metrics[metric],
+                            }))
+                            .collect();
+                        ((*id).to_string(), serde_json::Value::Array(points))
+                    })
+                    .collect();
+                let json = serde_json::to_string_pretty(&series)
+                    .map_err(|e| CliError::output(e.to_string()))?;
+                println!("{}", json);
+            }
+            OutputFormat::Table | OutputFormat::Plain => {
+                for id in target_ids {
+                    println!("{}", generate_trend(id, metric, &recent(id)));
+                }
+            }
+            OutputFormat::Prometheus => return Err(prometheus_unsupported()),
+            OutputFormat::Junit => return Err(junit_unsupported()),
+            OutputFormat::Csv | OutputFormat::Html => return Err(export_unsupported(format)),
+        }
+
+        Ok(())
+    }
+}
+
+impl CompareBenchmarkCommand {
+    /// Compares the current results against the baseline.
+    pub async fn run(self, format: OutputFormat) -> Result<(), CliError> {
+        use vault_benchmarks::{compare_against_baseline, metric_direction, BenchmarkIO};
+
+        let read = |dir: &str| {
+            BenchmarkIO::with_paths(dir, format!("{}/raw", dir))
+                .read_results()
+                .map_err(|e| CliError::io(format!("Failed to read results from '{}': {}", dir, e)))
+        };
+
+        let baseline = read(&self.baseline)?;
+        if baseline.is_empty() {
+            return Err(CliError::validation(format!(
+                "No baseline results found in '{}'",
+                self.baseline
+            )));
+        }
+
+        let current_dir = self
+            .current
+            .clone()
+            .unwrap_or_else(|| vault_benchmarks::DEFAULT_OUTPUT_DIR.to_string());
+        let mut current = latest_per_target(&read(&current_dir)?);
+        current.sort_by(|a, b| a.target_id.cmp(&b.target_id));
+
+        let regressions = compare_against_baseline(&current, &baseline, self.threshold);
+
+        if annotations_enabled(self.github_annotations, format) {
+            use vault_benchmarks::{regression_annotation, AnnotationLevel};
+
+            // Regressions fail the comparison, so they are reported as errors
+            for regression in &regressions {
+                println!("{}", regression_annotation(regression, AnnotationLevel::Error));
+            }
+        }
+
+        match format {
+            OutputFormat::Json | OutputFormat::JsonCompact | OutputFormat::Yaml => {
+                print!("{}", to_document(&regressions, format)?);
+            }
+            OutputFormat::Table | OutputFormat::Plain => {
+                let baseline = latest_per_target(&baseline);
+                println!(
+                    "{:<35} {:<25} {:>14} {:>14} {:>9}",
+                    "Target", "Metric", "Baseline", "Current", "Change"
+                );
+                println!("{}", "-".repeat(101));
+
+                for result in &current {
+                    let Some(base) = baseline.iter().find(|b| b.target_id == result.target_id) else {
+                        continue;
+                    };
+                    let Some(metrics) = result.metrics.as_object() else {
+                        continue;
+                    };
+
+                    for metric in metrics.keys().filter(|m| metric_direction(m).is_some()) {
+                        let Some(change) = result.percent_change(base, metric) else {
+                            continue;
+                        };
+                        let regressed = regressions
+                            .iter()
+                            .any(|r| r.target_id == result.target_id && &r.metric == metric);
+                        println!(
+                            "{:<35} {:<25} {:>14.3} {:>14.3} {:>+8.1}%{}",
+                            result.target_id,
+                            metric,
+                            base.metrics[metric].as_f64().unwrap_or_default(),
+                            result.metrics[metric].as_f64().unwrap_or_default(),
+                            change,
+                            if regressed { "  REGRESSION" } else { "" }
+                        );
+                    }
+                }
+            }
+            OutputFormat::Prometheus => return Err(prometheus_unsupported()),
+            OutputFormat::Junit => return Err(junit_unsupported()),
+            OutputFormat::Csv | OutputFormat::Html => return Err(export_unsupported(format)),
+        }
+
+        if !regressions.is_empty() {
+            return Err(CliError::validation(format!(
+                "{} metric(s) regressed by more than {:.1}%",
+                regressions.len(),
+                self.threshold
+            )));
         }
 
         Ok(())
@@ -22,10 +22,12 @@ async fn main() -> ExitCode {
     let filter = EnvFilter::try_from_default_env()
         .unwrap_or_else(|_| EnvFilter::new("warn"));
 
+    // Log to stderr so JSON, YAML, and XML output on stdout stays parseable
     fmt()
         .with_env_filter(filter)
         .with_target(false)
         .without_time()
+        .with_writer(std::io::stderr)
         .init();
 
     // Parse CLI arguments
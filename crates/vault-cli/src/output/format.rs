@@ -19,6 +19,23 @@ pub enum OutputFormat {
     Yaml,
     /// Plain text format (for scripting).
     Plain,
+    /// Prometheus text exposition format (benchmark results only).
+    Prometheus,
+    /// JUnit XML report (benchmark runs only).
+    Junit,
+    /// CSV, one row per result (benchmark results only).
+    Csv,
+    /// Standalone HTML table (benchmark results only).
+    Html,
+}
+
+impl OutputFormat {
+    /// Returns true if the output is meant to be parsed by another program,
+    /// so nothing else may be written to stdout alongside it.
+    #[must_use]
+    pub const fn is_machine_readable(self) -> bool {
+        !matches!(self, Self::Table | Self::Plain)
+    }
 }
 
 impl std::fmt::Display for OutputFormat {
@@ -29,6 +46,10 @@ impl std::fmt::Display for OutputFormat {
             Self::JsonCompact => write!(f, "json-compact"),
             Self::Yaml => write!(f, "yaml"),
             Self::Plain => write!(f, "plain"),
+            Self::Prometheus => write!(f, "prometheus"),
+            Self::Junit => write!(f, "junit"),
+            Self::Csv => write!(f, "csv"),
+            Self::Html => write!(f, "html"),
         }
     }
 }
@@ -43,7 +64,14 @@ impl FromStr for OutputFormat {
             "json-compact" | "jsoncompact" => Ok(Self::JsonCompact),
             "yaml" | "yml" => Ok(Self::Yaml),
             "plain" | "text" => Ok(Self::Plain),
-            _ => Err(format!("Unknown format: {s}. Use: table, json, json-compact, yaml, or plain")),
+            "prometheus" | "prom" => Ok(Self::Prometheus),
+            "junit" => Ok(Self::Junit),
+            "csv" => Ok(Self::Csv),
+            "html" => Ok(Self::Html),
+            _ => Err(format!(
+                "Unknown format: {s}. \
+                 Use: table, json, json-compact, yaml, plain, prometheus, junit, csv, or html"
+            )),
         }
     }
 }
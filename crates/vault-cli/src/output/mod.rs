@@ -55,6 +55,9 @@ pub fn print_output<T: Serialize + TableDisplay>(data: &T, format: OutputFormat)
             data.print_plain();
             Ok(())
         }
+        OutputFormat::Prometheus => Err(prometheus_unsupported()),
+        OutputFormat::Junit => Err(junit_unsupported()),
+        OutputFormat::Csv | OutputFormat::Html => Err(export_unsupported(format)),
     }
 }
 
@@ -92,9 +95,29 @@ pub fn print_list<T: Serialize + TableDisplay>(items: &[T], format: OutputFormat
             }
             Ok(())
         }
+        OutputFormat::Prometheus => Err(prometheus_unsupported()),
+        OutputFormat::Junit => Err(junit_unsupported()),
+        OutputFormat::Csv | OutputFormat::Html => Err(export_unsupported(format)),
     }
 }
 
+/// Error for commands whose output has no Prometheus representation.
+pub fn prometheus_unsupported() -> CliError {
+    CliError::validation("--format prometheus is only supported by `benchmark run`, `benchmark results`, and `benchmark export`")
+}
+
+/// Error for commands whose output has no JUnit representation.
+pub fn junit_unsupported() -> CliError {
+    CliError::validation("--format junit is only supported by `benchmark run` and `benchmark export`")
+}
+
+/// Error for commands whose output has no CSV or HTML representation.
+pub fn export_unsupported(format: OutputFormat) -> CliError {
+    CliError::validation(format!(
+        "--format {format} is only supported by `benchmark run`, `benchmark results`, and `benchmark export`"
+    ))
+}
+
 /// Formats a byte size for human-readable display.
 pub fn format_bytes(bytes: u64) -> String {
     const KB: u64 = 1024;
@@ -0,0 +1,114 @@
+//! Checks that benchmark commands keep machine-readable stdout parseable
+//! when run under GitHub Actions.
+
+use assert_cmd::Command;
+use tempfile::TempDir;
+
+const TARGET: &str = "hashing-blake3-1mb";
+
+/// Builds a `vault` invocation in `dir` that looks like a GitHub Actions job.
+fn vault(dir: &TempDir) -> Command {
+    let mut cmd = Command::cargo_bin("vault").unwrap();
+    cmd.current_dir(dir.path())
+        .env("HOME", dir.path())
+        .env("VAULT_URL", "http://localhost:1")
+        .env("GITHUB_ACTIONS", "true");
+    cmd
+}
+
+/// Asserts that `stdout` is a single well-formed document in `format`.
+fn assert_parses(format: &str, stdout: &str) {
+    match format {
+        "json" | "json-compact" => {
+            serde_json::from_str::<Vec<serde_json::Value>>(stdout)
+                .unwrap_or_else(|e| panic!("invalid {format} ({e}):\n{stdout}"));
+        }
+        "yaml" => {
+            serde_yaml::from_str::<Vec<serde_yaml::Value>>(stdout)
+                .unwrap_or_else(|e| panic!("invalid yaml ({e}):\n{stdout}"));
+        }
+        "junit" => {
+            assert!(stdout.starts_with("<?xml"), "invalid junit:\n{stdout}");
+            assert!(stdout.ends_with("</testsuites>\n"), "invalid junit:\n{stdout}");
+        }
+        "prometheus" => {
+            for line in stdout.lines() {
+                let sample = line
+                    .rsplit_once(' ')
+                    .is_some_and(|(_, value)| value.parse::<f64>().is_ok());
+                assert!(line.starts_with("# ") || sample, "invalid exposition line: {line}");
+            }
+        }
+        _ => unreachable!("no parser for {format}"),
+    }
+}
+
+#[test]
+fn run_keeps_machine_readable_stdout_valid() {
+    let dir = TempDir::new().unwrap();
+    // An unreachable limit guarantees a violation, and so an annotation
+    std::fs::write(
+        dir.path().join("thresholds.toml"),
+        format!("[limits.{TARGET}]\nmin_ops_per_second = 1e18\n"),
+    )
+    .unwrap();
+
+    for format in ["json", "json-compact", "yaml", "junit", "prometheus"] {
+        let output = vault(&dir)
+            .args(["benchmark", "run", "--target", TARGET, "--iterations", "3"])
+            .args(["--thresholds", "thresholds.toml", "--format", format])
+            .output()
+            .unwrap();
+        assert!(output.status.success(), "run -f {format} failed: {output:?}");
+
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(!stdout.contains("::warning"), "annotation leaked into {format} output");
+        assert_parses(format, &stdout);
+    }
+}
+
+#[test]
+fn run_prints_annotations_when_asked() {
+    let dir = TempDir::new().unwrap();
+    std::fs::write(
+        dir.path().join("thresholds.toml"),
+        format!("[limits.{TARGET}]\nmin_ops_per_second = 1e18\n"),
+    )
+    .unwrap();
+
+    let output = vault(&dir)
+        .args(["benchmark", "run", "--target", TARGET, "--iterations", "3"])
+        .args(["--thresholds", "thresholds.toml", "--format", "table"])
+        .output()
+        .unwrap();
+    assert!(String::from_utf8(output.stdout).unwrap().contains("::warning"));
+}
+
+#[test]
+fn compare_keeps_machine_readable_stdout_valid() {
+    let dir = TempDir::new().unwrap();
+    for (run, ops) in [("baseline", 1000.0), ("current", 10.0)] {
+        let raw = dir.path().join(run).join("raw");
+        std::fs::create_dir_all(&raw).unwrap();
+        let result = serde_json::json!({
+            "target_id": TARGET,
+            "metrics": {"ops_per_second": ops},
+            "timestamp": "2026-01-01T00:00:00Z",
+        });
+        std::fs::write(raw.join(format!("{TARGET}.json")), result.to_string()).unwrap();
+    }
+
+    for format in ["json", "json-compact", "yaml"] {
+        let output = vault(&dir)
+            .args(["benchmark", "compare", "--baseline", "baseline", "--current", "current"])
+            .args(["--format", format])
+            .output()
+            .unwrap();
+        // The regression fails the comparison, but only after printing it
+        assert!(!output.status.success());
+
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        assert!(!stdout.contains("::error"), "annotation leaked into {format} output");
+        assert_parses(format, &stdout);
+    }
+}
@@ -1,4 +1,4 @@
-//! AES-256-GCM encryption implementation.
+//! AES-256-GCM and ChaCha20-Poly1305 encryption implementations.
 
 use crate::{CryptoError, CryptoResult, KeyAlgorithm, SecureBytes};
 use aes_gcm::{
@@ -146,6 +146,110 @@ impl Default for AesGcmCipher {
     }
 }
 
+/// ChaCha20-Poly1305 cipher.
+///
+/// Usually faster than AES-256-GCM on hosts without AES hardware support.
+pub struct ChaCha20Poly1305Cipher {
+    algorithm: KeyAlgorithm,
+}
+
+impl ChaCha20Poly1305Cipher {
+    /// Creates a new ChaCha20-Poly1305 cipher.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            algorithm: KeyAlgorithm::ChaCha20Poly1305,
+        }
+    }
+
+    /// Encrypts data with the given key.
+    pub fn encrypt(
+        &self,
+        key: &SecureBytes,
+        plaintext: &[u8],
+        aad: Option<&[u8]>,
+    ) -> CryptoResult<EncryptedData> {
+        use chacha20poly1305::{aead::Payload, ChaCha20Poly1305};
+
+        self.validate_key(key)?;
+
+        let cipher = ChaCha20Poly1305::new_from_slice(key.as_slice())
+            .map_err(|e| CryptoError::InvalidKey(e.to_string()))?;
+
+        // Generate random nonce
+        let mut nonce_bytes = [0u8; 12];
+        rand::thread_rng().fill_bytes(&mut nonce_bytes);
+        let nonce = chacha20poly1305::Nonce::from_slice(&nonce_bytes);
+
+        let ciphertext = cipher
+            .encrypt(nonce, Payload {
+                msg: plaintext,
+                aad: aad.unwrap_or_default(),
+            })
+            .map_err(|_| CryptoError::EncryptionFailed("AEAD encryption failed".to_string()))?;
+
+        Ok(EncryptedData {
+            algorithm: self.algorithm,
+            nonce: nonce_bytes.to_vec(),
+            ciphertext,
+            aad: aad.map(|a| a.to_vec()),
+        })
+    }
+
+    /// Decrypts data with the given key.
+    pub fn decrypt(&self, key: &SecureBytes, data: &EncryptedData) -> CryptoResult<SecureBytes> {
+        use chacha20poly1305::{aead::Payload, ChaCha20Poly1305};
+
+        self.validate_key(key)?;
+
+        if data.algorithm != self.algorithm {
+            return Err(CryptoError::UnsupportedAlgorithm(format!(
+                "Expected {:?}, got {:?}",
+                self.algorithm, data.algorithm
+            )));
+        }
+
+        let cipher = ChaCha20Poly1305::new_from_slice(key.as_slice())
+            .map_err(|e| CryptoError::InvalidKey(e.to_string()))?;
+
+        let nonce = chacha20poly1305::Nonce::from_slice(&data.nonce);
+
+        let plaintext = cipher
+            .decrypt(nonce, Payload {
+                msg: &data.ciphertext,
+                aad: data.aad.as_deref().unwrap_or_default(),
+            })
+            .map_err(|_| CryptoError::DecryptionFailed("AEAD decryption failed".to_string()))?;
+
+        Ok(SecureBytes::new(plaintext))
+    }
+
+    /// Generates a new random key.
+    #[must_use]
+    pub fn generate_key(&self) -> SecureBytes {
+        let mut key = vec![0u8; self.algorithm.key_size()];
+        rand::thread_rng().fill_bytes(&mut key);
+        SecureBytes::new(key)
+    }
+
+    fn validate_key(&self, key: &SecureBytes) -> CryptoResult<()> {
+        if key.len() != self.algorithm.key_size() {
+            return Err(CryptoError::InvalidKey(format!(
+                "Expected {} bytes, got {}",
+                self.algorithm.key_size(),
+                key.len()
+            )));
+        }
+        Ok(())
+    }
+}
+
+impl Default for ChaCha20Poly1305Cipher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 /// Base64 serialization helpers.
 mod base64_serde {
     use base64::{engine::general_purpose::STANDARD, Engine};
@@ -272,6 +376,21 @@ mod tests {
         assert_eq!(decrypted.as_slice(), plaintext);
     }
 
+    #[test]
+    fn test_chacha20_encrypt_decrypt() {
+        let cipher = ChaCha20Poly1305Cipher::new();
+        let key = cipher.generate_key();
+        let aad = b"additional authenticated data";
+
+        let encrypted = cipher.encrypt(&key, b"Secret data", Some(aad)).unwrap();
+        assert_eq!(encrypted.algorithm, KeyAlgorithm::ChaCha20Poly1305);
+        let decrypted = cipher.decrypt(&key, &encrypted).unwrap();
+        assert_eq!(decrypted.as_slice(), b"Secret data");
+
+        // Ciphertext from one algorithm is rejected by the other
+        assert!(AesGcmCipher::new().decrypt(&key, &encrypted).is_err());
+    }
+
     #[test]
     fn test_wrong_key_fails() {
         let cipher = AesGcmCipher::new();
@@ -97,6 +97,15 @@ pub fn hmac_sha256_verify(key: &[u8], data: &[u8], expected: &[u8]) -> bool {
     constant_time_eq(&computed, expected)
 }
 
+/// Argon2id memory cost used by [`derive_key_argon2`], in KiB.
+pub const ARGON2_MEMORY_KIB: u32 = 65536;
+
+/// Argon2id time cost (passes over memory) used by [`derive_key_argon2`].
+pub const ARGON2_ITERATIONS: u32 = 3;
+
+/// Argon2id lane count used by [`derive_key_argon2`].
+pub const ARGON2_PARALLELISM: u32 = 4;
+
 /// Derives a key using Argon2id.
 pub fn derive_key_argon2(
     password: &[u8],
@@ -105,8 +114,13 @@ pub fn derive_key_argon2(
 ) -> CryptoResult<SecureBytes> {
     use argon2::{Argon2, Algorithm, Version, Params};
 
-    let params = Params::new(65536, 3, 4, Some(output_len))
-        .map_err(|e| crate::CryptoError::KeyGenerationFailed(e.to_string()))?;
+    let params = Params::new(
+        ARGON2_MEMORY_KIB,
+        ARGON2_ITERATIONS,
+        ARGON2_PARALLELISM,
+        Some(output_len),
+    )
+    .map_err(|e| crate::CryptoError::KeyGenerationFailed(e.to_string()))?;
 
     let argon2 = Argon2::new(Algorithm::Argon2id, Version::V0x13, params);
 
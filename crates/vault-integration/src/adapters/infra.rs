@@ -35,16 +35,31 @@
 //!
 //! // Access retry utilities
 //! let retry_policy = adapter.retry_policy();
+//!
+//! // Throttle outgoing requests
+//! let waited = adapter.rate_limiter().acquire().await;
+//!
+//! // Pick up policy changes from a file while running
+//! let watcher = adapter.watch_config("infra.toml").await?;
 //! ```
 
 use super::{AdapterConfig, AdapterHealth, EcosystemAdapter};
 use crate::{IntegrationError, IntegrationResult};
 use async_trait::async_trait;
+use dashmap::DashMap;
+use lru::LruCache;
+use notify::event::{AccessKind, AccessMode};
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher};
 use serde::{Deserialize, Serialize};
 use std::collections::HashMap;
+use std::ffi::OsStr;
+use std::future::Future;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, Ordering};
 use std::sync::Arc;
 use std::time::Duration;
-use parking_lot::RwLock;
+use parking_lot::{Mutex, RwLock};
+use tokio::time::Instant;
 use tracing::{debug, info, warn, instrument};
 
 /// Infra adapter configuration.
@@ -92,7 +107,10 @@ impl Default for InfraConfig {
 }
 
 /// Retry policy consumed from LLM-Infra.
+///
+/// Fields missing when deserializing take their default values.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct RetryPolicy {
     /// Maximum number of retry attempts.
     pub max_retries: u32,
@@ -102,10 +120,21 @@ pub struct RetryPolicy {
     pub max_backoff_ms: u64,
     /// Backoff multiplier.
     pub multiplier: f64,
-    /// Add jitter to backoff.
-    pub jitter: bool,
+    /// Jitter applied to backoff. Defaults to [`JitterStrategy::Equal`].
+    ///
+    /// This used to be a boolean that added up to 30% on top of the backoff
+    /// and was on by default; the default now keeps waits within
+    /// `[base / 2, base]` instead. Booleans are still accepted when
+    /// deserializing: `true` maps to [`JitterStrategy::Full`] and `false` to
+    /// [`JitterStrategy::None`].
+    #[serde(default, deserialize_with = "jitter_compat::deserialize")]
+    pub jitter: JitterStrategy,
     /// Retryable status codes.
     pub retryable_status_codes: Vec<u16>,
+    /// Cap on the total time spent in [`execute`](Self::execute), attempts
+    /// and backoff sleeps included. Serialized as milliseconds.
+    #[serde(with = "optional_duration_ms", skip_serializing_if = "Option::is_none")]
+    pub max_total_duration: Option<Duration>,
 }
 
 impl Default for RetryPolicy {
@@ -115,36 +144,182 @@ impl Default for RetryPolicy {
             initial_backoff_ms: 100,
             max_backoff_ms: 30_000,
             multiplier: 2.0,
-            jitter: true,
+            jitter: JitterStrategy::default(),
             retryable_status_codes: vec![408, 429, 500, 502, 503, 504],
+            max_total_duration: None,
+        }
+    }
+}
+
+/// Serializes an optional [`Duration`] as whole milliseconds.
+mod optional_duration_ms {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::time::Duration;
+
+    pub fn serialize<S: Serializer>(
+        value: &Option<Duration>,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        match value {
+            Some(duration) => serializer.serialize_some(&(duration.as_millis() as u64)),
+            None => serializer.serialize_none(),
         }
     }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Option<Duration>, D::Error> {
+        Ok(Option::<u64>::deserialize(deserializer)?.map(Duration::from_millis))
+    }
+}
+
+/// Deserializes a [`JitterStrategy`], also accepting the boolean `jitter`
+/// of older configs.
+mod jitter_compat {
+    use super::JitterStrategy;
+    use serde::{Deserialize, Deserializer};
+
+    /// Either form of the `jitter` setting.
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum JitterSetting {
+        Enabled(bool),
+        Strategy(JitterStrategy),
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<JitterStrategy, D::Error> {
+        Ok(match JitterSetting::deserialize(deserializer)? {
+            JitterSetting::Enabled(true) => JitterStrategy::Full,
+            JitterSetting::Enabled(false) => JitterStrategy::None,
+            JitterSetting::Strategy(strategy) => strategy,
+        })
+    }
+}
+
+/// Randomization applied to retry backoff.
+///
+/// `base` is the exponential backoff for the attempt, capped at
+/// `max_backoff_ms`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Default)]
+#[serde(rename_all = "snake_case")]
+pub enum JitterStrategy {
+    /// Exactly `base`.
+    None,
+    /// Uniform in `[0, base]`.
+    Full,
+    /// Uniform in `[base / 2, base]`.
+    #[default]
+    Equal,
+    /// Uniform in `[initial_backoff, 3 * previous]`, capped at `max_backoff_ms`.
+    Decorrelated,
 }
 
 impl RetryPolicy {
     /// Calculates backoff duration for a given attempt.
+    ///
+    /// Decorrelated jitter assumes the previous backoff was the un-jittered
+    /// exponential one; use
+    /// [`backoff_for_attempt_with_prev`](Self::backoff_for_attempt_with_prev)
+    /// to carry the actual previous value between attempts.
     pub fn backoff_for_attempt(&self, attempt: u32) -> Duration {
-        let base_backoff = self.initial_backoff_ms as f64 * self.multiplier.powi(attempt as i32 - 1);
-        let backoff_ms = base_backoff.min(self.max_backoff_ms as f64);
+        let prev_ms = self.base_backoff_ms(attempt.saturating_sub(1).max(1));
+        self.backoff_for_attempt_with_prev(attempt, Duration::from_millis(prev_ms as u64))
+    }
 
-        let final_backoff = if self.jitter {
-            let jitter = rand::random::<f64>() * 0.3 * backoff_ms;
-            backoff_ms + jitter
-        } else {
-            backoff_ms
+    /// Calculates backoff duration for a given attempt, given the backoff
+    /// used before the previous attempt.
+    ///
+    /// Only decorrelated jitter depends on `prev`.
+    pub fn backoff_for_attempt_with_prev(&self, attempt: u32, prev: Duration) -> Duration {
+        let backoff_ms = self.base_backoff_ms(attempt);
+        let max_ms = self.max_backoff_ms as f64;
+
+        let final_backoff = match self.jitter {
+            JitterStrategy::None => backoff_ms,
+            JitterStrategy::Full => rand::random::<f64>() * backoff_ms,
+            JitterStrategy::Equal => backoff_ms / 2.0 + rand::random::<f64>() * backoff_ms / 2.0,
+            JitterStrategy::Decorrelated => {
+                let low = (self.initial_backoff_ms as f64).min(max_ms);
+                let high = (prev.as_millis() as f64 * 3.0).clamp(low, max_ms);
+                low + rand::random::<f64>() * (high - low)
+            }
         };
 
         Duration::from_millis(final_backoff as u64)
     }
 
+    /// Calculates backoff duration for a given attempt, preferring a
+    /// server-provided `Retry-After` delay.
+    ///
+    /// The hint is clamped to `max_backoff_ms`; without one this is
+    /// [`backoff_for_attempt`](Self::backoff_for_attempt).
+    pub fn backoff_for_attempt_with_hint(
+        &self,
+        attempt: u32,
+        retry_after: Option<Duration>,
+    ) -> Duration {
+        match retry_after {
+            Some(hint) => hint.min(Duration::from_millis(self.max_backoff_ms)),
+            None => self.backoff_for_attempt(attempt),
+        }
+    }
+
+    /// Exponential backoff for an attempt before jitter, in milliseconds.
+    fn base_backoff_ms(&self, attempt: u32) -> f64 {
+        let base_backoff = self.initial_backoff_ms as f64 * self.multiplier.powi(attempt as i32 - 1);
+        base_backoff.min(self.max_backoff_ms as f64)
+    }
+
     /// Checks if a status code should be retried.
     pub fn should_retry(&self, status_code: u16) -> bool {
         self.retryable_status_codes.contains(&status_code)
     }
+
+    /// Runs an operation, retrying failures that `is_retryable` accepts.
+    ///
+    /// The operation runs at most `max_retries + 1` times, sleeping for
+    /// [`backoff_for_attempt`](Self::backoff_for_attempt) before each retry.
+    /// With `max_total_duration` set, no retry is made once the time spent
+    /// so far plus the next backoff would reach the budget. The last error
+    /// is returned once retries are exhausted, and a non-retryable error is
+    /// returned immediately.
+    pub async fn execute<F, Fut, T, E, C>(&self, op: F, is_retryable: C) -> Result<T, E>
+    where
+        F: Fn() -> Fut,
+        Fut: Future<Output = Result<T, E>>,
+        C: Fn(&E) -> bool,
+    {
+        let started = Instant::now();
+        let mut attempt = 0;
+        let mut backoff = Duration::from_millis(self.initial_backoff_ms);
+        loop {
+            match op().await {
+                Ok(value) => return Ok(value),
+                Err(err) if attempt < self.max_retries && is_retryable(&err) => {
+                    attempt += 1;
+                    backoff = self.backoff_for_attempt_with_prev(attempt, backoff);
+                    if let Some(budget) = self.max_total_duration {
+                        if started.elapsed() + backoff >= budget {
+                            debug!(attempt, "Retry budget exhausted");
+                            return Err(err);
+                        }
+                    }
+                    debug!(attempt, backoff_ms = backoff.as_millis() as u64, "Retrying operation");
+                    tokio::time::sleep(backoff).await;
+                }
+                Err(err) => return Err(err),
+            }
+        }
+    }
 }
 
 /// Rate limit configuration consumed from LLM-Infra.
+///
+/// Fields missing when deserializing take their default values.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct RateLimitPolicy {
     /// Requests per second.
     pub requests_per_second: u32,
@@ -170,8 +345,152 @@ impl Default for RateLimitPolicy {
     }
 }
 
+/// Token bucket rate limiter enforcing a [`RateLimitPolicy`].
+///
+/// Holds up to `burst_size` tokens and refills at `requests_per_second`.
+/// Each request takes one token; when the bucket is empty the caller waits
+/// for its turn. Waiting callers reserve tokens in arrival order, so
+/// concurrent callers are served fairly.
+#[derive(Debug)]
+pub struct TokenBucket {
+    /// Maximum number of stored tokens.
+    capacity: f64,
+    /// Tokens added per second.
+    refill_per_sec: f64,
+    /// Current tokens (negative while callers are waiting) and last refill.
+    state: Mutex<(f64, Instant)>,
+}
+
+impl TokenBucket {
+    /// Creates a full bucket from a rate limit policy.
+    ///
+    /// A zero rate or burst size is treated as one.
+    pub fn new(policy: &RateLimitPolicy) -> Self {
+        let capacity = f64::from(policy.burst_size.max(1));
+        Self {
+            capacity,
+            refill_per_sec: f64::from(policy.requests_per_second.max(1)),
+            state: Mutex::new((capacity, Instant::now())),
+        }
+    }
+
+    /// Takes a token, waiting until one is available.
+    ///
+    /// Returns [`Duration::ZERO`] if a token was available immediately, and
+    /// otherwise the time spent waiting for one.
+    pub async fn acquire(&self) -> Duration {
+        let wait = {
+            let mut state = self.state.lock();
+            let (tokens, last_refill) = &mut *state;
+
+            let now = Instant::now();
+            let elapsed = now.duration_since(*last_refill).as_secs_f64();
+            *tokens = (*tokens + elapsed * self.refill_per_sec).min(self.capacity);
+            *last_refill = now;
+
+            *tokens -= 1.0;
+            if *tokens >= 0.0 {
+                Duration::ZERO
+            } else {
+                Duration::from_secs_f64(-*tokens / self.refill_per_sec)
+            }
+        };
+
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+        wait
+    }
+
+    /// Returns the number of tokens currently available, after refilling.
+    pub fn available(&self) -> f64 {
+        let state = self.state.lock();
+        let elapsed = state.1.elapsed().as_secs_f64();
+        (state.0 + elapsed * self.refill_per_sec).min(self.capacity).max(0.0)
+    }
+}
+
+/// Rate limiter keeping a separate [`TokenBucket`] per caller key.
+///
+/// Keys are whatever identifies a caller, such as a user id or client IP;
+/// prefix them (`user:alice`, `ip:10.0.0.1`) when both kinds share one
+/// limiter. Per-key buckets apply when the policy sets `per_user` or
+/// `per_ip`, and when it sets `global` every request also draws from one
+/// shared bucket.
+///
+/// A bucket that has refilled completely is indistinguishable from a new
+/// one, so idle keys are dropped once their bucket is full again, bounding
+/// memory to the keys active within the last refill period.
+#[derive(Debug)]
+pub struct KeyedRateLimiter {
+    policy: RateLimitPolicy,
+    global: Option<Arc<TokenBucket>>,
+    buckets: DashMap<String, Arc<TokenBucket>>,
+    /// How long a drained bucket takes to refill completely.
+    refill_period: Duration,
+    last_sweep: Mutex<Instant>,
+}
+
+impl KeyedRateLimiter {
+    /// Creates a limiter for a policy, with `global` as the shared bucket.
+    pub fn new(policy: RateLimitPolicy, global: Arc<TokenBucket>) -> Self {
+        let refill_period = Duration::from_secs_f64(
+            f64::from(policy.burst_size.max(1)) / f64::from(policy.requests_per_second.max(1)),
+        );
+        Self {
+            global: policy.global.then_some(global),
+            policy,
+            buckets: DashMap::new(),
+            refill_period,
+            last_sweep: Mutex::new(Instant::now()),
+        }
+    }
+
+    /// Takes a token for `key`, and one from the global bucket if enabled,
+    /// waiting until both are available.
+    ///
+    /// Returns the total time spent waiting.
+    pub async fn acquire(&self, key: &str) -> Duration {
+        self.sweep_idle();
+
+        let mut waited = Duration::ZERO;
+        if self.policy.per_user || self.policy.per_ip {
+            let bucket = self
+                .buckets
+                .entry(key.to_string())
+                .or_insert_with(|| Arc::new(TokenBucket::new(&self.policy)))
+                .clone();
+            waited += bucket.acquire().await;
+        }
+        if let Some(global) = &self.global {
+            waited += global.acquire().await;
+        }
+        waited
+    }
+
+    /// Returns the number of keys currently tracked.
+    pub fn tracked_keys(&self) -> usize {
+        self.buckets.len()
+    }
+
+    /// Drops full buckets, at most once per refill period.
+    fn sweep_idle(&self) {
+        {
+            let mut last_sweep = self.last_sweep.lock();
+            if last_sweep.elapsed() < self.refill_period {
+                return;
+            }
+            *last_sweep = Instant::now();
+        }
+        self.buckets.retain(|_, bucket| bucket.available() < bucket.capacity);
+    }
+}
+
 /// Cache configuration consumed from LLM-Infra.
+///
+/// Fields missing when deserializing take their default values.
 #[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct CachePolicy {
     /// Maximum number of entries.
     pub max_entries: usize,
@@ -213,6 +532,216 @@ pub enum CacheBackend {
     Memcached,
 }
 
+/// Result of a [`MemoryCache`] lookup.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum CacheLookup {
+    /// The key is cached with this value.
+    Hit(Vec<u8>),
+    /// The key is known not to exist upstream.
+    Negative,
+    /// Nothing is cached for the key, or the entry has expired.
+    Miss,
+}
+
+/// Hit, miss, and eviction counts for a [`MemoryCache`].
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct CacheStats {
+    /// Lookups answered from the cache, including negative entries.
+    pub hits: u64,
+    /// Lookups that found nothing cached or an expired entry.
+    pub misses: u64,
+    /// Entries evicted to stay within `max_entries` or `max_size_bytes`.
+    pub evictions: u64,
+    /// Fraction of lookups that hit, or 0.0 before the first lookup.
+    pub hit_rate: f64,
+}
+
+/// Cached value or negative entry.
+struct MemoryCacheEntry {
+    value: Option<Vec<u8>>, // None = negative cache
+    expires_at: Instant,
+}
+
+impl MemoryCacheEntry {
+    fn size(&self) -> usize {
+        self.value.as_ref().map_or(0, Vec::len)
+    }
+}
+
+/// Entries in recency order and their total size.
+struct MemoryCacheState {
+    entries: LruCache<String, MemoryCacheEntry>,
+    size_bytes: usize,
+}
+
+/// In-memory LRU cache enforcing a [`CachePolicy`].
+///
+/// Evicts least recently used entries once either `max_entries` or
+/// `max_size_bytes` is exceeded; only value bytes count towards the size
+/// limit. Expired entries are dropped when they are next read.
+pub struct MemoryCache {
+    policy: CachePolicy,
+    state: Mutex<MemoryCacheState>,
+    hits: AtomicU64,
+    misses: AtomicU64,
+    evictions: AtomicU64,
+}
+
+impl MemoryCache {
+    /// Creates an empty cache for a cache policy.
+    pub fn new(policy: CachePolicy) -> Self {
+        Self {
+            policy,
+            state: Mutex::new(MemoryCacheState {
+                entries: LruCache::unbounded(),
+                size_bytes: 0,
+            }),
+            hits: AtomicU64::new(0),
+            misses: AtomicU64::new(0),
+            evictions: AtomicU64::new(0),
+        }
+    }
+
+    /// Returns the policy this cache enforces.
+    pub fn policy(&self) -> &CachePolicy {
+        &self.policy
+    }
+
+    /// Looks up a key, marking it as recently used.
+    pub fn get(&self, key: &str) -> CacheLookup {
+        let mut state = self.state.lock();
+
+        match state.entries.get(key) {
+            None => {
+                self.misses.fetch_add(1, Ordering::Relaxed);
+                return CacheLookup::Miss;
+            }
+            Some(entry) if entry.expires_at > Instant::now() => {
+                self.hits.fetch_add(1, Ordering::Relaxed);
+                return match &entry.value {
+                    Some(value) => CacheLookup::Hit(value.clone()),
+                    None => CacheLookup::Negative,
+                };
+            }
+            Some(_) => {}
+        }
+
+        // Expired: drop it now rather than waiting for eviction.
+        if let Some(entry) = state.entries.pop(key) {
+            state.size_bytes -= entry.size();
+        }
+        self.misses.fetch_add(1, Ordering::Relaxed);
+        CacheLookup::Miss
+    }
+
+    /// Caches a value with the policy's default TTL.
+    pub fn insert(&self, key: impl Into<String>, value: Vec<u8>) {
+        let ttl = Duration::from_secs(self.policy.default_ttl_secs);
+        self.insert_with_ttl(key, value, ttl);
+    }
+
+    /// Caches a value with an explicit TTL.
+    ///
+    /// Values larger than `max_size_bytes` are not cached.
+    pub fn insert_with_ttl(&self, key: impl Into<String>, value: Vec<u8>, ttl: Duration) {
+        let key = key.into();
+        if value.len() > self.policy.max_size_bytes {
+            self.remove(&key);
+            return;
+        }
+        self.put(key, Some(value), ttl);
+    }
+
+    /// Records that a key does not exist upstream.
+    ///
+    /// Does nothing unless the policy enables negative caching.
+    pub fn insert_negative(&self, key: impl Into<String>) {
+        if !self.policy.negative_cache {
+            return;
+        }
+        let ttl = Duration::from_secs(self.policy.negative_ttl_secs);
+        self.put(key.into(), None, ttl);
+    }
+
+    /// Removes a key.
+    pub fn remove(&self, key: &str) {
+        let mut state = self.state.lock();
+        if let Some(entry) = state.entries.pop(key) {
+            state.size_bytes -= entry.size();
+        }
+    }
+
+    /// Removes all entries.
+    pub fn clear(&self) {
+        let mut state = self.state.lock();
+        state.entries.clear();
+        state.size_bytes = 0;
+    }
+
+    /// Returns the number of cached entries, including negative ones.
+    pub fn len(&self) -> usize {
+        self.state.lock().entries.len()
+    }
+
+    /// Returns true if nothing is cached.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the total size of cached values in bytes.
+    pub fn size_bytes(&self) -> usize {
+        self.state.lock().size_bytes
+    }
+
+    /// Returns hit, miss, and eviction counts since the cache was created.
+    pub fn stats(&self) -> CacheStats {
+        let hits = self.hits.load(Ordering::Relaxed);
+        let misses = self.misses.load(Ordering::Relaxed);
+        let lookups = hits + misses;
+        CacheStats {
+            hits,
+            misses,
+            evictions: self.evictions.load(Ordering::Relaxed),
+            hit_rate: if lookups == 0 { 0.0 } else { hits as f64 / lookups as f64 },
+        }
+    }
+
+    fn put(&self, key: String, value: Option<Vec<u8>>, ttl: Duration) {
+        let entry = MemoryCacheEntry {
+            value,
+            expires_at: Instant::now() + ttl,
+        };
+
+        let mut state = self.state.lock();
+        state.size_bytes += entry.size();
+        if let Some(old) = state.entries.put(key, entry) {
+            state.size_bytes -= old.size();
+        }
+
+        let max_entries = self.policy.max_entries.max(1);
+        while state.entries.len() > max_entries || state.size_bytes > self.policy.max_size_bytes {
+            match state.entries.pop_lru() {
+                Some((_, evicted)) => {
+                    state.size_bytes -= evicted.size();
+                    self.evictions.fetch_add(1, Ordering::Relaxed);
+                }
+                None => break,
+            }
+        }
+    }
+}
+
+impl std::fmt::Debug for MemoryCache {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MemoryCache")
+            .field("policy", &self.policy)
+            .field("len", &self.len())
+            .field("size_bytes", &self.size_bytes())
+            .field("stats", &self.stats())
+            .finish()
+    }
+}
+
 /// Logging configuration consumed from LLM-Infra.
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LoggingConfig {
@@ -326,6 +855,157 @@ pub struct InfraCapabilities {
     pub rate_limiting_available: bool,
 }
 
+/// Policies read from an Infra config file.
+///
+/// The file is TOML, or JSON when its extension is `.json`, with optional
+/// `retry`, `rate_limit`, and `cache` sections:
+///
+/// ```toml
+/// [retry]
+/// max_retries = 5
+///
+/// [rate_limit]
+/// requests_per_second = 50
+/// ```
+///
+/// A missing section leaves that policy as it is; a missing field within a
+/// section takes its default.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct InfraPolicies {
+    /// Retry policy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retry: Option<RetryPolicy>,
+    /// Rate limit policy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rate_limit: Option<RateLimitPolicy>,
+    /// Cache policy.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cache: Option<CachePolicy>,
+}
+
+impl InfraPolicies {
+    /// Parses policies from file contents, choosing the format by `path`.
+    pub fn parse(path: &Path, content: &str) -> IntegrationResult<Self> {
+        let is_json = path
+            .extension()
+            .is_some_and(|ext| ext.eq_ignore_ascii_case("json"));
+        if is_json {
+            Ok(serde_json::from_str(content)?)
+        } else {
+            toml::from_str(content).map_err(|e| IntegrationError::Serialization(e.to_string()))
+        }
+    }
+
+    /// Reads and parses a policy file.
+    pub async fn load(path: impl AsRef<Path>) -> IntegrationResult<Self> {
+        let path = path.as_ref();
+        let content = tokio::fs::read_to_string(path).await.map_err(|e| {
+            IntegrationError::Internal(format!("Failed to read {}: {e}", path.display()))
+        })?;
+        Self::parse(path, &content)
+    }
+}
+
+/// Handle to a running config file watch.
+///
+/// The watch stops when the handle is dropped.
+#[derive(Debug)]
+pub struct ConfigWatcher {
+    path: PathBuf,
+    _watcher: RecommendedWatcher,
+    task: tokio::task::JoinHandle<()>,
+}
+
+impl ConfigWatcher {
+    /// Returns the watched file.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+}
+
+impl Drop for ConfigWatcher {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+/// Returns true if `event` may have changed the contents of the file named
+/// `file_name` in a watched directory.
+fn is_config_change(event: &Event, file_name: Option<&OsStr>) -> bool {
+    let relevant = matches!(
+        event.kind,
+        EventKind::Create(_)
+            | EventKind::Modify(_)
+            | EventKind::Access(AccessKind::Close(AccessMode::Write))
+    );
+    relevant && event.paths.iter().any(|p| p.file_name() == file_name)
+}
+
+/// Shared handles to an adapter's policies, so they can be updated from a
+/// background task.
+#[derive(Clone)]
+struct PolicySlots {
+    retry_policy: Arc<RwLock<RetryPolicy>>,
+    rate_limit_policy: Arc<RwLock<RateLimitPolicy>>,
+    rate_limiter: Arc<RwLock<Arc<TokenBucket>>>,
+    keyed_rate_limiter: Arc<RwLock<Arc<KeyedRateLimiter>>>,
+    cache_policy: Arc<RwLock<CachePolicy>>,
+    cache: Arc<RwLock<Option<Arc<MemoryCache>>>>,
+    enable_retry: bool,
+    enable_rate_limiting: bool,
+    enable_caching: bool,
+}
+
+impl PolicySlots {
+    fn set_retry_policy(&self, policy: RetryPolicy) {
+        *self.retry_policy.write() = policy;
+    }
+
+    /// Replaces the rate limiters with full buckets for the new policy.
+    fn set_rate_limit_policy(&self, policy: RateLimitPolicy) {
+        let global = Arc::new(TokenBucket::new(&policy));
+        *self.keyed_rate_limiter.write() =
+            Arc::new(KeyedRateLimiter::new(policy.clone(), Arc::clone(&global)));
+        *self.rate_limiter.write() = global;
+        *self.rate_limit_policy.write() = policy;
+    }
+
+    /// Replaces the cache with an empty one for the new policy.
+    fn set_cache_policy(&self, policy: CachePolicy) {
+        *self.cache.write() = match policy.backend {
+            CacheBackend::Memory => Some(Arc::new(MemoryCache::new(policy.clone()))),
+            CacheBackend::Redis | CacheBackend::Memcached => None,
+        };
+        *self.cache_policy.write() = policy;
+    }
+
+    /// Applies the sections present in `policies` whose capability is enabled.
+    fn apply(&self, policies: InfraPolicies, path: &Path) {
+        let retry = policies.retry.filter(|_| self.enable_retry);
+        let rate_limit = policies.rate_limit.filter(|_| self.enable_rate_limiting);
+        let cache = policies.cache.filter(|_| self.enable_caching);
+
+        info!(
+            path = %path.display(),
+            retry = retry.is_some(),
+            rate_limit = rate_limit.is_some(),
+            cache = cache.is_some(),
+            "Reloaded LLM-Infra policies from file"
+        );
+
+        if let Some(policy) = retry {
+            self.set_retry_policy(policy);
+        }
+        if let Some(policy) = rate_limit {
+            self.set_rate_limit_policy(policy);
+        }
+        if let Some(policy) = cache {
+            self.set_cache_policy(policy);
+        }
+    }
+}
+
 /// LLM-Infra adapter for consuming centralized infrastructure utilities.
 pub struct InfraAdapter {
     /// Adapter configuration.
@@ -334,8 +1014,14 @@ pub struct InfraAdapter {
     retry_policy: Arc<RwLock<RetryPolicy>>,
     /// Rate limit policy.
     rate_limit_policy: Arc<RwLock<RateLimitPolicy>>,
+    /// Rate limiter enforcing the current rate limit policy.
+    rate_limiter: Arc<RwLock<Arc<TokenBucket>>>,
+    /// Per-key rate limiter sharing `rate_limiter` as its global bucket.
+    keyed_rate_limiter: Arc<RwLock<Arc<KeyedRateLimiter>>>,
     /// Cache policy.
     cache_policy: Arc<RwLock<CachePolicy>>,
+    /// In-process cache for the current cache policy.
+    cache: Arc<RwLock<Option<Arc<MemoryCache>>>>,
     /// Logging config.
     logging_config: Arc<RwLock<LoggingConfig>>,
     /// Tracing config.
@@ -351,11 +1037,17 @@ pub struct InfraAdapter {
 impl InfraAdapter {
     /// Creates a new Infra adapter.
     pub fn new(config: InfraConfig) -> Self {
+        let rate_limiter = Arc::new(TokenBucket::new(&RateLimitPolicy::default()));
+        let keyed_rate_limiter =
+            KeyedRateLimiter::new(RateLimitPolicy::default(), Arc::clone(&rate_limiter));
         Self {
             config,
             retry_policy: Arc::new(RwLock::new(RetryPolicy::default())),
             rate_limit_policy: Arc::new(RwLock::new(RateLimitPolicy::default())),
+            rate_limiter: Arc::new(RwLock::new(rate_limiter)),
+            keyed_rate_limiter: Arc::new(RwLock::new(Arc::new(keyed_rate_limiter))),
             cache_policy: Arc::new(RwLock::new(CachePolicy::default())),
+            cache: Arc::new(RwLock::new(None)),
             logging_config: Arc::new(RwLock::new(LoggingConfig::default())),
             tracing_config: Arc::new(RwLock::new(TracingConfig::default())),
             error_config: Arc::new(RwLock::new(ErrorConfig::default())),
@@ -379,11 +1071,52 @@ impl InfraAdapter {
         self.rate_limit_policy.read().clone()
     }
 
+    /// Gets the rate limiter for the current rate limit policy.
+    ///
+    /// The limiter is shared: every caller draws from the same bucket until
+    /// the policy is refreshed, which replaces it with a full one.
+    pub fn rate_limiter(&self) -> Arc<TokenBucket> {
+        self.rate_limiter.read().clone()
+    }
+
+    /// Gets the per-key rate limiter for the current rate limit policy.
+    ///
+    /// Its global bucket is the one returned by
+    /// [`rate_limiter`](Self::rate_limiter). Refreshing the policy replaces
+    /// both, forgetting every key.
+    pub fn keyed_rate_limiter(&self) -> Arc<KeyedRateLimiter> {
+        self.keyed_rate_limiter.read().clone()
+    }
+
+    /// Takes a rate limit token for a caller key, such as a user id or IP.
+    ///
+    /// Shorthand for [`KeyedRateLimiter::acquire`] on the current limiter.
+    pub async fn acquire_for(&self, key: &str) -> Duration {
+        self.keyed_rate_limiter().acquire(key).await
+    }
+
     /// Gets the current cache policy.
     pub fn cache_policy(&self) -> CachePolicy {
         self.cache_policy.read().clone()
     }
 
+    /// Gets the in-process cache.
+    ///
+    /// Returns `None` until the cache policy has been loaded, when caching is
+    /// disabled, or when the policy selects an external backend. Refreshing
+    /// the policy replaces the cache with an empty one.
+    pub fn cache(&self) -> Option<Arc<MemoryCache>> {
+        self.cache.read().clone()
+    }
+
+    /// Gets hit, miss, and eviction counts for the in-process cache.
+    ///
+    /// Returns `None` whenever [`cache`](Self::cache) does. Counts start from
+    /// zero each time a policy refresh replaces the cache.
+    pub fn cache_stats(&self) -> Option<CacheStats> {
+        self.cache.read().as_ref().map(|cache| cache.stats())
+    }
+
     /// Gets the current logging config.
     pub fn logging_config(&self) -> LoggingConfig {
         self.logging_config.read().clone()
@@ -415,8 +1148,7 @@ impl InfraAdapter {
 
         // In a real implementation, this would fetch from the Infra service
         // For now, we use sensible defaults that match the existing implementation
-        let policy = RetryPolicy::default();
-        *self.retry_policy.write() = policy;
+        self.policy_slots().set_retry_policy(RetryPolicy::default());
 
         Ok(())
     }
@@ -430,8 +1162,7 @@ impl InfraAdapter {
 
         debug!("Refreshing rate limit policy from LLM-Infra");
 
-        let policy = RateLimitPolicy::default();
-        *self.rate_limit_policy.write() = policy;
+        self.policy_slots().set_rate_limit_policy(RateLimitPolicy::default());
 
         Ok(())
     }
@@ -445,12 +1176,100 @@ impl InfraAdapter {
 
         debug!("Refreshing cache policy from LLM-Infra");
 
-        let policy = CachePolicy::default();
-        *self.cache_policy.write() = policy;
+        self.policy_slots().set_cache_policy(CachePolicy::default());
 
         Ok(())
     }
 
+    /// Loads policies from a file and reloads them whenever it changes.
+    ///
+    /// The file format is described on [`InfraPolicies`]. Changes are picked
+    /// up from file system notifications on the file's directory, so a file
+    /// replaced by renaming over it is seen as well as one written in place.
+    /// The initial load must succeed; afterwards a file that cannot be read
+    /// or parsed is logged and the current policies are kept. Sections for
+    /// disabled capabilities are ignored. A later
+    /// [`refresh_all`](Self::refresh_all) resets the policies until the file
+    /// next changes.
+    pub async fn watch_config(&self, path: impl AsRef<Path>) -> IntegrationResult<ConfigWatcher> {
+        let path = path.as_ref().to_path_buf();
+        let slots = self.policy_slots();
+
+        let mut last = tokio::fs::read(&path).await.map_err(|e| {
+            IntegrationError::Internal(format!("Failed to read {}: {e}", path.display()))
+        })?;
+        let policies = InfraPolicies::parse(&path, &String::from_utf8_lossy(&last))?;
+        slots.apply(policies, &path);
+
+        let watch_error = |e: notify::Error| {
+            IntegrationError::Internal(format!("Failed to watch {}: {e}", path.display()))
+        };
+        let (changed_tx, mut changed_rx) = tokio::sync::mpsc::unbounded_channel();
+        let file_name = path.file_name().map(ToOwned::to_owned);
+        let mut watcher =
+            notify::recommended_watcher(move |event: notify::Result<Event>| match event {
+                Ok(event) if is_config_change(&event, file_name.as_deref()) => {
+                    let _ = changed_tx.send(());
+                }
+                Ok(_) => {}
+                Err(e) => warn!(error = %e, "LLM-Infra config watch error"),
+            })
+            .map_err(watch_error)?;
+        // Watch the directory, since replacing the file drops a watch on the file itself
+        let dir = match path.parent() {
+            Some(dir) if !dir.as_os_str().is_empty() => dir,
+            _ => Path::new("."),
+        };
+        watcher.watch(dir, RecursiveMode::NonRecursive).map_err(watch_error)?;
+
+        let watched = path.clone();
+        let task = tokio::spawn(async move {
+            while changed_rx.recv().await.is_some() {
+                // A single save fires several events; handle them with one read
+                while changed_rx.try_recv().is_ok() {}
+
+                let content = match tokio::fs::read(&watched).await {
+                    Ok(content) => content,
+                    // The file may be briefly missing while it is replaced
+                    Err(e) => {
+                        debug!(path = %watched.display(), error = %e, "Config file unreadable");
+                        continue;
+                    }
+                };
+                if content == last {
+                    continue;
+                }
+
+                match InfraPolicies::parse(&watched, &String::from_utf8_lossy(&content)) {
+                    Ok(policies) => slots.apply(policies, &watched),
+                    Err(e) => warn!(
+                        path = %watched.display(),
+                        error = %e,
+                        "Ignoring invalid LLM-Infra config file"
+                    ),
+                }
+                last = content;
+            }
+        });
+
+        Ok(ConfigWatcher { path, _watcher: watcher, task })
+    }
+
+    /// Returns shared handles to the adapter's policies.
+    fn policy_slots(&self) -> PolicySlots {
+        PolicySlots {
+            retry_policy: Arc::clone(&self.retry_policy),
+            rate_limit_policy: Arc::clone(&self.rate_limit_policy),
+            rate_limiter: Arc::clone(&self.rate_limiter),
+            keyed_rate_limiter: Arc::clone(&self.keyed_rate_limiter),
+            cache_policy: Arc::clone(&self.cache_policy),
+            cache: Arc::clone(&self.cache),
+            enable_retry: self.config.enable_retry,
+            enable_rate_limiting: self.config.enable_rate_limiting,
+            enable_caching: self.config.enable_caching,
+        }
+    }
+
     /// Refreshes all configurations from upstream.
     pub async fn refresh_all(&self) -> IntegrationResult<()> {
         info!("Refreshing all configurations from LLM-Infra");
@@ -506,10 +1325,21 @@ impl EcosystemAdapter for InfraAdapter {
         .filter(|&&x| x)
         .count();
 
-        Ok(AdapterHealth::healthy(format!(
+        let mut message = format!(
             "LLM-Infra adapter healthy: {}/6 capabilities available",
             available_count
-        )))
+        );
+        if let Some(stats) = self.cache_stats() {
+            message.push_str(&format!(
+                ", cache hit rate {:.1}% ({} hits, {} misses, {} evictions)",
+                stats.hit_rate * 100.0,
+                stats.hits,
+                stats.misses,
+                stats.evictions
+            ));
+        }
+
+        Ok(AdapterHealth::healthy(message))
     }
 
     async fn initialize(&self) -> IntegrationResult<()> {
@@ -550,6 +1380,7 @@ impl EcosystemAdapter for InfraAdapter {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use std::sync::atomic::{AtomicU32, Ordering};
 
     #[tokio::test]
     async fn test_adapter_creation() {
@@ -581,7 +1412,10 @@ mod tests {
 
     #[tokio::test]
     async fn test_backoff_calculation() {
-        let policy = RetryPolicy::default();
+        let policy = RetryPolicy {
+            jitter: JitterStrategy::None,
+            ..Default::default()
+        };
 
         let backoff1 = policy.backoff_for_attempt(1);
         assert!(backoff1.as_millis() >= 100);
@@ -590,6 +1424,149 @@ mod tests {
         assert!(backoff2 > backoff1);
     }
 
+    #[test]
+    fn test_backoff_with_retry_after_hint() {
+        let policy = RetryPolicy {
+            jitter: JitterStrategy::None,
+            max_backoff_ms: 10_000,
+            ..Default::default()
+        };
+
+        let hinted = policy.backoff_for_attempt_with_hint(1, Some(Duration::from_secs(2)));
+        assert_eq!(hinted, Duration::from_secs(2));
+
+        let clamped = policy.backoff_for_attempt_with_hint(1, Some(Duration::from_secs(120)));
+        assert_eq!(clamped, Duration::from_secs(10));
+
+        let computed = policy.backoff_for_attempt_with_hint(2, None);
+        assert_eq!(computed, policy.backoff_for_attempt(2));
+    }
+
+    #[test]
+    fn test_jitter_strategy_bounds() {
+        let policy = |jitter| RetryPolicy {
+            jitter,
+            max_backoff_ms: 1_000,
+            ..Default::default()
+        };
+        let none = policy(JitterStrategy::None);
+        let full = policy(JitterStrategy::Full);
+        let equal = policy(JitterStrategy::Equal);
+        let decorrelated = policy(JitterStrategy::Decorrelated);
+
+        assert_eq!(none.backoff_for_attempt(3), Duration::from_millis(400));
+        assert_eq!(none.backoff_for_attempt(10), Duration::from_millis(1_000));
+
+        for _ in 0..100 {
+            let backoff = full.backoff_for_attempt(3).as_millis();
+            assert!(backoff <= 400);
+
+            let backoff = equal.backoff_for_attempt(3).as_millis();
+            assert!((200..=400).contains(&backoff));
+
+            let prev = Duration::from_millis(300);
+            let backoff = decorrelated.backoff_for_attempt_with_prev(3, prev).as_millis();
+            assert!((100..=900).contains(&backoff));
+
+            let prev = Duration::from_millis(5_000);
+            let backoff = decorrelated.backoff_for_attempt_with_prev(3, prev).as_millis();
+            assert!((100..=1_000).contains(&backoff));
+        }
+    }
+
+    #[test]
+    fn test_jitter_accepts_legacy_booleans() {
+        let jitter = |json: &str| serde_json::from_str::<RetryPolicy>(json).unwrap().jitter;
+
+        assert_eq!(jitter(r#"{"jitter": true}"#), JitterStrategy::Full);
+        assert_eq!(jitter(r#"{"jitter": false}"#), JitterStrategy::None);
+        assert_eq!(jitter(r#"{"jitter": "decorrelated"}"#), JitterStrategy::Decorrelated);
+        assert_eq!(jitter("{}"), JitterStrategy::Equal);
+        assert!(serde_json::from_str::<RetryPolicy>(r#"{"jitter": "sometimes"}"#).is_err());
+
+        let policy: RetryPolicy = toml::from_str("max_retries = 5\njitter = false\n").unwrap();
+        assert_eq!(policy.jitter, JitterStrategy::None);
+
+        let round_trip = serde_json::to_string(&RetryPolicy::default()).unwrap();
+        assert_eq!(jitter(&round_trip), JitterStrategy::Equal);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_execute_retries_until_success() {
+        let policy = RetryPolicy::default();
+        let calls = AtomicU32::new(0);
+
+        let result: Result<u32, u16> = policy
+            .execute(
+                || async {
+                    let call = calls.fetch_add(1, Ordering::SeqCst) + 1;
+                    if call < 3 {
+                        Err(503)
+                    } else {
+                        Ok(call)
+                    }
+                },
+                |status| policy.should_retry(*status),
+            )
+            .await;
+
+        assert_eq!(result, Ok(3));
+        assert_eq!(calls.load(Ordering::SeqCst), 3);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_execute_stops_on_permanent_error() {
+        let policy = RetryPolicy::default();
+        let calls = AtomicU32::new(0);
+        let start = Instant::now();
+
+        let result: Result<(), u16> = policy
+            .execute(
+                || async {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    Err(404)
+                },
+                |status| policy.should_retry(*status),
+            )
+            .await;
+
+        assert_eq!(result, Err(404));
+        assert_eq!(calls.load(Ordering::SeqCst), 1);
+        assert_eq!(start.elapsed(), Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_execute_stops_at_total_duration() {
+        let policy = RetryPolicy {
+            max_retries: 10,
+            jitter: JitterStrategy::None,
+            max_total_duration: Some(Duration::from_millis(500)),
+            ..Default::default()
+        };
+        let calls = AtomicU32::new(0);
+        let start = Instant::now();
+
+        let result: Result<(), u16> = policy
+            .execute(
+                || async {
+                    calls.fetch_add(1, Ordering::SeqCst);
+                    tokio::time::sleep(Duration::from_millis(100)).await;
+                    Err(503)
+                },
+                |status| policy.should_retry(*status),
+            )
+            .await;
+
+        // 100ms attempt, 100ms backoff, 100ms attempt; a 200ms backoff would hit the budget
+        assert_eq!(result, Err(503));
+        assert_eq!(calls.load(Ordering::SeqCst), 2);
+        assert_eq!(start.elapsed(), Duration::from_millis(300));
+
+        let parsed: RetryPolicy = serde_json::from_str(r#"{"max_total_duration": 1500}"#).unwrap();
+        assert_eq!(parsed.max_total_duration, Some(Duration::from_millis(1500)));
+        assert_eq!(parsed.max_retries, 3);
+    }
+
     #[tokio::test]
     async fn test_rate_limit_policy() {
         let adapter = InfraAdapter::with_defaults();
@@ -600,6 +1577,70 @@ mod tests {
         assert_eq!(policy.burst_size, 200);
     }
 
+    #[tokio::test(start_paused = true)]
+    async fn test_token_bucket_burst_then_throttle() {
+        let policy = RateLimitPolicy {
+            requests_per_second: 10,
+            burst_size: 5,
+            ..Default::default()
+        };
+        let bucket = TokenBucket::new(&policy);
+
+        for _ in 0..policy.burst_size {
+            assert_eq!(bucket.acquire().await, Duration::ZERO);
+        }
+        assert_eq!(bucket.acquire().await, Duration::from_millis(100));
+        assert!(bucket.available() < 1.0);
+
+        tokio::time::advance(Duration::from_secs(1)).await;
+        assert_eq!(bucket.acquire().await, Duration::ZERO);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_keyed_rate_limiter_per_user_bursts() {
+        let policy = RateLimitPolicy {
+            requests_per_second: 10,
+            burst_size: 3,
+            global: false,
+            ..Default::default()
+        };
+        let limiter = KeyedRateLimiter::new(policy.clone(), Arc::new(TokenBucket::new(&policy)));
+
+        for user in ["user:alice", "user:bob"] {
+            for _ in 0..policy.burst_size {
+                assert_eq!(limiter.acquire(user).await, Duration::ZERO);
+            }
+        }
+        assert_eq!(limiter.tracked_keys(), 2);
+        assert_eq!(limiter.acquire("user:alice").await, Duration::from_millis(100));
+
+        // Once every bucket has refilled, idle keys are forgotten
+        tokio::time::advance(Duration::from_secs(1)).await;
+        assert_eq!(limiter.acquire("user:carol").await, Duration::ZERO);
+        assert_eq!(limiter.tracked_keys(), 1);
+
+        // The global bucket is drawn from in addition to each user's own
+        let global = RateLimitPolicy {
+            global: true,
+            ..policy
+        };
+        let limiter = KeyedRateLimiter::new(global.clone(), Arc::new(TokenBucket::new(&global)));
+        for _ in 0..global.burst_size {
+            assert_eq!(limiter.acquire("user:alice").await, Duration::ZERO);
+        }
+        assert_eq!(limiter.acquire("user:bob").await, Duration::from_millis(100));
+    }
+
+    #[tokio::test]
+    async fn test_rate_limiter_is_shared() {
+        let adapter = InfraAdapter::with_defaults();
+        adapter.initialize().await.unwrap();
+
+        let limiter = adapter.rate_limiter();
+        assert!(Arc::ptr_eq(&limiter, &adapter.rate_limiter()));
+        assert_eq!(limiter.acquire().await, Duration::ZERO);
+    }
+
     #[tokio::test]
     async fn test_cache_policy() {
         let adapter = InfraAdapter::with_defaults();
@@ -610,6 +1651,167 @@ mod tests {
         assert!(policy.negative_cache);
     }
 
+    #[tokio::test]
+    async fn test_memory_cache_exposed() {
+        let adapter = InfraAdapter::with_defaults();
+        assert!(adapter.cache().is_none());
+
+        adapter.initialize().await.unwrap();
+        let cache = adapter.cache().unwrap();
+        cache.insert("key", b"value".to_vec());
+        assert_eq!(
+            adapter.cache().unwrap().get("key"),
+            CacheLookup::Hit(b"value".to_vec())
+        );
+
+        let disabled = InfraAdapter::new(InfraConfig {
+            enable_caching: false,
+            ..Default::default()
+        });
+        disabled.initialize().await.unwrap();
+        assert!(disabled.cache().is_none());
+    }
+
+    #[test]
+    fn test_memory_cache_evicts_by_count() {
+        let cache = MemoryCache::new(CachePolicy {
+            max_entries: 2,
+            ..Default::default()
+        });
+
+        cache.insert("a", vec![1]);
+        cache.insert("b", vec![2]);
+        assert_eq!(cache.get("a"), CacheLookup::Hit(vec![1]));
+        cache.insert("c", vec![3]);
+
+        assert_eq!(cache.len(), 2);
+        assert_eq!(cache.get("b"), CacheLookup::Miss);
+        assert_eq!(cache.get("a"), CacheLookup::Hit(vec![1]));
+        assert_eq!(cache.get("c"), CacheLookup::Hit(vec![3]));
+    }
+
+    #[test]
+    fn test_memory_cache_evicts_by_bytes() {
+        let cache = MemoryCache::new(CachePolicy {
+            max_size_bytes: 10,
+            ..Default::default()
+        });
+
+        cache.insert("a", vec![0; 4]);
+        cache.insert("b", vec![0; 4]);
+        assert_eq!(cache.size_bytes(), 8);
+        cache.insert("c", vec![0; 4]);
+
+        assert_eq!(cache.size_bytes(), 8);
+        assert_eq!(cache.get("a"), CacheLookup::Miss);
+
+        cache.insert("huge", vec![0; 11]);
+        assert_eq!(cache.get("huge"), CacheLookup::Miss);
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_cache_stats() {
+        let adapter = InfraAdapter::new(InfraConfig::default());
+        assert_eq!(adapter.cache_stats(), None);
+        adapter.initialize().await.unwrap();
+
+        let cache = adapter.cache().unwrap();
+        assert_eq!(cache.stats(), CacheStats::default());
+        cache.insert("a", vec![1]);
+        cache.insert_negative("absent");
+        cache.get("a");
+        cache.get("a");
+        cache.get("absent");
+        cache.get("missing");
+
+        let stats = adapter.cache_stats().unwrap();
+        assert_eq!((stats.hits, stats.misses, stats.evictions), (3, 1, 0));
+        assert_eq!(stats.hit_rate, 0.75);
+
+        let health = adapter.health_check().await.unwrap();
+        assert!(health.message.contains("cache hit rate 75.0% (3 hits, 1 misses, 0 evictions)"));
+
+        let small = MemoryCache::new(CachePolicy {
+            max_entries: 1,
+            ..Default::default()
+        });
+        small.insert("a", vec![1]);
+        small.insert("b", vec![2]);
+        assert_eq!(small.stats().evictions, 1);
+    }
+
+    #[tokio::test(start_paused = true)]
+    async fn test_memory_cache_ttl_expiry() {
+        let cache = MemoryCache::new(CachePolicy {
+            default_ttl_secs: 10,
+            negative_ttl_secs: 2,
+            ..Default::default()
+        });
+
+        cache.insert("present", b"value".to_vec());
+        cache.insert_negative("absent");
+        assert_eq!(cache.get("absent"), CacheLookup::Negative);
+
+        tokio::time::advance(Duration::from_secs(3)).await;
+        assert_eq!(cache.get("absent"), CacheLookup::Miss);
+        assert_eq!(cache.get("present"), CacheLookup::Hit(b"value".to_vec()));
+
+        tokio::time::advance(Duration::from_secs(10)).await;
+        assert_eq!(cache.get("present"), CacheLookup::Miss);
+        assert!(cache.is_empty());
+        assert_eq!(cache.size_bytes(), 0);
+    }
+
+    #[tokio::test]
+    async fn test_watch_config_reloads_policies() {
+        let path = std::env::temp_dir().join(format!("infra-{}.toml", uuid::Uuid::new_v4()));
+        std::fs::write(&path, "[retry]\nmax_retries = 5\n").unwrap();
+
+        let adapter = InfraAdapter::with_defaults();
+        adapter.initialize().await.unwrap();
+        let watcher = adapter.watch_config(&path).await.unwrap();
+        assert_eq!(watcher.path(), path);
+        assert_eq!(adapter.retry_policy().max_retries, 5);
+        assert_eq!(adapter.retry_policy().initial_backoff_ms, 100);
+
+        std::fs::write(
+            &path,
+            "[retry]\nmax_retries = 7\n\n[rate_limit]\nrequests_per_second = 5\n",
+        )
+        .unwrap();
+        let reloaded = tokio::time::timeout(Duration::from_secs(5), async {
+            while adapter.retry_policy().max_retries != 7 {
+                tokio::time::sleep(Duration::from_millis(10)).await;
+            }
+        })
+        .await;
+        assert!(reloaded.is_ok(), "watcher did not pick up the change");
+        assert_eq!(adapter.rate_limit_policy().requests_per_second, 5);
+
+        // Invalid contents are ignored and stopping the watch freezes the policy
+        std::fs::write(&path, "[retry]\nmax_retries = \"many\"\n").unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(adapter.retry_policy().max_retries, 7);
+        drop(watcher);
+        std::fs::write(&path, "[retry]\nmax_retries = 1\n").unwrap();
+        tokio::time::sleep(Duration::from_millis(100)).await;
+        assert_eq!(adapter.retry_policy().max_retries, 7);
+
+        std::fs::remove_file(&path).unwrap();
+    }
+
+    #[test]
+    fn test_infra_policies_parse() {
+        let json = InfraPolicies::parse(Path::new("infra.json"), r#"{"cache": {"max_entries": 3}}"#)
+            .unwrap();
+        assert_eq!(json.cache.unwrap().max_entries, 3);
+        assert!(json.retry.is_none());
+
+        let err = InfraPolicies::parse(Path::new("infra.toml"), "[retries]\n").unwrap_err();
+        assert_eq!(err.code(), "SERIALIZATION_ERROR");
+    }
+
     #[tokio::test]
     async fn test_capabilities() {
         let adapter = InfraAdapter::with_defaults();
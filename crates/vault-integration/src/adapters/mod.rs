@@ -17,6 +17,8 @@
 //! - [`infra`]: Consumes centralized infrastructure utilities including config
 //!   loading, logging, tracing, caching, retry logic, and rate limiting from
 //!   LLM-Infra (Phase 2B).
+//! - [`registry`]: Initializes, health-checks, and shuts down a set of
+//!   adapters together.
 //!
 //! # Phase 2B Implementation
 //!
@@ -32,19 +34,24 @@ pub mod config_manager;
 pub mod observatory;
 pub mod memory_graph;
 pub mod infra;
+pub mod registry;
 
 pub use schema_registry::SchemaRegistryAdapter;
 pub use config_manager::ConfigManagerAdapter;
 pub use observatory::ObservatoryAdapter;
 pub use memory_graph::MemoryGraphAdapter;
+pub use registry::AdapterRegistry;
 pub use infra::{
     InfraAdapter, InfraConfig, InfraCapabilities,
-    RetryPolicy, RateLimitPolicy, CachePolicy, CacheBackend,
+    RetryPolicy, JitterStrategy, RateLimitPolicy, TokenBucket, KeyedRateLimiter,
+    CachePolicy, CacheBackend,
+    CacheLookup, CacheStats, MemoryCache, ConfigWatcher, InfraPolicies,
     LoggingConfig, TracingConfig, TracePropagation, ErrorConfig,
 };
 
 use crate::IntegrationResult;
 use async_trait::async_trait;
+use serde::{Deserialize, Serialize};
 
 /// Common trait for all LLM-Dev-Ops ecosystem adapters.
 #[async_trait]
@@ -107,7 +114,10 @@ impl AdapterHealth {
 }
 
 /// Configuration for ecosystem adapters.
-#[derive(Debug, Clone)]
+///
+/// Fields missing when deserializing take their default values.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(default)]
 pub struct AdapterConfig {
     /// Base URL for the upstream service.
     pub base_url: Option<String>,
@@ -0,0 +1,231 @@
+//! Registry coordinating the lifecycle of several ecosystem adapters.
+//!
+//! Each adapter can be initialized, health-checked, and shut down on its own;
+//! the registry does it for a whole set at once. One adapter failing never
+//! stops the others: every adapter is attempted and the failures are
+//! reported together as [`IntegrationError::AdapterFailures`].
+//!
+//! # Usage
+//!
+//! ```ignore
+//! use vault_integration::adapters::{AdapterRegistry, InfraAdapter};
+//!
+//! let registry = AdapterRegistry::new();
+//! registry.register(Arc::new(InfraAdapter::with_defaults()));
+//! registry.initialize_all().await?;
+//!
+//! for (name, health) in registry.health_check_all().await {
+//!     println!("{name}: {}", health.message);
+//! }
+//!
+//! registry.shutdown_all().await?;
+//! ```
+
+use super::{AdapterHealth, EcosystemAdapter};
+use crate::{IntegrationError, IntegrationResult};
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::sync::Arc;
+use tracing::{info, warn};
+
+/// Registry of ecosystem adapters managed as a set.
+pub struct AdapterRegistry {
+    /// Adapters in registration order.
+    adapters: RwLock<Vec<Arc<dyn EcosystemAdapter>>>,
+}
+
+impl AdapterRegistry {
+    /// Creates an empty registry.
+    pub fn new() -> Self {
+        Self {
+            adapters: RwLock::new(Vec::new()),
+        }
+    }
+
+    /// Registers an adapter.
+    ///
+    /// An adapter registered under a name that is already taken replaces the
+    /// earlier one, keeping its position.
+    pub fn register(&self, adapter: Arc<dyn EcosystemAdapter>) {
+        info!(adapter = %adapter.name(), "Registering ecosystem adapter");
+
+        let mut adapters = self.adapters.write();
+        match adapters.iter_mut().find(|a| a.name() == adapter.name()) {
+            Some(existing) => *existing = adapter,
+            None => adapters.push(adapter),
+        }
+    }
+
+    /// Unregisters an adapter by name.
+    pub fn unregister(&self, name: &str) -> Option<Arc<dyn EcosystemAdapter>> {
+        let mut adapters = self.adapters.write();
+        let index = adapters.iter().position(|a| a.name() == name)?;
+        Some(adapters.remove(index))
+    }
+
+    /// Gets an adapter by name.
+    pub fn get(&self, name: &str) -> Option<Arc<dyn EcosystemAdapter>> {
+        self.adapters.read().iter().find(|a| a.name() == name).cloned()
+    }
+
+    /// Returns the registered adapter names in registration order.
+    pub fn names(&self) -> Vec<String> {
+        self.adapters.read().iter().map(|a| a.name().to_string()).collect()
+    }
+
+    /// Returns the number of registered adapters.
+    pub fn len(&self) -> usize {
+        self.adapters.read().len()
+    }
+
+    /// Returns true if no adapters are registered.
+    pub fn is_empty(&self) -> bool {
+        self.adapters.read().is_empty()
+    }
+
+    /// Initializes every adapter in registration order.
+    ///
+    /// Adapters after a failed one are still initialized.
+    pub async fn initialize_all(&self) -> IntegrationResult<()> {
+        let mut failures = Vec::new();
+        for adapter in self.snapshot() {
+            if let Err(e) = adapter.initialize().await {
+                warn!(adapter = %adapter.name(), error = %e, "Adapter initialization failed");
+                failures.push((adapter.name().to_string(), e));
+            }
+        }
+        into_result(failures)
+    }
+
+    /// Checks every adapter's health concurrently, keyed by adapter name.
+    ///
+    /// A health check that returns an error is reported as unhealthy.
+    pub async fn health_check_all(&self) -> HashMap<String, AdapterHealth> {
+        let adapters = self.snapshot();
+        let checks = adapters.iter().map(|adapter| async move {
+            let health = adapter.health_check().await.unwrap_or_else(|e| {
+                AdapterHealth::unhealthy(format!("Health check failed: {e}"))
+            });
+            (adapter.name().to_string(), health)
+        });
+        futures::future::join_all(checks).await.into_iter().collect()
+    }
+
+    /// Shuts down every adapter in reverse registration order.
+    ///
+    /// Adapters after a failed one are still shut down.
+    pub async fn shutdown_all(&self) -> IntegrationResult<()> {
+        let mut failures = Vec::new();
+        for adapter in self.snapshot().into_iter().rev() {
+            if let Err(e) = adapter.shutdown().await {
+                warn!(adapter = %adapter.name(), error = %e, "Adapter shutdown failed");
+                failures.push((adapter.name().to_string(), e));
+            }
+        }
+        into_result(failures)
+    }
+
+    /// Copies the adapter list so no lock is held across awaits.
+    fn snapshot(&self) -> Vec<Arc<dyn EcosystemAdapter>> {
+        self.adapters.read().clone()
+    }
+}
+
+impl Default for AdapterRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Turns collected per-adapter failures into a single result.
+fn into_result(failures: Vec<(String, IntegrationError)>) -> IntegrationResult<()> {
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(IntegrationError::AdapterFailures(failures))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use async_trait::async_trait;
+    use std::sync::atomic::{AtomicBool, Ordering};
+
+    struct TestAdapter {
+        name: &'static str,
+        fail: bool,
+        initialized: AtomicBool,
+    }
+
+    impl TestAdapter {
+        fn new(name: &'static str, fail: bool) -> Arc<Self> {
+            Arc::new(Self {
+                name,
+                fail,
+                initialized: AtomicBool::new(false),
+            })
+        }
+    }
+
+    #[async_trait]
+    impl EcosystemAdapter for TestAdapter {
+        fn name(&self) -> &str {
+            self.name
+        }
+
+        fn version(&self) -> &str {
+            "0.1.0"
+        }
+
+        async fn health_check(&self) -> IntegrationResult<AdapterHealth> {
+            if self.fail {
+                return Err(IntegrationError::Timeout("upstream".to_string()));
+            }
+            Ok(AdapterHealth::healthy("ok"))
+        }
+
+        async fn initialize(&self) -> IntegrationResult<()> {
+            if self.fail {
+                return Err(IntegrationError::Http("connection refused".to_string()));
+            }
+            self.initialized.store(true, Ordering::SeqCst);
+            Ok(())
+        }
+
+        async fn shutdown(&self) -> IntegrationResult<()> {
+            self.initialized.store(false, Ordering::SeqCst);
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_registry_aggregates_failures() {
+        let registry = AdapterRegistry::new();
+        let broken = TestAdapter::new("broken", true);
+        let working = TestAdapter::new("working", false);
+        registry.register(broken);
+        registry.register(working.clone());
+        assert_eq!(registry.names(), ["broken", "working"]);
+
+        let err = registry.initialize_all().await.unwrap_err();
+        assert_eq!(err.code(), "ADAPTER_FAILURES");
+        assert_eq!(
+            err.to_string(),
+            "1 adapter(s) failed: broken: HTTP error: connection refused"
+        );
+        assert!(working.initialized.load(Ordering::SeqCst));
+
+        let health = registry.health_check_all().await;
+        assert!(health["working"].healthy);
+        assert!(!health["broken"].healthy);
+        assert_eq!(health["broken"].message, "Health check failed: Timeout: upstream");
+
+        registry.shutdown_all().await.unwrap();
+        assert!(!working.initialized.load(Ordering::SeqCst));
+
+        assert!(registry.unregister("broken").is_some());
+        registry.initialize_all().await.unwrap();
+        assert_eq!(registry.len(), 1);
+    }
+}
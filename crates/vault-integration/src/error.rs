@@ -56,11 +56,24 @@ pub enum IntegrationError {
     #[error("Channel closed")]
     ChannelClosed,
 
+    /// One or more ecosystem adapters failed, keyed by adapter name.
+    #[error("{} adapter(s) failed: {}", .0.len(), describe_adapter_failures(.0))]
+    AdapterFailures(Vec<(String, IntegrationError)>),
+
     /// Internal error.
     #[error("Internal error: {0}")]
     Internal(String),
 }
 
+/// Joins adapter failures into `name: error` pairs.
+fn describe_adapter_failures(failures: &[(String, IntegrationError)]) -> String {
+    failures
+        .iter()
+        .map(|(name, error)| format!("{name}: {error}"))
+        .collect::<Vec<_>>()
+        .join("; ")
+}
+
 impl IntegrationError {
     /// Returns the error code.
     #[must_use]
@@ -78,6 +91,7 @@ impl IntegrationError {
             Self::RateLimited(_) => "RATE_LIMITED",
             Self::SignatureInvalid(_) => "SIGNATURE_INVALID",
             Self::ChannelClosed => "CHANNEL_CLOSED",
+            Self::AdapterFailures(_) => "ADAPTER_FAILURES",
             Self::Internal(_) => "INTERNAL_ERROR",
         }
     }
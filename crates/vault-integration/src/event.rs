@@ -614,7 +614,7 @@ mod tests {
         let event = Event::dataset(EventType::DatasetCreated, "test-service", payload);
         let json = event.to_json().unwrap();
 
-        assert!(json.contains("dataset.created") || json.contains("DatasetCreated"));
+        assert!(json.contains("\"event_type\":\"dataset_created\""));
         assert!(json.contains("ds-123"));
     }
 
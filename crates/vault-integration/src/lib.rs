@@ -26,7 +26,7 @@ pub use handler::{EventHandler, HandlerRegistry};
 
 // LLM-Dev-Ops ecosystem adapter re-exports (Phase 2A)
 pub use adapters::{
-    AdapterConfig, AdapterHealth, EcosystemAdapter,
+    AdapterConfig, AdapterHealth, AdapterRegistry, EcosystemAdapter,
     SchemaRegistryAdapter, ConfigManagerAdapter,
     ObservatoryAdapter, MemoryGraphAdapter,
 };
@@ -34,6 +34,8 @@ pub use adapters::{
 // LLM-Infra adapter re-exports (Phase 2B)
 pub use adapters::{
     InfraAdapter, InfraConfig, InfraCapabilities,
-    RetryPolicy, RateLimitPolicy, CachePolicy, CacheBackend,
+    RetryPolicy, JitterStrategy, RateLimitPolicy, TokenBucket, KeyedRateLimiter,
+    CachePolicy, CacheBackend,
+    CacheLookup, CacheStats, MemoryCache, ConfigWatcher, InfraPolicies,
     LoggingConfig, TracingConfig, TracePropagation, ErrorConfig,
 };
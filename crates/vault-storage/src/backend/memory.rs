@@ -5,6 +5,8 @@ use super::{ObjectMetadata, StorageBackend, StorageStats};
 use async_trait::async_trait;
 use bytes::Bytes;
 use dashmap::DashMap;
+use parking_lot::Mutex;
+use std::collections::HashMap;
 use std::sync::atomic::{AtomicU64, Ordering};
 
 /// In-memory storage backend.
@@ -12,6 +14,9 @@ pub struct InMemoryBackend {
     data: DashMap<String, StoredObject>,
     total_size: AtomicU64,
     max_size: Option<u64>,
+    /// Serializes quota-checked writes so a quota check and the inserts it
+    /// admits happen as one step. Unbounded backends skip it on `put`.
+    write_lock: Mutex<()>,
 }
 
 struct StoredObject {
@@ -27,6 +32,7 @@ impl InMemoryBackend {
             data: DashMap::new(),
             total_size: AtomicU64::new(0),
             max_size: None,
+            write_lock: Mutex::new(()),
         }
     }
 
@@ -37,6 +43,7 @@ impl InMemoryBackend {
             data: DashMap::new(),
             total_size: AtomicU64::new(0),
             max_size: Some(max_size),
+            write_lock: Mutex::new(()),
         }
     }
 
@@ -63,40 +70,33 @@ impl InMemoryBackend {
     pub fn is_empty(&self) -> bool {
         self.data.is_empty()
     }
-}
-
-impl Default for InMemoryBackend {
-    fn default() -> Self {
-        Self::new()
-    }
-}
-
-#[async_trait]
-impl StorageBackend for InMemoryBackend {
-    fn name(&self) -> &str {
-        "memory"
-    }
-
-    async fn put(&self, key: &str, data: Bytes) -> StorageResult<()> {
-        let size = data.len() as u64;
 
-        // Check max size
+    /// Fails if writing `added` bytes over `replaced` existing bytes would
+    /// exceed the size limit. Callers of a bounded backend must hold
+    /// `write_lock`.
+    fn check_quota(&self, added: u64, replaced: u64) -> StorageResult<()> {
         if let Some(max) = self.max_size {
             let current = self.total_size.load(Ordering::SeqCst);
-            if current + size > max {
+            let projected = (current + added).saturating_sub(replaced);
+            if projected > max {
                 return Err(StorageError::QuotaExceeded {
-                    used: current + size,
+                    used: projected,
                     limit: max,
                 });
             }
         }
+        Ok(())
+    }
 
-        // Remove old object size if exists
-        if let Some(old) = self.data.get(key) {
-            self.total_size
-                .fetch_sub(old.data.len() as u64, Ordering::SeqCst);
-        }
+    /// Returns the size of the object stored under `key`, or 0 if absent.
+    fn stored_size(&self, key: &str) -> u64 {
+        self.data.get(key).map_or(0, |obj| obj.data.len() as u64)
+    }
 
+    /// Stores an object and updates the total size. Callers of a bounded
+    /// backend must hold `write_lock` and have checked the quota.
+    fn insert(&self, key: String, data: Bytes) {
+        let size = data.len() as u64;
         let metadata = ObjectMetadata {
             size,
             content_type: None,
@@ -105,12 +105,52 @@ impl StorageBackend for InMemoryBackend {
             metadata: Default::default(),
         };
 
-        self.data.insert(
-            key.to_string(),
-            StoredObject { data, metadata },
-        );
-
+        if let Some(old) = self.data.insert(key, StoredObject { data, metadata }) {
+            self.total_size
+                .fetch_sub(old.data.len() as u64, Ordering::SeqCst);
+        }
         self.total_size.fetch_add(size, Ordering::SeqCst);
+    }
+}
+
+impl Default for InMemoryBackend {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[async_trait]
+impl StorageBackend for InMemoryBackend {
+    fn name(&self) -> &str {
+        "memory"
+    }
+
+    async fn put(&self, key: &str, data: Bytes) -> StorageResult<()> {
+        // Without a quota there is nothing to check, so writes stay concurrent
+        let _guard = self.max_size.is_some().then(|| self.write_lock.lock());
+        self.check_quota(data.len() as u64, self.stored_size(key))?;
+        self.insert(key.to_string(), data);
+        Ok(())
+    }
+
+    async fn put_batch(&self, items: Vec<(String, Bytes)>) -> StorageResult<()> {
+        // Check the quota for the whole batch and insert it under one lock,
+        // so a batch is stored completely or not at all and concurrent
+        // writes cannot slip in between the check and the inserts.
+        let _guard = self.write_lock.lock();
+
+        // A key repeated within the batch only keeps its last value
+        let mut final_sizes: HashMap<&str, u64> = HashMap::new();
+        for (key, data) in &items {
+            final_sizes.insert(key, data.len() as u64);
+        }
+        let added = final_sizes.values().sum();
+        let replaced = final_sizes.keys().map(|key| self.stored_size(key)).sum();
+        self.check_quota(added, replaced)?;
+
+        for (key, data) in items {
+            self.insert(key, data);
+        }
         Ok(())
     }
 
@@ -220,6 +260,57 @@ mod tests {
         assert!(matches!(result, Err(StorageError::QuotaExceeded { .. })));
     }
 
+    #[tokio::test]
+    async fn test_put_batch_quota_is_all_or_nothing() {
+        let backend = InMemoryBackend::with_max_size(100);
+        let batch = vec![
+            ("key1".to_string(), Bytes::from(vec![0u8; 60])),
+            ("key2".to_string(), Bytes::from(vec![0u8; 60])),
+        ];
+
+        let result = backend.put_batch(batch).await;
+        assert!(matches!(result, Err(StorageError::QuotaExceeded { .. })));
+        assert!(backend.is_empty());
+
+        // Replacing an object within a batch only counts the new size once
+        backend.put("key1", Bytes::from(vec![0u8; 60])).await.unwrap();
+        let batch = vec![
+            ("key1".to_string(), Bytes::from(vec![0u8; 70])),
+            ("key1".to_string(), Bytes::from(vec![0u8; 80])),
+            ("key2".to_string(), Bytes::from(vec![0u8; 20])),
+        ];
+        backend.put_batch(batch).await.unwrap();
+        assert_eq!(backend.size(), 100);
+    }
+
+    #[tokio::test(flavor = "multi_thread", worker_threads = 4)]
+    async fn test_concurrent_batches_respect_quota() {
+        let backend = std::sync::Arc::new(InMemoryBackend::with_max_size(1000));
+        let tasks: Vec<_> = (0..8)
+            .map(|t| {
+                let backend = std::sync::Arc::clone(&backend);
+                tokio::spawn(async move {
+                    let batch = (0..5)
+                        .map(|i| (format!("t{t}/k{i}"), Bytes::from(vec![0u8; 50])))
+                        .collect();
+                    backend.put_batch(batch).await.is_ok()
+                })
+            })
+            .collect();
+
+        let mut stored_batches = 0;
+        for task in tasks {
+            if task.await.unwrap() {
+                stored_batches += 1;
+            }
+        }
+
+        // Each batch is 250 bytes, so exactly four fit, each one whole
+        assert_eq!(stored_batches, 4);
+        assert_eq!(backend.size(), 1000);
+        assert_eq!(backend.len(), 20);
+    }
+
     #[tokio::test]
     async fn test_stats() {
         let backend = InMemoryBackend::new();
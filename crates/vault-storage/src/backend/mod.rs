@@ -21,6 +21,17 @@ pub trait StorageBackend: Send + Sync {
     /// Stores data at the given key.
     async fn put(&self, key: &str, data: Bytes) -> StorageResult<()>;
 
+    /// Stores several objects in one call.
+    ///
+    /// The default implementation issues one `put` per object; backends
+    /// with a native bulk or transactional write should override it.
+    async fn put_batch(&self, items: Vec<(String, Bytes)>) -> StorageResult<()> {
+        for (key, data) in items {
+            self.put(&key, data).await?;
+        }
+        Ok(())
+    }
+
     /// Retrieves data by key.
     async fn get(&self, key: &str) -> StorageResult<Bytes>;
 
@@ -265,6 +265,50 @@ impl ContentStore {
         }
     }
 
+    /// Stores several pieces of content with a single backend batch write.
+    ///
+    /// Content already present in the store (or repeated within the batch)
+    /// is deduplicated as in [`put`](Self::put). Content above the chunk
+    /// threshold is stored individually. Returns metadata in input order.
+    pub async fn put_batch(&self, items: &[&[u8]]) -> StorageResult<Vec<ContentMetadata>> {
+        let mut results = Vec::with_capacity(items.len());
+        let mut batch = Vec::new();
+        let mut pending = std::collections::HashSet::new();
+
+        for data in items {
+            let address = ContentAddress::from_data(self.algorithm, data);
+
+            if pending.contains(&address.hash) {
+                results.push(ContentMetadata::new(address, data.len() as u64));
+                continue;
+            }
+            if self.exists(&address).await? {
+                results.push(self.get_metadata(&address).await?);
+                continue;
+            }
+            if data.len() > self.chunk_threshold {
+                results.push(self.put(data).await?);
+                continue;
+            }
+
+            let metadata = ContentMetadata::new(address, data.len() as u64);
+            let key = metadata.address.to_key();
+            let meta_bytes = serde_json::to_vec(&metadata)
+                .map_err(|e| StorageError::Serialization(e.to_string()))?;
+
+            pending.insert(metadata.address.hash.clone());
+            batch.push((key.clone(), Bytes::copy_from_slice(data)));
+            batch.push((format!("{}.meta", key), Bytes::from(meta_bytes)));
+            results.push(metadata);
+        }
+
+        if !batch.is_empty() {
+            self.backend.put_batch(batch).await?;
+        }
+
+        Ok(results)
+    }
+
     /// Retrieves content by address.
     pub async fn get(&self, address: &ContentAddress) -> StorageResult<Bytes> {
         let metadata = self.get_metadata(address).await?;
@@ -412,6 +456,21 @@ mod tests {
         assert_eq!(meta1.address, meta2.address);
     }
 
+    #[tokio::test]
+    async fn test_put_batch() {
+        let backend = Arc::new(InMemoryBackend::new());
+        let store = ContentStore::new(backend.clone());
+        let items: [&[u8]; 3] = [b"first", b"second", b"first"];
+
+        let metas = store.put_batch(&items).await.unwrap();
+
+        assert_eq!(metas.len(), 3);
+        assert_eq!(metas[0].address, metas[2].address);
+        // Two unique objects, each with a metadata entry.
+        assert_eq!(backend.len(), 4);
+        assert_eq!(store.get(&metas[1].address).await.unwrap().as_ref(), b"second");
+    }
+
     #[tokio::test]
     async fn test_content_address() {
         let data = b"test";